@@ -0,0 +1,461 @@
+//! Typed coordinates for blocks, sections, chunks and regions.
+//!
+//! All conversions to a coarser coordinate system round towards negative infinity,
+//! so the block `-1` is in the chunk `-1` and the region `-1`.
+
+use std::fmt::Display;
+
+/// Number of blocks along one horizontal axis of a chunk and along every axis of a section.
+pub const BLOCKS_PER_CHUNK_AXIS: i32 = 16;
+/// Number of chunks along one axis of a region.
+pub const CHUNKS_PER_REGION_AXIS: i32 = 32;
+/// Number of chunks in a region.
+pub const CHUNKS_PER_REGION: usize = (CHUNKS_PER_REGION_AXIS * CHUNKS_PER_REGION_AXIS) as usize;
+const BLOCK_TO_CHUNK_SHIFT: u32 = 4;
+const CHUNK_TO_REGION_SHIFT: u32 = 5;
+
+/// Position of a block in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// Position of a 16x16x16 section in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SectionPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// Position of a chunk column in the world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ChunkPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+/// Position of a region file containing 32x32 chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RegionPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+/// An axis aligned box in block coordinates. Both corners are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub min_z: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+    pub max_z: i32,
+}
+
+impl BlockPos {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The chunk containing the block.
+    pub fn chunk(&self) -> ChunkPos {
+        ChunkPos::new(
+            self.x >> BLOCK_TO_CHUNK_SHIFT,
+            self.z >> BLOCK_TO_CHUNK_SHIFT,
+        )
+    }
+
+    /// The section containing the block.
+    pub fn section(&self) -> SectionPos {
+        SectionPos::new(
+            self.x >> BLOCK_TO_CHUNK_SHIFT,
+            self.y >> BLOCK_TO_CHUNK_SHIFT,
+            self.z >> BLOCK_TO_CHUNK_SHIFT,
+        )
+    }
+
+    /// The region containing the block.
+    pub fn region(&self) -> RegionPos {
+        self.chunk().region()
+    }
+
+    /// The position of the block relative to the minimum corner of its section.
+    /// Every value is in `0..16`.
+    pub fn in_section(&self) -> (usize, usize, usize) {
+        let mask = BLOCKS_PER_CHUNK_AXIS - 1;
+        (
+            (self.x & mask) as usize,
+            (self.y & mask) as usize,
+            (self.z & mask) as usize,
+        )
+    }
+
+    /// The block containing the point. Coordinates are rounded down.
+    pub fn containing(x: f64, y: f64, z: f64) -> Self {
+        Self::new(x.floor() as i32, y.floor() as i32, z.floor() as i32)
+    }
+}
+
+impl SectionPos {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The chunk column containing the section.
+    pub fn chunk(&self) -> ChunkPos {
+        ChunkPos::new(self.x, self.z)
+    }
+
+    /// The block with the smallest coordinates in the section.
+    pub fn min_block(&self) -> BlockPos {
+        BlockPos::new(
+            self.x * BLOCKS_PER_CHUNK_AXIS,
+            self.y * BLOCKS_PER_CHUNK_AXIS,
+            self.z * BLOCKS_PER_CHUNK_AXIS,
+        )
+    }
+}
+
+impl ChunkPos {
+    pub fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// The region containing the chunk.
+    pub fn region(&self) -> RegionPos {
+        RegionPos::new(
+            self.x >> CHUNK_TO_REGION_SHIFT,
+            self.z >> CHUNK_TO_REGION_SHIFT,
+        )
+    }
+
+    /// The block with the smallest x and z coordinates in the chunk at the given height.
+    pub fn min_block(&self, y: i32) -> BlockPos {
+        BlockPos::new(
+            self.x * BLOCKS_PER_CHUNK_AXIS,
+            y,
+            self.z * BLOCKS_PER_CHUNK_AXIS,
+        )
+    }
+
+    /// The block with the largest x and z coordinates in the chunk at the given height.
+    pub fn max_block(&self, y: i32) -> BlockPos {
+        let min = self.min_block(y);
+        BlockPos::new(
+            min.x + BLOCKS_PER_CHUNK_AXIS - 1,
+            y,
+            min.z + BLOCKS_PER_CHUNK_AXIS - 1,
+        )
+    }
+
+    /// The index of the chunk in the header of its region file.
+    pub fn index_in_region(&self) -> usize {
+        let mask = CHUNKS_PER_REGION_AXIS - 1;
+        ((self.x & mask) + (self.z & mask) * CHUNKS_PER_REGION_AXIS) as usize
+    }
+}
+
+impl RegionPos {
+    pub fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// The chunk with the smallest coordinates in the region.
+    pub fn min_chunk(&self) -> ChunkPos {
+        ChunkPos::new(
+            self.x * CHUNKS_PER_REGION_AXIS,
+            self.z * CHUNKS_PER_REGION_AXIS,
+        )
+    }
+
+    /// The chunk with the largest coordinates in the region.
+    pub fn max_chunk(&self) -> ChunkPos {
+        let min = self.min_chunk();
+        ChunkPos::new(
+            min.x + CHUNKS_PER_REGION_AXIS - 1,
+            min.z + CHUNKS_PER_REGION_AXIS - 1,
+        )
+    }
+
+    /// The chunk stored at `index` in the header of the region file.
+    pub fn chunk_at_index(&self, index: usize) -> ChunkPos {
+        let min = self.min_chunk();
+        let index = index as i32;
+        ChunkPos::new(
+            min.x + index % CHUNKS_PER_REGION_AXIS,
+            min.z + index / CHUNKS_PER_REGION_AXIS % CHUNKS_PER_REGION_AXIS,
+        )
+    }
+
+    /// The block with the smallest x and z coordinates in the region at the given height.
+    pub fn min_block(&self, y: i32) -> BlockPos {
+        self.min_chunk().min_block(y)
+    }
+
+    /// The block with the largest x and z coordinates in the region at the given height.
+    pub fn max_block(&self, y: i32) -> BlockPos {
+        self.max_chunk().max_block(y)
+    }
+
+    /// All regions in the rectangle spanned by both corners. The corners can be given in any order.
+    pub fn area(a: RegionPos, b: RegionPos) -> impl Iterator<Item = RegionPos> {
+        let (min_x, max_x) = (a.x.min(b.x), a.x.max(b.x));
+        let (min_z, max_z) = (a.z.min(b.z), a.z.max(b.z));
+        (min_x..=max_x).flat_map(move |x| (min_z..=max_z).map(move |z| RegionPos::new(x, z)))
+    }
+
+    /// The name of the region file e.g. `r.-1.2.mca`.
+    pub fn file_name(&self) -> String {
+        format!("{self}.mca")
+    }
+
+    /// Parse the name of a region file e.g. `r.-1.2.mca`.
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let mut split = file_name
+            .strip_prefix("r.")?
+            .strip_suffix(".mca")?
+            .split('.');
+        let x = split.next()?.parse().ok()?;
+        let z = split.next()?.parse().ok()?;
+        match split.next() {
+            Some(_) => None,
+            None => Some(Self::new(x, z)),
+        }
+    }
+}
+
+impl BoundingBox {
+    /// Create a bounding box from two corners in any order.
+    pub fn from_corners(a: BlockPos, b: BlockPos) -> Self {
+        BoundingBox {
+            min_x: a.x.min(b.x),
+            min_y: a.y.min(b.y),
+            min_z: a.z.min(b.z),
+            max_x: a.x.max(b.x),
+            max_y: a.y.max(b.y),
+            max_z: a.z.max(b.z),
+        }
+    }
+
+    /// The corner with the smallest coordinates.
+    pub fn min(&self) -> BlockPos {
+        BlockPos::new(self.min_x, self.min_y, self.min_z)
+    }
+
+    /// The corner with the largest coordinates.
+    pub fn max(&self) -> BlockPos {
+        BlockPos::new(self.max_x, self.max_y, self.max_z)
+    }
+
+    /// Returns true if the block position is inside of the bounding box.
+    pub fn contains(&self, pos: BlockPos) -> bool {
+        (self.min_x..=self.max_x).contains(&pos.x)
+            && (self.min_y..=self.max_y).contains(&pos.y)
+            && (self.min_z..=self.max_z).contains(&pos.z)
+    }
+
+    /// Returns true if any block of the chunk is inside of the bounding box. The height is ignored.
+    pub fn overlaps_chunk(&self, chunk: ChunkPos) -> bool {
+        let (min, max) = (self.min().chunk(), self.max().chunk());
+        (min.x..=max.x).contains(&chunk.x) && (min.z..=max.z).contains(&chunk.z)
+    }
+
+    /// All regions containing at least one block of the bounding box.
+    pub fn regions(&self) -> impl Iterator<Item = RegionPos> {
+        RegionPos::area(self.min().region(), self.max().region())
+    }
+
+    /// The smallest bounding box containing both boxes.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            min_z: self.min_z.min(other.min_z),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+            max_z: self.max_z.max(other.max_z),
+        }
+    }
+}
+
+impl From<BlockPos> for SectionPos {
+    fn from(value: BlockPos) -> Self {
+        value.section()
+    }
+}
+
+impl From<BlockPos> for ChunkPos {
+    fn from(value: BlockPos) -> Self {
+        value.chunk()
+    }
+}
+
+impl From<SectionPos> for ChunkPos {
+    fn from(value: SectionPos) -> Self {
+        value.chunk()
+    }
+}
+
+impl From<BlockPos> for RegionPos {
+    fn from(value: BlockPos) -> Self {
+        value.region()
+    }
+}
+
+impl From<ChunkPos> for RegionPos {
+    fn from(value: ChunkPos) -> Self {
+        value.region()
+    }
+}
+
+impl From<(i32, i32, i32)> for BlockPos {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl Display for BlockPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for SectionPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "s.{}.{}.{}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for ChunkPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "c.{}.{}", self.x, self.z)
+    }
+}
+
+impl Display for RegionPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "r.{}.{}", self.x, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockPos, BoundingBox, ChunkPos, RegionPos, SectionPos};
+    use test_case::test_case;
+
+    #[test_case(BlockPos::new(0, 0, 0) => ChunkPos::new(0, 0); "Origin")]
+    #[test_case(BlockPos::new(15, 0, 16) => ChunkPos::new(0, 1); "Chunk border")]
+    #[test_case(BlockPos::new(-1, 0, -16) => ChunkPos::new(-1, -1); "Negative")]
+    #[test_case(BlockPos::new(-17, 0, -33) => ChunkPos::new(-2, -3); "Negative across chunks")]
+    fn test_block_to_chunk(block: BlockPos) -> ChunkPos {
+        block.chunk()
+    }
+
+    #[test_case(BlockPos::new(0, -64, 0) => SectionPos::new(0, -4, 0); "Bottom of the world")]
+    #[test_case(BlockPos::new(-1, -1, 17) => SectionPos::new(-1, -1, 1); "Negative")]
+    fn test_block_to_section(block: BlockPos) -> SectionPos {
+        block.section()
+    }
+
+    #[test_case(BlockPos::new(511, 0, 512) => RegionPos::new(0, 1); "Region border")]
+    #[test_case(BlockPos::new(-1, 0, -512) => RegionPos::new(-1, -1); "Negative")]
+    #[test_case(BlockPos::new(-513, 0, 0) => RegionPos::new(-2, 0); "Negative across regions")]
+    fn test_block_to_region(block: BlockPos) -> RegionPos {
+        block.region()
+    }
+
+    #[test_case(BlockPos::new(0, 0, 0) => (0, 0, 0); "Origin")]
+    #[test_case(BlockPos::new(-1, -64, 17) => (15, 0, 1); "Negative")]
+    fn test_block_in_section(block: BlockPos) -> (usize, usize, usize) {
+        block.in_section()
+    }
+
+    #[test_case(0.5, 64.0, -0.5 => BlockPos::new(0, 64, -1); "Fraction")]
+    #[test_case(-16.0, -0.1, 3.9 => BlockPos::new(-16, -1, 3); "Negative")]
+    fn test_block_containing(x: f64, y: f64, z: f64) -> BlockPos {
+        BlockPos::containing(x, y, z)
+    }
+
+    #[test_case(ChunkPos::new(31, 32) => RegionPos::new(0, 1); "Region border")]
+    #[test_case(ChunkPos::new(-1, -32) => RegionPos::new(-1, -1); "Negative")]
+    #[test_case(ChunkPos::new(-33, 0) => RegionPos::new(-2, 0); "Negative across regions")]
+    fn test_chunk_to_region(chunk: ChunkPos) -> RegionPos {
+        chunk.region()
+    }
+
+    #[test_case(ChunkPos::new(0, 0) => 0; "Origin")]
+    #[test_case(ChunkPos::new(1, 1) => 33; "Positive")]
+    #[test_case(ChunkPos::new(-1, 0) => 31; "Negative x")]
+    #[test_case(ChunkPos::new(-1, -1) => 1023; "Negative")]
+    fn test_chunk_index_in_region(chunk: ChunkPos) -> usize {
+        chunk.index_in_region()
+    }
+
+    #[test_case(RegionPos::new(0, 0), 0 => ChunkPos::new(0, 0); "Origin")]
+    #[test_case(RegionPos::new(-1, 0), 31 => ChunkPos::new(-1, 0); "Negative x")]
+    #[test_case(RegionPos::new(-1, -1), 1023 => ChunkPos::new(-1, -1); "Negative")]
+    fn test_region_chunk_at_index(region: RegionPos, index: usize) -> ChunkPos {
+        region.chunk_at_index(index)
+    }
+
+    #[test]
+    fn test_index_round_trip() {
+        let region = RegionPos::new(-3, 2);
+        for index in 0..super::CHUNKS_PER_REGION {
+            let chunk = region.chunk_at_index(index);
+            assert_eq!(chunk.region(), region);
+            assert_eq!(chunk.index_in_region(), index);
+        }
+    }
+
+    #[test_case(RegionPos::new(0, 0) => (BlockPos::new(0, 0, 0), BlockPos::new(511, 0, 511)); "Origin")]
+    #[test_case(RegionPos::new(-1, -2) => (BlockPos::new(-512, 0, -1024), BlockPos::new(-1, 0, -513)); "Negative")]
+    fn test_region_block_corners(region: RegionPos) -> (BlockPos, BlockPos) {
+        (region.min_block(0), region.max_block(0))
+    }
+
+    #[test]
+    fn test_region_area() {
+        assert_eq!(
+            RegionPos::area(RegionPos::new(0, 1), RegionPos::new(-1, 0)).collect::<Vec<_>>(),
+            vec![
+                RegionPos::new(-1, 0),
+                RegionPos::new(-1, 1),
+                RegionPos::new(0, 0),
+                RegionPos::new(0, 1)
+            ]
+        );
+    }
+
+    #[test_case("r.-1.2.mca" => Some(RegionPos::new(-1, 2)); "Success")]
+    #[test_case("r.-1.2" => None; "Missing extension")]
+    #[test_case("r.1.2.3.mca" => None; "Too many values")]
+    #[test_case("r.a.2.mca" => None; "Not a number")]
+    #[test_case("c.1.2.mca" => None; "Wrong prefix")]
+    fn test_region_from_file_name(file_name: &str) -> Option<RegionPos> {
+        RegionPos::from_file_name(file_name)
+    }
+
+    #[test_case((0, 0, 0) => true; "Min corner")]
+    #[test_case((10, -5, 3) => true; "Max corner")]
+    #[test_case((5, -2, 1) => true; "Inside")]
+    #[test_case((11, 0, 0) => false; "Outside x")]
+    #[test_case((0, -6, 0) => false; "Outside y")]
+    #[test_case((0, 0, 4) => false; "Outside z")]
+    fn test_bounding_box_contains((x, y, z): (i32, i32, i32)) -> bool {
+        BoundingBox::from_corners(BlockPos::new(10, 0, 3), BlockPos::new(0, -5, 0))
+            .contains(BlockPos::new(x, y, z))
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BlockPos::new(-1, 64, 2).to_string(), "-1,64,2");
+        assert_eq!(SectionPos::new(-1, -4, 2).to_string(), "s.-1.-4.2");
+        assert_eq!(ChunkPos::new(-1, 2).to_string(), "c.-1.2");
+        assert_eq!(RegionPos::new(-1, 2).to_string(), "r.-1.2");
+        assert_eq!(RegionPos::new(-1, 2).file_name(), "r.-1.2.mca");
+    }
+}
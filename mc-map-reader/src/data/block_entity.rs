@@ -3,10 +3,12 @@ use std::collections::HashMap;
 use jbe::Builder;
 
 use crate::{
+    coordinates::BlockPos,
     data::{
         entity::Entity,
         item::{Item, ItemWithSlot},
         load::block_entity::*,
+        text_component::{plain_text_from_tag, TextFormat},
     },
     nbt::{Array, List, Tag},
 };
@@ -24,6 +26,13 @@ pub struct BlockEntity {
     pub entity_type: BlockEntityType,
 }
 
+impl BlockEntity {
+    /// The position of the block entity.
+    pub fn position(&self) -> BlockPos {
+        BlockPos::new(self.x, self.y, self.z)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BlockEntityType {
     Banner(Banner),
@@ -34,13 +43,16 @@ pub enum BlockEntityType {
     Bell,
     BlastFurnace(BlastFurnace),
     BrewingStand(BrewingStand),
+    BrushableBlock(BrushableBlock),
     Campfire(Campfire),
     ChiseledBookshelf(ChiseledBookshelf),
     Chest(Chest),
     Comparator(Comparator),
     CommandBlock(CommandBlock),
     Conduit(Conduit),
+    Crafter(Crafter),
     DaylightDetector,
+    DecoratedPot(DecoratedPot),
     Dispenser(Dispenser),
     Dropper(Dropper),
     EnchantingTable(EnchantingTable),
@@ -48,12 +60,16 @@ pub enum BlockEntityType {
     EndGateway(EndGateway),
     EndPortal,
     Furnace(Furnace),
+    HangingSign(Sign),
     Hopper(Hopper),
     Jigsaw(Jigsaw),
     Jukebox(Jukebox),
     Lectern(Lectern),
     MobSpawner(MobSpawner),
     Piston(Piston),
+    SculkCatalyst(SculkCatalyst),
+    SculkSensor(SculkSensor),
+    SculkShrieker(SculkShrieker),
     ShulkerBox(ShulkerBox),
     Sign(Sign),
     Skull(Skull),
@@ -61,6 +77,8 @@ pub enum BlockEntityType {
     SoulCampfire(SoulCampfire),
     StructureBlock(StructureBlock),
     TrappedChest(TrappedChest),
+    TrialSpawner(TrialSpawner),
+    Vault(Vault),
     Other(HashMap<String, Tag>),
 }
 
@@ -134,6 +152,14 @@ pub struct BrewingStand {
     pub lock: Option<String>,
 }
 
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct BrushableBlock {
+    pub hit_direction: Option<i32>,
+    pub item: Option<Item>,
+    pub loot_table: Option<String>,
+    pub loot_table_seed: Option<i64>,
+}
+
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Campfire {
     pub cooking_times: Array<i32>,
@@ -180,6 +206,29 @@ pub struct Conduit {
     pub target: Array<i32>,
 }
 
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Crafter {
+    #[builder({default: 0})]
+    pub crafting_ticks_remaining: i32,
+    pub custom_name: Option<String>,
+    pub disabled_slots: Option<Array<i32>>,
+    pub items: Option<List<ItemWithSlot>>,
+    pub lock: Option<String>,
+    pub loot_table: Option<String>,
+    pub loot_table_seed: Option<i64>,
+    #[builder({default: false})]
+    pub triggered: bool,
+}
+
+/// A decorated pot holds a single item which is stored in slot 0 of [`DecoratedPot::items`].
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct DecoratedPot {
+    pub items: Option<List<ItemWithSlot>>,
+    pub loot_table: Option<String>,
+    pub loot_table_seed: Option<i64>,
+    pub sherds: Option<List<String>>,
+}
+
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Dispenser {
     pub custom_name: Option<String>,
@@ -285,6 +334,27 @@ pub struct PotentialSpawn {
     pub data: HashMap<String, Tag>,
 }
 
+impl Spawner {
+    /// The entities this spawner can spawn, starting with the next one.
+    pub fn entities(&self) -> impl Iterator<Item = &HashMap<String, Tag>> {
+        spawn_entity(&self.spawn_data).into_iter().chain(
+            self.spawn_potentials
+                .iter()
+                .flat_map(|potentials| potentials.iter())
+                .filter_map(|potential| spawn_entity(&potential.data)),
+        )
+    }
+}
+
+/// Spawn data stores the entity in `entity` since 1.18 and directly before.
+fn spawn_entity(spawn_data: &HashMap<String, Tag>) -> Option<&HashMap<String, Tag>> {
+    match spawn_data.get("entity") {
+        Some(Tag::Compound(entity)) => Some(entity),
+        _ if spawn_data.contains_key("id") => Some(spawn_data),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct CustomSpawnRules {
     pub block_light_limit: i32,
@@ -306,6 +376,32 @@ pub struct PistonBlockState {
     pub properties: HashMap<String, Tag>,
 }
 
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct SculkCatalyst {
+    pub cursors: Option<List<SculkCursor>>,
+}
+
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct SculkCursor {
+    pub charge: i32,
+    pub decay_delay: i32,
+    pub facings: Option<List<String>>,
+    pub pos: Array<i32>,
+    pub update_delay: i32,
+}
+
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct SculkSensor {
+    pub last_vibration_frequency: Option<i32>,
+    pub listener: Option<HashMap<String, Tag>>,
+}
+
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct SculkShrieker {
+    pub listener: Option<HashMap<String, Tag>>,
+    pub warning_level: Option<i32>,
+}
+
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct ShulkerBox {
     pub custom_name: Option<String>,
@@ -315,14 +411,61 @@ pub struct ShulkerBox {
     pub loot_table_seed: Option<i64>,
 }
 
+/// Number of lines on each side of a sign.
+pub const SIGN_LINES: usize = 4;
+/// Color of sign texts which were never dyed.
+pub const DEFAULT_SIGN_COLOR: &str = "black";
+
+/// A sign or hanging sign. Signs written before 1.20 only have a front side.
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Sign {
-    pub glowing_text: bool,
+    #[builder({default: SignText::default()})]
+    pub back_text: SignText,
+    pub front_text: SignText,
+    #[builder({default: false})]
+    pub is_waxed: bool,
+}
+
+impl Sign {
+    /// The plain text of all lines of both sides of the sign.
+    /// `format` is the text format of the chunk the sign was read from.
+    pub fn messages(&self, format: TextFormat) -> impl Iterator<Item = String> + '_ {
+        self.front_text
+            .plain_messages(format)
+            .chain(self.back_text.plain_messages(format))
+    }
+}
+
+/// The text on one side of a sign. Messages contain the text component of each line.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct SignText {
+    #[builder({default: DEFAULT_SIGN_COLOR.to_string()})]
     pub color: String,
-    pub text1: String,
-    pub text2: String,
-    pub text3: String,
-    pub text4: String,
+    pub filtered_messages: Option<List<Tag>>,
+    #[builder({default: false})]
+    pub has_glowing_text: bool,
+    #[builder({default: List::from(vec![Tag::String(String::new()); SIGN_LINES])})]
+    pub messages: List<Tag>,
+}
+
+impl SignText {
+    /// The plain text of every line.
+    pub fn plain_messages(&self, format: TextFormat) -> impl Iterator<Item = String> + '_ {
+        self.messages
+            .iter()
+            .map(move |message| plain_text_from_tag(message, format))
+    }
+}
+
+impl Default for SignText {
+    fn default() -> Self {
+        Self {
+            color: DEFAULT_SIGN_COLOR.to_string(),
+            filtered_messages: None,
+            has_glowing_text: false,
+            messages: List::from(vec![Tag::String(String::new()); SIGN_LINES]),
+        }
+    }
 }
 
 #[derive(Debug, Builder, Clone, PartialEq)]
@@ -398,6 +541,34 @@ pub struct TrappedChest {
     pub loot_table_seed: Option<i64>,
 }
 
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct TrialSpawner {
+    pub cooldown_ends_at: Option<i64>,
+    pub current_mobs: Option<List<Array<i32>>>,
+    pub ejecting_loot_table: Option<String>,
+    pub next_mob_spawns_at: Option<i64>,
+    pub registered_players: Option<List<Array<i32>>>,
+    pub required_player_range: Option<i32>,
+    pub spawn_data: Option<HashMap<String, Tag>>,
+    pub target_cooldown_length: Option<i32>,
+    pub total_mobs_spawned: Option<i32>,
+}
+
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct Vault {
+    pub config: Option<HashMap<String, Tag>>,
+    pub server_data: Option<VaultServerData>,
+    pub shared_data: Option<HashMap<String, Tag>>,
+}
+
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct VaultServerData {
+    pub items_to_eject: Option<List<Item>>,
+    pub rewarded_players: Option<List<Array<i32>>>,
+    pub state_updating_resumes_at: Option<i64>,
+    pub total_ejections_needed: Option<i32>,
+}
+
 macro_rules! impl_IBE_for_builder {
     ($ty:ty, $res:ty) => {
         impl InventoryBlockEntityBuilder for $ty {
@@ -517,6 +688,7 @@ macro_rules! impl_CBEB_for_builder {
 
 impl_IBE_for_builder!(BarrelBuilder, Barrel);
 impl_IBE_for_builder!(ChestBuilder, Chest);
+impl_IBE_for_builder!(CrafterBuilder, Crafter);
 impl_IBE_for_builder!(DispenserBuilder, Dispenser);
 impl_IBE_for_builder!(DropperBuilder, Dropper);
 impl_IBE_for_builder!(HopperBuilder, Hopper);
@@ -526,6 +698,24 @@ impl_CBEB_for_builder!(BlastFurnaceBuilder, BlastFurnace);
 impl_CBEB_for_builder!(FurnaceBuilder, Furnace);
 impl_CBEB_for_builder!(SmokerBuilder, Smoker);
 
+impl InventoryBlock for DecoratedPot {
+    fn custom_name(&self) -> Option<&String> {
+        None
+    }
+    fn items(&self) -> Option<&List<ItemWithSlot>> {
+        self.items.as_ref()
+    }
+    fn lock(&self) -> Option<&String> {
+        None
+    }
+    fn loot_table(&self) -> Option<&String> {
+        self.loot_table.as_ref()
+    }
+    fn loot_table_seed(&self) -> Option<i64> {
+        self.loot_table_seed
+    }
+}
+
 pub trait InventoryBlock {
     fn custom_name(&self) -> Option<&String>;
     fn items(&self) -> Option<&List<ItemWithSlot>>;
@@ -578,6 +768,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_case::test_case;
 
     fn test_inventory_block_entity<B>(builder: &mut B)
     where
@@ -638,6 +829,15 @@ mod tests {
         assert_inventory_block_entity(&chest);
     }
 
+    #[test]
+    fn test_crafter() {
+        let mut builder = CrafterBuilder::default();
+        test_inventory_block_entity(&mut builder);
+        let crafter =
+            InventoryBlockEntityBuilder::try_build(builder).expect("Error building crafter");
+        assert_inventory_block_entity(&crafter);
+    }
+
     #[test]
     fn test_dispenser() {
         let mut builder = DispenserBuilder::default();
@@ -708,4 +908,44 @@ mod tests {
         let smoker = CookingBlockEntityBuilder::try_build(builder).expect("Error building smoker");
         assert_cooking_block_entity(&smoker);
     }
+
+    fn entity_with_id(id: &str) -> HashMap<String, Tag> {
+        HashMap::from_iter([("id".to_string(), Tag::String(id.to_string()))])
+    }
+
+    #[test_case(
+        HashMap::from_iter([("entity".to_string(), Tag::Compound(entity_with_id("minecraft:zombie")))]),
+        vec![HashMap::from_iter([("entity".to_string(), Tag::Compound(entity_with_id("minecraft:skeleton")))])]
+        => vec!["minecraft:zombie", "minecraft:skeleton"]; "Entity compound"
+    )]
+    #[test_case(entity_with_id("minecraft:blaze"), vec![] => vec!["minecraft:blaze"]; "Legacy")]
+    #[test_case(HashMap::new(), vec![] => Vec::<String>::new(); "Empty")]
+    fn test_spawner_entities(
+        spawn_data: HashMap<String, Tag>,
+        potentials: Vec<HashMap<String, Tag>>,
+    ) -> Vec<String> {
+        let spawner = Spawner {
+            delay: 20,
+            max_nearby_entities: 6,
+            max_spawn_delay: 800,
+            min_spawn_delay: 200,
+            required_player_range: 16,
+            spawn_count: 4,
+            spawn_data,
+            spawn_potentials: Some(List::from(
+                potentials
+                    .into_iter()
+                    .map(|data| PotentialSpawn { weight: 1, data })
+                    .collect::<Vec<_>>(),
+            )),
+            spawn_range: 4,
+        };
+        spawner
+            .entities()
+            .filter_map(|entity| match entity.get("id") {
+                Some(Tag::String(id)) => Some(id.clone()),
+                _ => None,
+            })
+            .collect()
+    }
 }
@@ -2,11 +2,35 @@ use std::collections::HashMap;
 
 use jbe::Builder;
 
-use crate::nbt::{Array, List};
+use crate::{
+    coordinates::{BlockPos, ChunkPos, SectionPos},
+    nbt::{Array, List},
+};
 
 #[cfg(feature = "block_entity")]
 use super::block_entity::BlockEntity;
 pub use super::load::chunk::*;
+use super::packed_array;
+use super::structure::Structures;
+
+/// Width, height and depth of a chunk section in blocks.
+#[cfg(feature = "chunk_section")]
+pub const SECTION_SIZE: usize = 16;
+/// Number of blocks in a chunk section.
+#[cfg(feature = "chunk_section")]
+pub const BLOCKS_PER_SECTION: usize = SECTION_SIZE * SECTION_SIZE * SECTION_SIZE;
+#[cfg(feature = "chunk_section")]
+const MIN_BLOCK_STATE_BITS: u32 = 4;
+/// Number of biome cells along every axis of a chunk section. Every cell covers 4x4x4 blocks.
+#[cfg(feature = "chunk_section")]
+pub const BIOME_CELLS_PER_AXIS: usize = 4;
+/// Number of biome cells in a chunk section.
+#[cfg(feature = "chunk_section")]
+pub const BIOME_CELLS_PER_SECTION: usize =
+    BIOME_CELLS_PER_AXIS * BIOME_CELLS_PER_AXIS * BIOME_CELLS_PER_AXIS;
+/// Blocks that count as empty space.
+#[cfg(feature = "chunk_section")]
+const AIR_BLOCKS: [&str; 3] = ["minecraft:air", "minecraft:cave_air", "minecraft:void_air"];
 
 #[derive(jbe::Builder, Debug, PartialEq)]
 pub struct ChunkData {
@@ -18,24 +42,281 @@ pub struct ChunkData {
     pub last_update: i64,
     #[cfg(feature = "chunk_section")]
     pub sections: List<Section>,
+    pub height_maps: Option<Heightmaps>,
+    pub structures: Option<Structures>,
+    pub block_ticks: Option<List<ScheduledTick>>,
+    pub fluid_ticks: Option<List<ScheduledTick>>,
     pub block_entities: Option<List<BlockEntity>>, /*#[get = "pub"]
                                                    carving_masks: Option<()>,
                                                    #[get = "pub"]
-                                                   height_maps: (),
-                                                   #[get = "pub"]
                                                    lights: Vec<i16>,
                                                    #[get = "pub"]
                                                    entities: Vec<()>,
-                                                   #[get = "pub"]
-                                                   fluid_ticks: Vec<()>,
-                                                   #[get = "pub"]
-                                                   block_ticks: Vec<()>,
                                                    #[get_copy = "pub"]
                                                    inhabited_time: i64,
                                                    #[get = "pub"]
                                                    post_processing: Vec<()>*/
 }
 
+/// Bits used per entry of a heightmap.
+const HEIGHTMAP_BITS: u32 = 9;
+
+impl ChunkData {
+    /// The position of this chunk.
+    pub fn position(&self) -> ChunkPos {
+        ChunkPos::new(self.x_pos, self.z_pos)
+    }
+
+    /// Decode a heightmap of this chunk. See [`Heightmaps::decode`].
+    pub fn heightmap(&self, kind: HeightmapKind) -> Option<[[i32; 16]; 16]> {
+        self.height_maps.as_ref()?.decode(kind, self.y_pos)
+    }
+
+    /// Number of scheduled block and fluid ticks in this chunk.
+    pub fn pending_ticks(&self) -> usize {
+        self.block_ticks.as_ref().map_or(0, |ticks| ticks.len())
+            + self.fluid_ticks.as_ref().map_or(0, |ticks| ticks.len())
+    }
+}
+
+/// A block or fluid update scheduled for a later game tick.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct ScheduledTick {
+    /// The id of the block or fluid.
+    pub id: String,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    /// Number of ticks until the update happens. Negative if the update is overdue.
+    pub delay: i32,
+    /// Updates with a lower priority value happen first.
+    pub priority: i32,
+}
+
+#[cfg(feature = "chunk_section")]
+impl ChunkData {
+    /// Get the section with the given section Y coordinate.
+    pub fn section(&self, y: i8) -> Option<&Section> {
+        self.sections.iter().find(|section| section.y == y)
+    }
+
+    /// Get the block state at a block position given in world coordinates.
+    /// Returns `None` if the position is not part of this chunk or the section is missing.
+    pub fn block_at(&self, pos: BlockPos) -> Option<&BlockState> {
+        if pos.chunk() != self.position() {
+            return None;
+        }
+        let section = self.section(i8::try_from(pos.section().y).ok()?)?;
+        let (x, y, z) = pos.in_section();
+        section.block_states.get(x, y, z)
+    }
+
+    /// Get the biome at a block position given in world coordinates.
+    /// Returns `None` if the position is not part of this chunk or the section is missing.
+    pub fn biome_at(&self, pos: BlockPos) -> Option<&String> {
+        if pos.chunk() != self.position() {
+            return None;
+        }
+        let section = self.section(i8::try_from(pos.section().y).ok()?)?;
+        let (x, y, z) = pos.in_section();
+        section.biomes.get(
+            x / BIOME_CELLS_PER_AXIS,
+            y / BIOME_CELLS_PER_AXIS,
+            z / BIOME_CELLS_PER_AXIS,
+        )
+    }
+
+    /// Count the biome cells of all sections by biome.
+    pub fn biome_histogram(&self) -> HashMap<&str, usize> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.biomes.iter())
+            .fold(HashMap::new(), |mut histogram, (_, biome)| {
+                *histogram.entry(biome.as_str()).or_default() += 1;
+                histogram
+            })
+    }
+
+    /// Find connected volumes of air in which every block has a block light and a sky light level of 0.
+    /// Volumes containing a single lit block are ignored.
+    /// Missing block light data counts as dark. Missing sky light data counts as lit
+    /// because the game does not store it for sections exposed to the sky.
+    pub fn dark_spaces(&self) -> Vec<DarkSpace> {
+        // Section Y values span the whole i8 range in modded worlds, so offsets are computed as i32.
+        let section_ys = || self.sections.iter().map(|section| i32::from(section.y));
+        let Some(min_section) = section_ys().min() else {
+            return Vec::new();
+        };
+        let max_section = section_ys().max().unwrap_or(min_section);
+        let height = (max_section - min_section + 1) as usize * SECTION_SIZE;
+        let index = |x: usize, y: usize, z: usize| (y * SECTION_SIZE + z) * SECTION_SIZE + x;
+
+        let mut cells = vec![LightCell::Unknown; height * SECTION_SIZE * SECTION_SIZE];
+        for section in self.sections.iter() {
+            let base_y = (i32::from(section.y) - min_section) as usize * SECTION_SIZE;
+            for ((x, y, z), block_state) in section.block_states.iter() {
+                cells[index(x, base_y + y, z)] = if !block_state.is_air() {
+                    LightCell::Solid
+                } else if section.is_dark_at(x, y, z) {
+                    LightCell::DarkAir
+                } else {
+                    LightCell::LitAir
+                };
+            }
+        }
+
+        let origin = SectionPos::new(self.x_pos, min_section, self.z_pos).min_block();
+        let to_world = |(x, y, z): (usize, usize, usize)| {
+            BlockPos::new(
+                origin.x + x as i32,
+                origin.y + y as i32,
+                origin.z + z as i32,
+            )
+        };
+        let mut visited = vec![false; cells.len()];
+        let mut dark_spaces = Vec::new();
+        for start in 0..cells.len() {
+            if visited[start] || !matches!(cells[start], LightCell::DarkAir | LightCell::LitAir) {
+                continue;
+            }
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut volume = 0;
+            let mut lit = false;
+            let mut enclosed = true;
+            let mut min = (usize::MAX, usize::MAX, usize::MAX);
+            let mut max = (0, 0, 0);
+            while let Some(current) = stack.pop() {
+                let (x, y, z) = (
+                    current % SECTION_SIZE,
+                    current / (SECTION_SIZE * SECTION_SIZE),
+                    current / SECTION_SIZE % SECTION_SIZE,
+                );
+                volume += 1;
+                lit |= cells[current] == LightCell::LitAir;
+                min = (min.0.min(x), min.1.min(y), min.2.min(z));
+                max = (max.0.max(x), max.1.max(y), max.2.max(z));
+                let neighbours = [
+                    x.checked_sub(1).map(|x| index(x, y, z)),
+                    (x + 1 < SECTION_SIZE).then(|| index(x + 1, y, z)),
+                    y.checked_sub(1).map(|y| index(x, y, z)),
+                    (y + 1 < height).then(|| index(x, y + 1, z)),
+                    z.checked_sub(1).map(|z| index(x, y, z)),
+                    (z + 1 < SECTION_SIZE).then(|| index(x, y, z + 1)),
+                ];
+                for neighbour in neighbours {
+                    let Some(neighbour) = neighbour else {
+                        enclosed = false;
+                        continue;
+                    };
+                    match cells[neighbour] {
+                        LightCell::Unknown => enclosed = false,
+                        LightCell::Solid => {}
+                        LightCell::DarkAir | LightCell::LitAir => {
+                            if !visited[neighbour] {
+                                visited[neighbour] = true;
+                                stack.push(neighbour);
+                            }
+                        }
+                    }
+                }
+            }
+            if !lit {
+                dark_spaces.push(DarkSpace {
+                    volume,
+                    min: to_world(min),
+                    max: to_world(max),
+                    enclosed,
+                });
+            }
+        }
+        dark_spaces
+    }
+}
+
+/// A connected volume of air without any light found by [`ChunkData::dark_spaces`].
+#[cfg(feature = "chunk_section")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DarkSpace {
+    /// Number of air blocks in this space.
+    pub volume: usize,
+    /// Lowest corner of the bounding box in world coordinates.
+    pub min: BlockPos,
+    /// Highest corner of the bounding box in world coordinates.
+    pub max: BlockPos,
+    /// `false` if the space reaches the border of the chunk or a missing section
+    /// and might continue outside of it.
+    pub enclosed: bool,
+}
+
+#[cfg(feature = "chunk_section")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LightCell {
+    Unknown,
+    Solid,
+    DarkAir,
+    LitAir,
+}
+
+/// Heightmaps of a chunk. Every heightmap contains 256 packed values.
+#[derive(Debug, Builder, PartialEq)]
+pub struct Heightmaps {
+    pub motion_blocking: Option<Array<i64>>,
+    pub motion_blocking_no_leaves: Option<Array<i64>>,
+    pub ocean_floor: Option<Array<i64>>,
+    pub ocean_floor_wg: Option<Array<i64>>,
+    pub world_surface: Option<Array<i64>>,
+    pub world_surface_wg: Option<Array<i64>>,
+}
+
+/// The different kinds of heightmaps stored in a chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeightmapKind {
+    /// Highest block that blocks motion or contains a fluid.
+    MotionBlocking,
+    /// Like [`HeightmapKind::MotionBlocking`] but ignores leaves.
+    MotionBlockingNoLeaves,
+    /// Highest solid block.
+    OceanFloor,
+    /// Highest solid block. Only used during world generation.
+    OceanFloorWg,
+    /// Highest non-air block.
+    WorldSurface,
+    /// Highest non-air block. Only used during world generation.
+    WorldSurfaceWg,
+}
+
+impl Heightmaps {
+    /// Get the raw packed data of a heightmap.
+    pub fn get(&self, kind: HeightmapKind) -> Option<&Array<i64>> {
+        match kind {
+            HeightmapKind::MotionBlocking => self.motion_blocking.as_ref(),
+            HeightmapKind::MotionBlockingNoLeaves => self.motion_blocking_no_leaves.as_ref(),
+            HeightmapKind::OceanFloor => self.ocean_floor.as_ref(),
+            HeightmapKind::OceanFloorWg => self.ocean_floor_wg.as_ref(),
+            HeightmapKind::WorldSurface => self.world_surface.as_ref(),
+            HeightmapKind::WorldSurfaceWg => self.world_surface_wg.as_ref(),
+        }
+    }
+
+    /// Decode a heightmap into world Y coordinates indexed as `[z][x]` relative to the chunk.
+    /// Every value is the Y coordinate of the lowest air block above the highest matching block.
+    /// `y_pos` is the section Y coordinate of the lowest section of the chunk.
+    /// Returns `None` if the heightmap is missing or too short.
+    pub fn decode(&self, kind: HeightmapKind, y_pos: i32) -> Option<[[i32; 16]; 16]> {
+        let data = self.get(kind)?;
+        let min_y = y_pos * 16;
+        let mut heightmap = [[0; 16]; 16];
+        for (z, row) in heightmap.iter_mut().enumerate() {
+            for (x, height) in row.iter_mut().enumerate() {
+                *height = packed_array::get(data, HEIGHTMAP_BITS, z * 16 + x)? as i32 + min_y;
+            }
+        }
+        Some(heightmap)
+    }
+}
+
+/// The generation step a chunk has reached. Only chunks with the status [`ChunkStatus::Full`] are fully generated.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ChunkStatus {
     Empty,
@@ -47,12 +328,35 @@ pub enum ChunkStatus {
     Carvers,
     LiquidCarvers,
     Features,
+    InitializeLight,
     Light,
     Spawn,
     Heightmaps,
     Full,
 }
 
+impl ChunkStatus {
+    /// The id of the status without namespace e.g. `liquid_carvers`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::StructureStarts => "structure_starts",
+            Self::StructureReferences => "structure_references",
+            Self::Biomes => "biomes",
+            Self::Noise => "noise",
+            Self::Surface => "surface",
+            Self::Carvers => "carvers",
+            Self::LiquidCarvers => "liquid_carvers",
+            Self::Features => "features",
+            Self::InitializeLight => "initialize_light",
+            Self::Light => "light",
+            Self::Spawn => "spawn",
+            Self::Heightmaps => "heightmaps",
+            Self::Full => "full",
+        }
+    }
+}
+
 #[cfg(feature = "chunk_section")]
 #[derive(Debug, Builder, PartialEq)]
 pub struct Section {
@@ -63,6 +367,40 @@ pub struct Section {
     pub sky_light: Option<Array<i8>>,
 }
 
+#[cfg(feature = "chunk_section")]
+impl Section {
+    /// Get the block light level at a position relative to the section. All coordinates must be in `0..16`.
+    /// Returns `None` if the position is outside of the section or the section contains no block light data.
+    pub fn block_light_at(&self, x: usize, y: usize, z: usize) -> Option<u8> {
+        nibble_at(self.block_light.as_ref()?, x, y, z)
+    }
+
+    /// Get the sky light level at a position relative to the section. All coordinates must be in `0..16`.
+    /// Returns `None` if the position is outside of the section or the section contains no sky light data.
+    pub fn sky_light_at(&self, x: usize, y: usize, z: usize) -> Option<u8> {
+        nibble_at(self.sky_light.as_ref()?, x, y, z)
+    }
+
+    fn is_dark_at(&self, x: usize, y: usize, z: usize) -> bool {
+        self.block_light_at(x, y, z).unwrap_or(0) == 0 && self.sky_light_at(x, y, z) == Some(0)
+    }
+}
+
+/// Read a value from a light array. Every byte contains two values, the lower half comes first.
+#[cfg(feature = "chunk_section")]
+fn nibble_at(data: &[i8], x: usize, y: usize, z: usize) -> Option<u8> {
+    if x >= SECTION_SIZE || y >= SECTION_SIZE || z >= SECTION_SIZE {
+        return None;
+    }
+    let index = (y * SECTION_SIZE + z) * SECTION_SIZE + x;
+    let byte = *data.get(index / 2)? as u8;
+    Some(if index & 1 == 0 {
+        byte & 0x0f
+    } else {
+        byte >> 4
+    })
+}
+
 #[cfg(feature = "chunk_section")]
 #[derive(Debug, Builder, PartialEq)]
 pub struct BlockStates {
@@ -70,6 +408,55 @@ pub struct BlockStates {
     pub data: Option<Array<i64>>,
 }
 
+#[cfg(feature = "chunk_section")]
+impl BlockStates {
+    /// Get the block state at a position relative to the section. All coordinates must be in `0..16`.
+    /// Returns `None` if the position is outside of the section or the stored data is invalid.
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&BlockState> {
+        if x >= SECTION_SIZE || y >= SECTION_SIZE || z >= SECTION_SIZE {
+            return None;
+        }
+        self.get_by_index(
+            self.bits_per_entry(),
+            (y * SECTION_SIZE + z) * SECTION_SIZE + x,
+        )
+    }
+
+    /// Iterate over all 4096 blocks of the section in YZX order.
+    /// Every item contains the position `(x, y, z)` relative to the section and the block state.
+    /// Blocks with invalid data are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize, usize), &BlockState)> {
+        let bits = self.bits_per_entry();
+        (0..BLOCKS_PER_SECTION).filter_map(move |index| {
+            let position = (
+                index % SECTION_SIZE,
+                index / (SECTION_SIZE * SECTION_SIZE),
+                index / SECTION_SIZE % SECTION_SIZE,
+            );
+            self.get_by_index(bits, index)
+                .map(|block_state| (position, block_state))
+        })
+    }
+
+    /// Returns true if the palette only contains air. Such sections can be skipped without decoding the data.
+    pub fn is_air(&self) -> bool {
+        self.palette.iter().all(BlockState::is_air)
+    }
+
+    fn bits_per_entry(&self) -> u32 {
+        packed_array::bits_per_entry(self.palette.len(), MIN_BLOCK_STATE_BITS)
+    }
+
+    fn get_by_index(&self, bits: u32, index: usize) -> Option<&BlockState> {
+        let palette_index = match &self.data {
+            // Sections containing a single block state do not store any data.
+            Some(data) if self.palette.len() > 1 => packed_array::get(data, bits, index)?,
+            _ => 0,
+        };
+        self.palette.get(palette_index)
+    }
+}
+
 #[derive(Debug, Builder, PartialEq)]
 pub struct Biomes {
     pub palette: List<String>,
@@ -82,3 +469,457 @@ pub struct BlockState {
     pub name: String,
     pub properties: Option<HashMap<String, crate::nbt::Tag>>,
 }
+
+#[cfg(feature = "chunk_section")]
+impl BlockState {
+    /// Returns true for air, cave air and void air.
+    pub fn is_air(&self) -> bool {
+        AIR_BLOCKS.contains(&self.name.as_str())
+    }
+
+    /// Get the value of a block state property e.g. `eye` of an end portal frame.
+    /// Returns `None` if the property is not set or not a string.
+    pub fn property(&self, name: &str) -> Option<&str> {
+        match self.properties.as_ref()?.get(name)? {
+            crate::nbt::Tag::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chunk_section")]
+impl Biomes {
+    /// Get the biome of a 4x4x4 cell of the section. All coordinates must be in `0..4`.
+    /// Returns `None` if the cell is outside of the section or the stored data is invalid.
+    pub fn get(&self, cell_x: usize, cell_y: usize, cell_z: usize) -> Option<&String> {
+        if cell_x >= BIOME_CELLS_PER_AXIS
+            || cell_y >= BIOME_CELLS_PER_AXIS
+            || cell_z >= BIOME_CELLS_PER_AXIS
+        {
+            return None;
+        }
+        self.get_by_index(
+            self.bits_per_entry(),
+            (cell_y * BIOME_CELLS_PER_AXIS + cell_z) * BIOME_CELLS_PER_AXIS + cell_x,
+        )
+    }
+
+    /// Iterate over all 64 cells of the section in YZX order.
+    /// Every item contains the cell position `(x, y, z)` and the biome.
+    /// Cells with invalid data are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize, usize), &String)> {
+        let bits = self.bits_per_entry();
+        (0..BIOME_CELLS_PER_SECTION).filter_map(move |index| {
+            let position = (
+                index % BIOME_CELLS_PER_AXIS,
+                index / (BIOME_CELLS_PER_AXIS * BIOME_CELLS_PER_AXIS),
+                index / BIOME_CELLS_PER_AXIS % BIOME_CELLS_PER_AXIS,
+            );
+            self.get_by_index(bits, index)
+                .map(|biome| (position, biome))
+        })
+    }
+
+    fn bits_per_entry(&self) -> u32 {
+        packed_array::bits_per_entry(self.palette.len(), 0)
+    }
+
+    fn get_by_index(&self, bits: u32, index: usize) -> Option<&String> {
+        let palette_index = match &self.data {
+            Some(data) if self.palette.len() > 1 => packed_array::get(data, bits, index)?,
+            _ => 0,
+        };
+        self.palette.get(palette_index)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "chunk_section")]
+mod tests {
+    use super::*;
+    use crate::test_util::empty_chunk;
+    use test_case::test_case;
+
+    fn block_state(name: &str) -> BlockState {
+        BlockState {
+            name: name.to_string(),
+            properties: None,
+        }
+    }
+
+    fn block_states(palette: &[&str], data: Option<Vec<i64>>) -> BlockStates {
+        BlockStates {
+            palette: List::from(
+                palette
+                    .iter()
+                    .map(|name| block_state(name))
+                    .collect::<Vec<_>>(),
+            ),
+            data: data.map(Array::from),
+        }
+    }
+
+    /// Two entries per block with 4 bits each. Every block in the lowest layer is stone, the rest is air.
+    fn stone_floor() -> BlockStates {
+        let mut data = vec![0; BLOCKS_PER_SECTION / 16];
+        data[..16].fill(0x1111_1111_1111_1111);
+        block_states(&["minecraft:air", "minecraft:stone"], Some(data))
+    }
+
+    #[test_case(0, 0, 0 => Some("minecraft:stone".to_string()); "First block")]
+    #[test_case(15, 0, 15 => Some("minecraft:stone".to_string()); "Last block of layer")]
+    #[test_case(0, 1, 0 => Some("minecraft:air".to_string()); "Second layer")]
+    #[test_case(16, 0, 0 => None; "Outside of section")]
+    fn test_get(x: usize, y: usize, z: usize) -> Option<String> {
+        stone_floor().get(x, y, z).map(|b| b.name.clone())
+    }
+
+    #[test_case(None; "Without data")]
+    #[test_case(Some(vec![0; 256]); "With data")]
+    fn test_get_single_entry_palette(data: Option<Vec<i64>>) {
+        let block_states = block_states(&["minecraft:stone"], data);
+        assert_eq!(
+            block_states.get(3, 4, 5),
+            Some(&block_state("minecraft:stone"))
+        );
+        assert_eq!(block_states.iter().count(), BLOCKS_PER_SECTION);
+    }
+
+    #[test_case(&["minecraft:air"] => true; "Air")]
+    #[test_case(&["minecraft:air", "minecraft:cave_air", "minecraft:void_air"] => true; "All kinds of air")]
+    #[test_case(&["minecraft:air", "minecraft:stone"] => false; "Air and stone")]
+    fn test_is_air(palette: &[&str]) -> bool {
+        block_states(palette, None).is_air()
+    }
+
+    #[test_case("eye" => Some("true".to_string()); "String property")]
+    #[test_case("facing" => None; "Missing property")]
+    #[test_case("power" => None; "Not a string")]
+    fn test_block_state_property(name: &str) -> Option<String> {
+        let block_state = BlockState {
+            name: "minecraft:end_portal_frame".to_string(),
+            properties: Some(HashMap::from_iter([
+                (
+                    "eye".to_string(),
+                    crate::nbt::Tag::String("true".to_string()),
+                ),
+                ("power".to_string(), crate::nbt::Tag::Int(1)),
+            ])),
+        };
+        block_state.property(name).map(str::to_string)
+    }
+
+    #[test]
+    fn test_get_non_spanning() {
+        // 17 palette entries need 5 bits. 12 entries fit into one long, the last 4 bits are unused.
+        let palette = (0..17)
+            .map(|i| format!("minecraft:block_{i}"))
+            .collect::<Vec<_>>();
+        let palette = palette.iter().map(String::as_str).collect::<Vec<_>>();
+        let mut data = vec![0; 342];
+        data[1] = 16;
+        let block_states = block_states(&palette, Some(data));
+        assert_eq!(
+            block_states.get(12, 0, 0),
+            Some(&block_state("minecraft:block_16"))
+        );
+        assert_eq!(
+            block_states.get(11, 0, 0),
+            Some(&block_state("minecraft:block_0"))
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let block_states = stone_floor();
+        let stone = block_states
+            .iter()
+            .filter(|(_, block)| block.name == "minecraft:stone")
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        assert_eq!(stone.len(), 256);
+        assert!(stone.iter().all(|(_, y, _)| *y == 0));
+        assert_eq!(stone[17], (1, 0, 1));
+    }
+
+    #[test]
+    fn test_iter_skips_invalid_data() {
+        let block_states = block_states(&["minecraft:air", "minecraft:stone"], Some(vec![0; 16]));
+        assert_eq!(block_states.iter().count(), 256);
+    }
+
+    fn biomes(palette: &[&str], data: Option<Vec<i64>>) -> Biomes {
+        Biomes {
+            palette: List::from(palette.iter().map(|b| b.to_string()).collect::<Vec<_>>()),
+            data: data.map(Array::from),
+        }
+    }
+
+    /// One bit per cell. The upper half of the section is a mushroom field.
+    fn mushroom_top() -> Biomes {
+        biomes(
+            &["minecraft:plains", "minecraft:mushroom_fields"],
+            Some(vec![0xffff_ffff_0000_0000_u64 as i64]),
+        )
+    }
+
+    #[test_case(0, 0, 0 => Some("minecraft:plains".to_string()); "Lower half")]
+    #[test_case(3, 2, 3 => Some("minecraft:mushroom_fields".to_string()); "Upper half")]
+    #[test_case(4, 0, 0 => None; "Outside of section")]
+    fn test_biomes_get(x: usize, y: usize, z: usize) -> Option<String> {
+        mushroom_top().get(x, y, z).cloned()
+    }
+
+    #[test]
+    fn test_biomes_single_entry_palette() {
+        let biomes = biomes(&["minecraft:desert"], None);
+        assert_eq!(biomes.get(1, 2, 3), Some(&"minecraft:desert".to_string()));
+        assert_eq!(biomes.iter().count(), BIOME_CELLS_PER_SECTION);
+    }
+
+    fn chunk(sections: Vec<Section>) -> ChunkData {
+        ChunkData {
+            sections: List::from(sections),
+            ..empty_chunk(-1, 2)
+        }
+    }
+
+    fn section(y: i8, biomes: Biomes) -> Section {
+        Section {
+            y,
+            block_states: block_states(&["minecraft:air"], None),
+            biomes,
+            block_light: None,
+            sky_light: None,
+        }
+    }
+
+    #[test_case(-16, -64, 32 => Some("minecraft:plains".to_string()); "Lowest block")]
+    #[test_case(-1, -49, 47 => Some("minecraft:mushroom_fields".to_string()); "Highest block of section")]
+    #[test_case(-1, -48, 47 => None; "Missing section")]
+    #[test_case(0, -60, 32 => None; "Other chunk")]
+    fn test_chunk_biome_at(x: i32, y: i32, z: i32) -> Option<String> {
+        chunk(vec![section(-4, mushroom_top())])
+            .biome_at(BlockPos::new(x, y, z))
+            .cloned()
+    }
+
+    #[test_case(-16, -64, 32 => Some("minecraft:stone".to_string()); "Lowest block")]
+    #[test_case(-1, -63, 47 => Some("minecraft:air".to_string()); "Above floor")]
+    #[test_case(-1, -48, 47 => None; "Missing section")]
+    #[test_case(0, -64, 32 => None; "Other chunk")]
+    fn test_chunk_block_at(x: i32, y: i32, z: i32) -> Option<String> {
+        let mut section = section(-4, biomes(&["minecraft:plains"], None));
+        section.block_states = stone_floor();
+        chunk(vec![section])
+            .block_at(BlockPos::new(x, y, z))
+            .map(|block| block.name.clone())
+    }
+
+    #[test]
+    fn test_chunk_biome_histogram() {
+        let chunk = chunk(vec![
+            section(-4, mushroom_top()),
+            section(-3, biomes(&["minecraft:plains"], None)),
+        ]);
+        assert_eq!(
+            chunk.biome_histogram(),
+            HashMap::from_iter([("minecraft:plains", 96), ("minecraft:mushroom_fields", 32)])
+        );
+    }
+
+    fn heightmaps(world_surface: Option<Vec<i64>>) -> Heightmaps {
+        Heightmaps {
+            motion_blocking: None,
+            motion_blocking_no_leaves: None,
+            ocean_floor: None,
+            ocean_floor_wg: None,
+            world_surface: world_surface.map(Array::from),
+            world_surface_wg: None,
+        }
+    }
+
+    #[test]
+    fn test_heightmap_decode() {
+        // 7 values per long. Every value is its index + 1.
+        let data = (0..37)
+            .map(|long| {
+                (0..7).fold(0_i64, |acc, i| {
+                    let index = long * 7 + i;
+                    acc | ((index + 1) << (i * 9))
+                })
+            })
+            .collect();
+        let heightmap = heightmaps(Some(data))
+            .decode(HeightmapKind::WorldSurface, -4)
+            .expect("Heightmap should be decoded");
+        assert_eq!(heightmap[0][0], 1 - 64);
+        assert_eq!(heightmap[0][15], 16 - 64);
+        assert_eq!(heightmap[1][0], 17 - 64);
+        assert_eq!(heightmap[15][15], 256 - 64);
+    }
+
+    #[test_case(heightmaps(None), HeightmapKind::WorldSurface; "Missing heightmap")]
+    #[test_case(heightmaps(Some(vec![0; 36])), HeightmapKind::WorldSurface; "Too short")]
+    #[test_case(heightmaps(Some(vec![0; 37])), HeightmapKind::MotionBlocking; "Other kind")]
+    fn test_heightmap_decode_none(heightmaps: Heightmaps, kind: HeightmapKind) {
+        assert_eq!(heightmaps.decode(kind, 0), None);
+    }
+
+    #[test]
+    fn test_chunk_heightmap() {
+        let mut chunk = chunk(vec![]);
+        assert_eq!(chunk.heightmap(HeightmapKind::WorldSurface), None);
+        chunk.height_maps = Some(heightmaps(Some(vec![0; 37])));
+        assert_eq!(
+            chunk.heightmap(HeightmapKind::WorldSurface),
+            Some([[-64; 16]; 16])
+        );
+    }
+
+    #[test_case(0, 0, 0 => Some(1); "Lower half of first byte")]
+    #[test_case(1, 0, 0 => Some(2); "Upper half of first byte")]
+    #[test_case(15, 15, 15 => Some(15); "Last value")]
+    #[test_case(16, 0, 0 => None; "Outside of section")]
+    fn test_light_at(x: usize, y: usize, z: usize) -> Option<u8> {
+        let mut light = vec![0_i8; 2048];
+        light[0] = 0x21;
+        light[2047] = 0xf0_u8 as i8;
+        let section = Section {
+            block_light: Some(Array::from(light.clone())),
+            sky_light: Some(Array::from(light)),
+            ..section(0, biomes(&["minecraft:plains"], None))
+        };
+        assert_eq!(
+            section.block_light_at(x, y, z),
+            section.sky_light_at(x, y, z)
+        );
+        section.block_light_at(x, y, z)
+    }
+
+    #[test]
+    fn test_light_at_missing_data() {
+        let section = section(0, biomes(&["minecraft:plains"], None));
+        assert_eq!(section.block_light_at(0, 0, 0), None);
+        assert_eq!(section.sky_light_at(0, 0, 0), None);
+    }
+
+    /// A stone section with air where `is_air` returns true.
+    fn room_section(y: i8, is_air: impl Fn(usize, usize, usize) -> bool) -> Section {
+        let mut data = vec![0_i64; BLOCKS_PER_SECTION / 16];
+        for index in 0..BLOCKS_PER_SECTION {
+            let (x, y, z) = (index % 16, index / 256, index / 16 % 16);
+            if is_air(x, y, z) {
+                data[index / 16] |= 1 << (index % 16 * 4);
+            }
+        }
+        Section {
+            y,
+            block_states: block_states(&["minecraft:stone", "minecraft:cave_air"], Some(data)),
+            biomes: biomes(&["minecraft:plains"], None),
+            block_light: None,
+            sky_light: Some(Array::from(vec![0; 2048])),
+        }
+    }
+
+    fn in_box(v: usize, min: usize, max: usize) -> bool {
+        (min..=max).contains(&v)
+    }
+
+    #[test]
+    fn test_dark_spaces_enclosed() {
+        let chunk = chunk(vec![room_section(-4, |x, y, z| {
+            in_box(x, 5, 6) && in_box(y, 5, 6) && in_box(z, 5, 6)
+        })]);
+        assert_eq!(
+            chunk.dark_spaces(),
+            vec![DarkSpace {
+                volume: 8,
+                min: BlockPos::new(-16 + 5, -64 + 5, 32 + 5),
+                max: BlockPos::new(-16 + 6, -64 + 6, 32 + 6),
+                enclosed: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dark_spaces_touching_border() {
+        let chunk = chunk(vec![room_section(0, |x, y, z| {
+            in_box(x, 0, 2) && y == 3 && z == 3
+        })]);
+        let dark_spaces = chunk.dark_spaces();
+        assert_eq!(dark_spaces.len(), 1);
+        assert_eq!(dark_spaces[0].volume, 3);
+        assert!(!dark_spaces[0].enclosed);
+    }
+
+    #[test]
+    fn test_dark_spaces_across_sections() {
+        let chunk = chunk(vec![
+            room_section(0, |x, y, z| x == 8 && z == 8 && y == 15),
+            room_section(1, |x, y, z| x == 8 && z == 8 && y == 0),
+        ]);
+        let dark_spaces = chunk.dark_spaces();
+        assert_eq!(dark_spaces.len(), 1);
+        assert_eq!(dark_spaces[0].volume, 2);
+        assert!(dark_spaces[0].enclosed);
+    }
+
+    #[test]
+    fn test_dark_spaces_tall_world() {
+        let chunk = chunk(vec![
+            room_section(-100, |_, _, _| false),
+            room_section(100, |x, y, z| x == 5 && y == 5 && z == 5),
+        ]);
+        assert_eq!(
+            chunk.dark_spaces(),
+            vec![DarkSpace {
+                volume: 1,
+                min: BlockPos::new(-16 + 5, 1600 + 5, 32 + 5),
+                max: BlockPos::new(-16 + 5, 1600 + 5, 32 + 5),
+                enclosed: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dark_spaces_ignores_lit_space() {
+        let mut section = room_section(0, |x, y, z| in_box(x, 5, 6) && y == 5 && z == 5);
+        let mut block_light = vec![0; 2048];
+        block_light[((5 * 16 + 5) * 16 + 6) / 2] = 0x07;
+        section.block_light = Some(Array::from(block_light));
+        assert_eq!(chunk(vec![section]).dark_spaces(), vec![]);
+    }
+
+    #[test]
+    fn test_dark_spaces_missing_sky_light() {
+        let mut section = room_section(0, |x, y, z| x == 5 && y == 5 && z == 5);
+        section.sky_light = None;
+        assert_eq!(chunk(vec![section]).dark_spaces(), vec![]);
+    }
+
+    fn scheduled_tick(id: &str) -> ScheduledTick {
+        ScheduledTick {
+            id: id.to_string(),
+            x: 0,
+            y: 0,
+            z: 0,
+            delay: 1,
+            priority: 0,
+        }
+    }
+
+    #[test_case(None, None => 0; "No ticks")]
+    #[test_case(Some(2), None => 2; "Block ticks")]
+    #[test_case(None, Some(3) => 3; "Fluid ticks")]
+    #[test_case(Some(2), Some(3) => 5; "Block and fluid ticks")]
+    fn test_pending_ticks(block_ticks: Option<usize>, fluid_ticks: Option<usize>) -> usize {
+        let ticks = |count: usize, id: &str| List::from(vec![scheduled_tick(id); count]);
+        ChunkData {
+            block_ticks: block_ticks.map(|count| ticks(count, "minecraft:redstone_wire")),
+            fluid_ticks: fluid_ticks.map(|count| ticks(count, "minecraft:water")),
+            ..chunk(vec![])
+        }
+        .pending_ticks()
+    }
+}
@@ -2,9 +2,13 @@ use std::collections::HashMap;
 
 use jbe::Builder;
 
-use crate::nbt::{Array, List, Tag};
+use crate::{
+    coordinates::{BlockPos, ChunkPos},
+    nbt::{Array, List, Tag},
+};
 
 use super::item::Item;
+pub use super::load::entity::*;
 
 ///<a href="https://minecraft.fandom.com/wiki/Entity_format#Entity_Format">minecraft wiki</a>
 #[derive(Debug, Builder, Clone, PartialEq)]
@@ -30,15 +34,69 @@ pub struct Entity {
     pub passengers: Option<List<Entity>>,
     #[builder({default: 0})]
     pub portal_colldown: i32,
-    pub pos: Option<List<f32>>,
+    pub pos: Option<List<f64>>,
     pub rotation: Option<List<f32>>,
     #[builder({default: false})]
     pub silent: bool,
-    pub tags: Option<HashMap<String, Tag>>,
+    pub tags: Option<List<String>>,
     pub ticks_frozen: Option<i32>,
     pub uuid: Option<Array<i32>>,
 }
 
+impl Entity {
+    /// The position of the entity as `(x, y, z)`.
+    /// Returns `None` if the entity has no valid position.
+    pub fn position(&self) -> Option<(f64, f64, f64)> {
+        match self.pos.as_ref()?.as_slice() {
+            [x, y, z] => Some((*x, *y, *z)),
+            _ => None,
+        }
+    }
+
+    /// The block containing the entity.
+    pub fn block_pos(&self) -> Option<BlockPos> {
+        self.position()
+            .map(|(x, y, z)| BlockPos::containing(x, y, z))
+    }
+}
+
+/// Entities of a chunk stored in the `entities` directory of a dimension.
+/// [Minecraft Wiki](https://minecraft.wiki/w/Entity_format#Entity_chunk_format)
+#[derive(Debug, Builder, PartialEq)]
+pub struct EntityChunk {
+    pub data_version: i32,
+    /// Position of the chunk as `[x, z]`.
+    pub position: Array<i32>,
+    pub entities: List<Entity>,
+}
+
+impl EntityChunk {
+    /// The position of the chunk.
+    /// Returns `None` if the stored position does not contain exactly two values.
+    pub fn chunk_position(&self) -> Option<ChunkPos> {
+        match self.position.as_slice() {
+            [x, z] => Some(ChunkPos::new(*x, *z)),
+            _ => None,
+        }
+    }
+}
+
+/// Entity id of minecarts with a command block.
+pub const COMMAND_BLOCK_MINECART: &str = "minecraft:command_block_minecart";
+
+/// A minecart with a command block which runs its command while on an activator rail.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct CommandBlockMinecart {
+    #[builder({default: String::new()})]
+    pub command: String,
+    pub entity: Entity,
+    pub last_output: Option<String>,
+    #[builder({default: 0})]
+    pub success_count: i32,
+    #[builder({default: true})]
+    pub track_output: bool,
+}
+
 #[derive(Debug, Builder, PartialEq)]
 pub struct Mob {
     pub absorption_amount: Option<f32>,
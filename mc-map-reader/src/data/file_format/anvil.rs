@@ -1,25 +1,27 @@
 //! Anvil save file.
 
-use crate::data::chunk::ChunkData;
+use crate::{coordinates::ChunkPos, data::chunk::ChunkData};
 
 /// Anvil save file.
 /// [Minecraft Wiki](https://minecraft.fandom.com/wiki/Anvil_file_format)
+///
+/// The same container format is used for terrain, entity and POI regions. `C` is the type of the chunk data.
 #[derive(Debug, PartialEq)]
-pub struct AnvilSave {
+pub struct AnvilSave<C = ChunkData> {
     /// The header of the save file.
     pub header: McRegionHeader,
     /// The chunks in the save file.
-    pub chunks: Vec<ChunkData>,
+    pub chunks: Vec<C>,
 }
 
-impl AnvilSave {
+impl<C> AnvilSave<C> {
     /// Create a new Anvil save file.
-    pub fn new(header: McRegionHeader, chunks: Vec<ChunkData>) -> Self {
+    pub fn new(header: McRegionHeader, chunks: Vec<C>) -> Self {
         Self { header, chunks }
     }
 }
 
-const CHUNKS_PER_FILE: usize = 1024;
+const CHUNKS_PER_FILE: usize = crate::coordinates::CHUNKS_PER_REGION;
 const CHUNK_OFFSET_LENGTH: usize = 4;
 const CHUNK_OFFSETS_START: usize = 0;
 const CHUNK_OFFSETS_SIZE: usize = CHUNK_OFFSET_LENGTH * CHUNKS_PER_FILE;
@@ -51,6 +53,12 @@ impl McRegionHeader {
     pub fn get_chunk_info(&self) -> &[Option<ChunkInfo>; CHUNKS_PER_FILE] {
         &self.chunks
     }
+
+    /// Get the chunk information of a chunk in this region.
+    /// Only the position of the chunk inside of the region is used.
+    pub fn chunk_info(&self, chunk: ChunkPos) -> Option<&ChunkInfo> {
+        self.chunks[chunk.index_in_region()].as_ref()
+    }
 }
 
 impl From<[u8; MC_REGION_HEADER_SIZE]> for McRegionHeader {
@@ -121,7 +129,7 @@ mod tests {
                 .unwrap(),
         };
         let chunks = vec![];
-        let anvil_save = AnvilSave::new(header, chunks);
+        let anvil_save: AnvilSave = AnvilSave::new(header, chunks);
         assert_eq!(
             anvil_save,
             AnvilSave {
@@ -154,6 +162,27 @@ mod tests {
         assert_eq!(header.get_chunk_info(), &expect);
     }
 
+    #[test]
+    fn test_chunk_info_by_position() {
+        let mut raw = [0; MC_REGION_HEADER_SIZE];
+        // Chunk 31, 0 of the region is stored at index 31
+        raw[31 * CHUNK_OFFSET_LENGTH..32 * CHUNK_OFFSET_LENGTH].copy_from_slice(&[0, 0, 2, 1]);
+        let header = McRegionHeader::from(raw);
+        assert_eq!(
+            header.chunk_info(ChunkPos::new(-1, 0)),
+            Some(&ChunkInfo {
+                sector_count: 1,
+                offset: 2,
+                timestamp: 0,
+            })
+        );
+        assert_eq!(
+            header.chunk_info(ChunkPos::new(31, 0)),
+            header.chunk_info(ChunkPos::new(-1, 0))
+        );
+        assert_eq!(header.chunk_info(ChunkPos::new(0, 0)), None);
+    }
+
     #[test]
     fn test_chunk_info_get() {
         let chunk_info = ChunkInfo {
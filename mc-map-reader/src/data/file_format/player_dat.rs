@@ -2,6 +2,7 @@
 
 use jbe::Builder;
 
+pub use crate::data::load::file_format::player_dat::*;
 use crate::{
     data::{
         entity::{Entity, Mob},
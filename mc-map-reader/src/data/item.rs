@@ -2,17 +2,86 @@ use std::collections::HashMap;
 
 use jbe::Builder;
 
-use crate::nbt::Tag;
+use crate::{
+    data::text_component::{plain_text_from_tag, TextFormat},
+    nbt::Tag,
+};
+
+/// Where legacy items store the data of a component as `(component, path in tag)`.
+const LEGACY_COMPONENT_PATHS: [(&str, &[&str]); 18] = [
+    ("minecraft:attribute_modifiers", &["AttributeModifiers"]),
+    ("minecraft:block_entity_data", &["BlockEntityTag"]),
+    ("minecraft:bundle_contents", &["Items"]),
+    ("minecraft:container", &["BlockEntityTag", "Items"]),
+    ("minecraft:custom_model_data", &["CustomModelData"]),
+    ("minecraft:custom_name", &["display", "Name"]),
+    ("minecraft:damage", &["Damage"]),
+    ("minecraft:dyed_color", &["display", "color"]),
+    ("minecraft:enchantments", &["Enchantments"]),
+    ("minecraft:entity_data", &["EntityTag"]),
+    ("minecraft:lore", &["display", "Lore"]),
+    ("minecraft:map_color", &["display", "MapColor"]),
+    ("minecraft:map_id", &["map"]),
+    ("minecraft:potion_contents", &["Potion"]),
+    ("minecraft:repair_cost", &["RepairCost"]),
+    ("minecraft:stored_enchantments", &["StoredEnchantments"]),
+    ("minecraft:trim", &["Trim"]),
+    ("minecraft:unbreakable", &["Unbreakable"]),
+];
 
 /// Representation of an item.
-/// [Minecraft Wiki](https://minecraft.fandom.com/wiki/Player.dat_format#Item_structure)
+/// Items saved before 1.20.5 store their data in `tag`, newer items in `components`.
+/// Use [`Item::get`] to read data independent of the format.
+/// [Minecraft Wiki](https://minecraft.wiki/w/Item_format)
 #[derive(Debug, Builder, Clone, PartialEq)]
 pub struct Item {
     /// Internal item ID
     pub id: String,
     pub tag: Option<HashMap<String, Tag>>,
+    /// Item components used since 1.20.5 e.g. `minecraft:container`
+    pub components: Option<HashMap<String, Tag>>,
     /// Stack size
-    pub count: i8,
+    pub count: i32,
+}
+
+/// An enchantment of an item or enchanted book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enchantment {
+    /// Enchantment id e.g. `minecraft:sharpness`
+    pub id: String,
+    pub level: i32,
+}
+
+/// The content of a written book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrittenBook {
+    pub title: String,
+    pub author: String,
+    /// 0 for the original, 1 for a copy, 2 for a copy of a copy and 3 for tattered books
+    pub generation: i32,
+    /// The plain text of every page
+    pub pages: Vec<String>,
+    pub resolved: bool,
+}
+
+/// A modifier of an attribute like `minecraft:generic.attack_damage` applied while the item is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    pub attribute: String,
+    pub amount: f64,
+    /// `add_value`, `add_multiplied_base` or `add_multiplied_total`
+    pub operation: String,
+    /// The equipment slot the item has to be in. `None` applies in every slot.
+    pub slot: Option<String>,
+}
+
+/// The layouts used to store items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemFormat {
+    /// Before 1.20.5 the stack size is stored in the byte `Count` and other data in `tag`.
+    Legacy,
+    /// Since 1.20.5 the stack size is stored in the int `count` and other data in `components`.
+    Components,
 }
 
 /// Representation of an item inside a slot. This type is used if something takes more than one item.
@@ -23,3 +92,889 @@ pub struct ItemWithSlot {
     /// Item
     pub item: Item,
 }
+
+/// The slot of an item inside of another item like a shulker box or a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContentSlot {
+    /// Id of the item containing the item e.g. `minecraft:shulker_box`
+    pub container: String,
+    /// Slot inside of the container. Bundles use the index of the item.
+    pub slot: i32,
+}
+
+/// The contents of an item created by [`Item::walk_contents`].
+/// Nested items are stored as NBT inside of their container, so they are parsed once
+/// and kept here to hand out references to them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ItemContents {
+    /// Every item with the slots leading to it in depth first order.
+    items: Vec<(Vec<ContentSlot>, Item)>,
+}
+
+impl ItemFormat {
+    /// Guess the format of an item from its keys.
+    /// The keys of both formats differ, so items are read without knowing the DataVersion.
+    pub fn detect(nbt_data: &HashMap<String, Tag>) -> Self {
+        if nbt_data.contains_key("Count") || nbt_data.contains_key("tag") {
+            Self::Legacy
+        } else {
+            Self::Components
+        }
+    }
+}
+
+impl Item {
+    /// Get the data of a component like `minecraft:custom_name` or `custom_name`.
+    /// Legacy items look the component up at its location in `tag`.
+    /// The value is returned as stored, so its layout depends on the format of the item.
+    pub fn get(&self, component: &str) -> Option<&Tag> {
+        let component = if component.contains(':') {
+            component.to_string()
+        } else {
+            format!("minecraft:{component}")
+        };
+        if let Some(components) = &self.components {
+            return components.get(&component);
+        }
+        let tag = self.tag.as_ref()?;
+        let (_, path) = LEGACY_COMPONENT_PATHS
+            .iter()
+            .find(|(name, _)| *name == component)?;
+        let (last, parents) = path.split_last()?;
+        let mut compound = tag;
+        for key in parents {
+            let Some(Tag::Compound(child)) = compound.get(*key) else {
+                return None;
+            };
+            compound = child;
+        }
+        compound.get(*last)
+    }
+
+    /// The enchantments of the item. Levels are not capped to vanilla maximums.
+    pub fn enchantments(&self) -> Vec<Enchantment> {
+        self.get("minecraft:enchantments")
+            .map(enchantment_list)
+            .unwrap_or_default()
+    }
+
+    /// The enchantments stored in an enchanted book.
+    pub fn stored_enchantments(&self) -> Vec<Enchantment> {
+        self.get("minecraft:stored_enchantments")
+            .map(enchantment_list)
+            .unwrap_or_default()
+    }
+
+    /// The plain text of the custom name.
+    /// `format` is the text format of the file the item was read from, see [`Item::text_format`].
+    pub fn custom_name(&self, format: TextFormat) -> Option<String> {
+        let format = self.text_format(format);
+        self.get("minecraft:custom_name")
+            .map(|name| plain_text_from_tag(name, format))
+    }
+
+    /// The plain text of every lore line.
+    pub fn lore(&self, format: TextFormat) -> Vec<String> {
+        let format = self.text_format(format);
+        match self.get("minecraft:lore") {
+            Some(Tag::List(lines)) => lines
+                .iter()
+                .map(|line| plain_text_from_tag(line, format))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The format of the text components of the item in a file using `format`.
+    /// Legacy items are only found in files older than 1.20.5 and always use JSON.
+    pub fn text_format(&self, format: TextFormat) -> TextFormat {
+        match self.components {
+            Some(_) => format,
+            None => TextFormat::Json,
+        }
+    }
+
+    /// The damage taken by a tool or armor piece.
+    pub fn damage(&self) -> Option<i32> {
+        self.get("minecraft:damage").and_then(int)
+    }
+
+    pub fn unbreakable(&self) -> bool {
+        match self.get("minecraft:unbreakable") {
+            // Legacy items store a flag, components are present or absent
+            Some(Tag::Byte(unbreakable)) => *unbreakable != 0,
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// The potion id of potions, splash potions, lingering potions and tipped arrows.
+    pub fn potion(&self) -> Option<String> {
+        match self.get("minecraft:potion_contents")? {
+            Tag::String(potion) => Some(potion.clone()),
+            Tag::Compound(contents) => match contents.get("potion") {
+                Some(Tag::String(potion)) => Some(potion.clone()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The content of a written book. `None` for every other item including book and quills.
+    pub fn written_book(&self, format: TextFormat) -> Option<WrittenBook> {
+        if let Some(components) = &self.components {
+            let Some(Tag::Compound(book)) = components.get("minecraft:written_book_content") else {
+                return None;
+            };
+            return Some(WrittenBook {
+                title: book.get("title").map(filterable_string).unwrap_or_default(),
+                author: string(book.get("author")),
+                generation: book.get("generation").and_then(int).unwrap_or_default(),
+                pages: match book.get("pages") {
+                    Some(Tag::List(pages)) => pages
+                        .iter()
+                        .map(|page| filterable_text(page, format))
+                        .collect(),
+                    _ => Vec::new(),
+                },
+                resolved: matches!(book.get("resolved"), Some(Tag::Byte(1))),
+            });
+        }
+        let tag = self.tag.as_ref()?;
+        if !tag.contains_key("title") {
+            return None;
+        }
+        Some(WrittenBook {
+            title: string(tag.get("title")),
+            author: string(tag.get("author")),
+            generation: tag.get("generation").and_then(int).unwrap_or_default(),
+            pages: match tag.get("pages") {
+                Some(Tag::List(pages)) => pages
+                    .iter()
+                    .map(|page| plain_text_from_tag(page, TextFormat::Json))
+                    .collect(),
+                _ => Vec::new(),
+            },
+            resolved: matches!(tag.get("resolved"), Some(Tag::Byte(1))),
+        })
+    }
+
+    /// Attribute modifiers added to the item with commands.
+    pub fn attribute_modifiers(&self) -> Vec<AttributeModifier> {
+        let modifiers = match self.get("minecraft:attribute_modifiers") {
+            // Before 1.21.5 components wrap the modifiers in a compound
+            Some(Tag::Compound(component)) => match component.get("modifiers") {
+                Some(Tag::List(modifiers)) => modifiers,
+                _ => return Vec::new(),
+            },
+            Some(Tag::List(modifiers)) => modifiers,
+            _ => return Vec::new(),
+        };
+        modifiers
+            .iter()
+            .filter_map(|modifier| match modifier {
+                Tag::Compound(modifier) if modifier.contains_key("AttributeName") => {
+                    legacy_attribute_modifier(modifier)
+                }
+                Tag::Compound(modifier) => Some(AttributeModifier {
+                    attribute: string(modifier.get("type")),
+                    amount: modifier.get("amount").and_then(float)?,
+                    operation: string(modifier.get("operation")),
+                    slot: optional_string(modifier.get("slot")),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Collect all items inside of this item and the items inside of them in depth first order.
+    /// Every item comes with the slots leading to it, starting with its slot in this item.
+    /// A `max_depth` of 1 only returns the items directly inside of this item.
+    ///
+    /// Contents are read from `BlockEntityTag.Items` of containers, `Items` of bundles
+    /// and the `minecraft:container` and `minecraft:bundle_contents` components.
+    pub fn walk_contents(&self, max_depth: usize) -> ItemContents {
+        let mut contents = ItemContents::default();
+        contents.push_contents(&[], self, max_depth);
+        contents
+    }
+
+    /// Items directly inside of this item with their slot.
+    pub fn contents(&self) -> Vec<(i32, Item)> {
+        let mut contents = Vec::new();
+        if let Some(tag) = &self.tag {
+            if let Some(Tag::Compound(block_entity)) = tag.get("BlockEntityTag") {
+                push_item_list(block_entity.get("Items"), &mut contents);
+            }
+            push_item_list(tag.get("Items"), &mut contents);
+        }
+        if let Some(components) = &self.components {
+            if let Some(Tag::List(slots)) = components.get("minecraft:container") {
+                for slot in slots.iter() {
+                    let Tag::Compound(slot) = slot else {
+                        continue;
+                    };
+                    if let (Some(Tag::Int(index)), Some(item)) =
+                        (slot.get("slot"), slot.get("item"))
+                    {
+                        if let Some(item) = nested_item(item) {
+                            contents.push((*index, item));
+                        }
+                    }
+                }
+            }
+            push_item_list(components.get("minecraft:bundle_contents"), &mut contents);
+        }
+        contents
+    }
+}
+
+impl ItemContents {
+    /// Iterate over every item with the slots leading to it.
+    pub fn iter(&self) -> impl Iterator<Item = (&[ContentSlot], &Item)> {
+        self.items
+            .iter()
+            .map(|(path, item)| (path.as_slice(), item))
+    }
+
+    /// Number of items inside of the item including nested items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the item does not contain any items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn push_contents(&mut self, path: &[ContentSlot], item: &Item, max_depth: usize) {
+        if path.len() >= max_depth {
+            return;
+        }
+        for (slot, content) in item.contents() {
+            let mut path = path.to_vec();
+            path.push(ContentSlot {
+                container: item.id.clone(),
+                slot,
+            });
+            let mut nested = ItemContents::default();
+            nested.push_contents(&path, &content, max_depth);
+            self.items.push((path, content));
+            self.items.append(&mut nested.items);
+        }
+    }
+}
+
+/// Add all items of a list. Items without a `Slot` use their index as slot.
+fn push_item_list(items: Option<&Tag>, contents: &mut Vec<(i32, Item)>) {
+    let Some(Tag::List(items)) = items else {
+        return;
+    };
+    for (index, tag) in items.iter().enumerate() {
+        let slot = match tag {
+            Tag::Compound(item) => match item.get("Slot") {
+                Some(Tag::Byte(slot)) => *slot as i32,
+                _ => index as i32,
+            },
+            _ => continue,
+        };
+        if let Some(item) = nested_item(tag) {
+            contents.push((slot, item));
+        }
+    }
+}
+
+/// Read enchantments from a legacy list or a component.
+fn enchantment_list(enchantments: &Tag) -> Vec<Enchantment> {
+    match enchantments {
+        Tag::List(enchantments) => enchantments
+            .iter()
+            .filter_map(|enchantment| {
+                let Tag::Compound(enchantment) = enchantment else {
+                    return None;
+                };
+                let id = match enchantment.get("id")? {
+                    Tag::String(id) => id.clone(),
+                    // Before 1.13 enchantments have numeric ids
+                    other => int(other)?.to_string(),
+                };
+                Some(Enchantment {
+                    id,
+                    level: enchantment.get("lvl").and_then(int)?,
+                })
+            })
+            .collect(),
+        Tag::Compound(component) => {
+            // Before 1.21.5 the levels are wrapped in a compound
+            let levels = match component.get("levels") {
+                Some(Tag::Compound(levels)) => levels,
+                _ => component,
+            };
+            let mut enchantments = levels
+                .iter()
+                .filter_map(|(id, level)| {
+                    Some(Enchantment {
+                        id: id.clone(),
+                        level: int(level)?,
+                    })
+                })
+                .collect::<Vec<_>>();
+            enchantments.sort_by(|a, b| a.id.cmp(&b.id));
+            enchantments
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn legacy_attribute_modifier(modifier: &HashMap<String, Tag>) -> Option<AttributeModifier> {
+    const OPERATIONS: [&str; 3] = ["add_value", "add_multiplied_base", "add_multiplied_total"];
+    let operation = modifier.get("Operation").and_then(int)?;
+    Some(AttributeModifier {
+        attribute: string(modifier.get("AttributeName")),
+        amount: modifier.get("Amount").and_then(float)?,
+        operation: OPERATIONS
+            .get(usize::try_from(operation).ok()?)?
+            .to_string(),
+        slot: optional_string(modifier.get("Slot")),
+    })
+}
+
+/// Books may store a filtered version of a text component next to the raw component.
+fn filterable_text(text: &Tag, format: TextFormat) -> String {
+    match text {
+        Tag::Compound(filterable) if filterable.contains_key("raw") => filterable
+            .get("raw")
+            .map(|raw| plain_text_from_tag(raw, format))
+            .unwrap_or_default(),
+        other => plain_text_from_tag(other, format),
+    }
+}
+
+/// Book titles are plain strings which may be filtered like [`filterable_text`].
+fn filterable_string(text: &Tag) -> String {
+    match text {
+        Tag::Compound(filterable) => string(filterable.get("raw")),
+        other => string(Some(other)),
+    }
+}
+
+fn int(tag: &Tag) -> Option<i32> {
+    match tag {
+        Tag::Byte(value) => Some(*value as i32),
+        Tag::Short(value) => Some(*value as i32),
+        Tag::Int(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn float(tag: &Tag) -> Option<f64> {
+    match tag {
+        Tag::Float(value) => Some(*value as f64),
+        Tag::Double(value) => Some(*value),
+        other => int(other).map(f64::from),
+    }
+}
+
+fn string(tag: Option<&Tag>) -> String {
+    optional_string(tag).unwrap_or_default()
+}
+
+fn optional_string(tag: Option<&Tag>) -> Option<String> {
+    match tag {
+        Some(Tag::String(value)) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+fn nested_item(tag: &Tag) -> Option<Item> {
+    match Item::try_from(tag.clone()) {
+        Ok(item) => Some(item),
+        Err(e) => {
+            log::warn!("Could not parse nested item: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nbt::List;
+
+    use super::*;
+    use test_case::test_case;
+
+    fn compound(values: Vec<(&str, Tag)>) -> HashMap<String, Tag> {
+        values
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
+    }
+
+    fn legacy_item(id: &str, slot: Option<i8>, tag: Option<HashMap<String, Tag>>) -> Tag {
+        let mut item = compound(vec![
+            ("id", Tag::String(id.to_string())),
+            ("Count", Tag::Byte(1)),
+        ]);
+        if let Some(slot) = slot {
+            item.insert("Slot".to_string(), Tag::Byte(slot));
+        }
+        if let Some(tag) = tag {
+            item.insert("tag".to_string(), Tag::Compound(tag));
+        }
+        Tag::Compound(item)
+    }
+
+    fn component_item(id: &str, components: Option<HashMap<String, Tag>>) -> Tag {
+        let mut item = compound(vec![
+            ("id", Tag::String(id.to_string())),
+            ("count", Tag::Int(2)),
+        ]);
+        if let Some(components) = components {
+            item.insert("components".to_string(), Tag::Compound(components));
+        }
+        Tag::Compound(item)
+    }
+
+    fn item(
+        id: &str,
+        tag: Option<HashMap<String, Tag>>,
+        components: Option<HashMap<String, Tag>>,
+    ) -> Item {
+        Item {
+            id: id.to_string(),
+            tag,
+            components,
+            count: 1,
+        }
+    }
+
+    /// A shulker box containing a bundle with a diamond and a shulker box containing dirt.
+    fn legacy_shulker_box() -> Item {
+        let inner_shulker = legacy_item(
+            "minecraft:shulker_box",
+            None,
+            Some(compound(vec![(
+                "BlockEntityTag",
+                Tag::Compound(compound(vec![(
+                    "Items",
+                    Tag::List(List::from(vec![legacy_item(
+                        "minecraft:dirt",
+                        Some(5),
+                        None,
+                    )])),
+                )])),
+            )])),
+        );
+        let bundle = legacy_item(
+            "minecraft:bundle",
+            Some(3),
+            Some(compound(vec![(
+                "Items",
+                Tag::List(List::from(vec![
+                    legacy_item("minecraft:diamond", None, None),
+                    inner_shulker,
+                ])),
+            )])),
+        );
+        item(
+            "minecraft:shulker_box",
+            Some(compound(vec![(
+                "BlockEntityTag",
+                Tag::Compound(compound(vec![(
+                    "Items",
+                    Tag::List(List::from(vec![bundle])),
+                )])),
+            )])),
+            None,
+        )
+    }
+
+    fn component_shulker_box() -> Item {
+        let bundle = component_item(
+            "minecraft:bundle",
+            Some(compound(vec![(
+                "minecraft:bundle_contents",
+                Tag::List(List::from(vec![component_item("minecraft:diamond", None)])),
+            )])),
+        );
+        item(
+            "minecraft:shulker_box",
+            None,
+            Some(compound(vec![(
+                "minecraft:container",
+                Tag::List(List::from(vec![Tag::Compound(compound(vec![
+                    ("slot", Tag::Int(7)),
+                    ("item", bundle),
+                ]))])),
+            )])),
+        )
+    }
+
+    /// The slots leading to every item and its id.
+    fn walk(item: &Item, max_depth: usize) -> Vec<(Vec<i32>, String)> {
+        item.walk_contents(max_depth)
+            .iter()
+            .map(|(path, item)| (path.iter().map(|slot| slot.slot).collect(), item.id.clone()))
+            .collect()
+    }
+
+    #[test_case(usize::MAX => vec![
+        (vec![3], "minecraft:bundle".to_string()),
+        (vec![3, 0], "minecraft:diamond".to_string()),
+        (vec![3, 1], "minecraft:shulker_box".to_string()),
+        (vec![3, 1, 5], "minecraft:dirt".to_string()),
+    ]; "Unlimited")]
+    #[test_case(2 => vec![
+        (vec![3], "minecraft:bundle".to_string()),
+        (vec![3, 0], "minecraft:diamond".to_string()),
+        (vec![3, 1], "minecraft:shulker_box".to_string()),
+    ]; "Depth 2")]
+    #[test_case(0 => Vec::<(Vec<i32>, String)>::new(); "Depth 0")]
+    fn test_walk_legacy_contents(max_depth: usize) -> Vec<(Vec<i32>, String)> {
+        walk(&legacy_shulker_box(), max_depth)
+    }
+
+    #[test]
+    fn test_walk_component_contents() {
+        let walk = component_shulker_box().walk_contents(usize::MAX);
+        let contents = walk.iter().collect::<Vec<_>>();
+        assert_eq!(contents.len(), 2);
+        assert_eq!(
+            contents[0].0,
+            vec![ContentSlot {
+                container: "minecraft:shulker_box".to_string(),
+                slot: 7
+            }]
+        );
+        assert_eq!(contents[0].1.id, "minecraft:bundle");
+        assert_eq!(contents[0].1.count, 2);
+        assert_eq!(
+            contents[1].0,
+            vec![
+                ContentSlot {
+                    container: "minecraft:shulker_box".to_string(),
+                    slot: 7
+                },
+                ContentSlot {
+                    container: "minecraft:bundle".to_string(),
+                    slot: 0
+                },
+            ]
+        );
+        assert_eq!(
+            contents[1].1,
+            &Item {
+                id: "minecraft:diamond".to_string(),
+                tag: None,
+                components: None,
+                count: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_walk_empty() {
+        assert_eq!(
+            item("minecraft:diamond", None, None)
+                .walk_contents(usize::MAX)
+                .len(),
+            0
+        );
+    }
+
+    #[test_case(vec![("Count", Tag::Byte(1))] => ItemFormat::Legacy; "Count")]
+    #[test_case(vec![("tag", Tag::Compound(HashMap::new()))] => ItemFormat::Legacy; "Tag")]
+    #[test_case(vec![("count", Tag::Int(1))] => ItemFormat::Components; "Lowercase count")]
+    #[test_case(vec![] => ItemFormat::Components; "Only id")]
+    fn test_detect_format(keys: Vec<(&str, Tag)>) -> ItemFormat {
+        let mut nbt_data = compound(keys);
+        nbt_data.insert("id".to_string(), Tag::String("minecraft:stone".to_string()));
+        ItemFormat::detect(&nbt_data)
+    }
+
+    fn named_legacy_item() -> Item {
+        item(
+            "minecraft:diamond_sword",
+            Some(compound(vec![
+                (
+                    "display",
+                    Tag::Compound(compound(vec![("Name", Tag::String("Sword".to_string()))])),
+                ),
+                ("Damage", Tag::Int(5)),
+            ])),
+            None,
+        )
+    }
+
+    fn named_component_item() -> Item {
+        item(
+            "minecraft:diamond_sword",
+            None,
+            Some(compound(vec![
+                ("minecraft:custom_name", Tag::String("Sword".to_string())),
+                ("minecraft:damage", Tag::Int(5)),
+            ])),
+        )
+    }
+
+    #[test_case(named_legacy_item(), "minecraft:custom_name" => Some(Tag::String("Sword".to_string())); "Legacy nested")]
+    #[test_case(named_legacy_item(), "damage" => Some(Tag::Int(5)); "Legacy without namespace")]
+    #[test_case(named_legacy_item(), "minecraft:lore" => None; "Legacy missing")]
+    #[test_case(named_legacy_item(), "minecraft:food" => None; "Legacy unknown")]
+    #[test_case(named_component_item(), "minecraft:custom_name" => Some(Tag::String("Sword".to_string())); "Components")]
+    #[test_case(named_component_item(), "damage" => Some(Tag::Int(5)); "Components without namespace")]
+    #[test_case(item("minecraft:stone", None, None), "damage" => None; "No data")]
+    fn test_get(item: Item, component: &str) -> Option<Tag> {
+        item.get(component).cloned()
+    }
+
+    fn string_tag(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn legacy_sword() -> Item {
+        item(
+            "minecraft:diamond_sword",
+            Some(compound(vec![
+                (
+                    "display",
+                    Tag::Compound(compound(vec![
+                        ("Name", string_tag(r#"{"text":"Excalibur"}"#)),
+                        (
+                            "Lore",
+                            Tag::List(List::from(vec![
+                                string_tag(r#""Line 1""#),
+                                string_tag(r#"{"text":"Line 2"}"#),
+                            ])),
+                        ),
+                    ])),
+                ),
+                (
+                    "Enchantments",
+                    Tag::List(List::from(vec![
+                        Tag::Compound(compound(vec![
+                            ("id", string_tag("minecraft:sharpness")),
+                            ("lvl", Tag::Short(10)),
+                        ])),
+                        Tag::Compound(compound(vec![
+                            ("id", Tag::Short(34)),
+                            ("lvl", Tag::Short(3)),
+                        ])),
+                    ])),
+                ),
+                ("Damage", Tag::Int(12)),
+                ("Unbreakable", Tag::Byte(1)),
+                (
+                    "AttributeModifiers",
+                    Tag::List(List::from(vec![Tag::Compound(compound(vec![
+                        ("AttributeName", string_tag("generic.attack_damage")),
+                        ("Amount", Tag::Double(100.0)),
+                        ("Operation", Tag::Int(1)),
+                        ("Slot", string_tag("mainhand")),
+                    ]))])),
+                ),
+            ])),
+            None,
+        )
+    }
+
+    fn component_sword(wrapped: bool) -> Item {
+        let levels = Tag::Compound(compound(vec![
+            ("minecraft:sharpness", Tag::Int(10)),
+            ("minecraft:unbreaking", Tag::Int(3)),
+        ]));
+        let modifiers = Tag::List(List::from(vec![Tag::Compound(compound(vec![
+            ("type", string_tag("minecraft:generic.attack_damage")),
+            ("amount", Tag::Double(100.0)),
+            ("operation", string_tag("add_multiplied_base")),
+            ("slot", string_tag("mainhand")),
+        ]))]));
+        // Since 1.21.5 text components are NBT instead of JSON as well
+        let (enchantments, modifiers, name, first_lore_line) = if wrapped {
+            (
+                Tag::Compound(compound(vec![("levels", levels)])),
+                Tag::Compound(compound(vec![("modifiers", modifiers)])),
+                string_tag(r#"{"text":"Excalibur"}"#),
+                string_tag(r#""Line 1""#),
+            )
+        } else {
+            (
+                levels,
+                modifiers,
+                string_tag("Excalibur"),
+                string_tag("Line 1"),
+            )
+        };
+        item(
+            "minecraft:diamond_sword",
+            None,
+            Some(compound(vec![
+                ("minecraft:custom_name", name),
+                (
+                    "minecraft:lore",
+                    Tag::List(List::from(vec![
+                        first_lore_line,
+                        Tag::Compound(compound(vec![("text", string_tag("Line 2"))])),
+                    ])),
+                ),
+                ("minecraft:enchantments", enchantments),
+                ("minecraft:damage", Tag::Int(12)),
+                ("minecraft:unbreakable", Tag::Compound(HashMap::new())),
+                ("minecraft:attribute_modifiers", modifiers),
+            ])),
+        )
+    }
+
+    #[test_case(legacy_sword(), "34", TextFormat::Json; "Legacy")]
+    #[test_case(legacy_sword(), "34", TextFormat::Nbt; "Legacy in NBT text file")]
+    #[test_case(component_sword(true), "minecraft:unbreaking", TextFormat::Json; "Components 1.20.5")]
+    #[test_case(component_sword(false), "minecraft:unbreaking", TextFormat::Nbt; "Components 1.21.5")]
+    fn test_typed_accessors(item: Item, second_enchantment: &str, format: TextFormat) {
+        assert_eq!(
+            item.enchantments(),
+            vec![
+                Enchantment {
+                    id: "minecraft:sharpness".to_string(),
+                    level: 10
+                },
+                Enchantment {
+                    id: second_enchantment.to_string(),
+                    level: 3
+                },
+            ]
+        );
+        assert_eq!(item.stored_enchantments(), vec![]);
+        assert_eq!(item.custom_name(format), Some("Excalibur".to_string()));
+        assert_eq!(item.lore(format), vec!["Line 1", "Line 2"]);
+        assert_eq!(item.damage(), Some(12));
+        assert!(item.unbreakable());
+        assert_eq!(item.potion(), None);
+        assert_eq!(item.written_book(format), None);
+        let modifiers = item.attribute_modifiers();
+        assert_eq!(modifiers.len(), 1);
+        assert!(modifiers[0].attribute.ends_with("generic.attack_damage"));
+        assert_eq!(modifiers[0].amount, 100.0);
+        assert_eq!(modifiers[0].operation, "add_multiplied_base");
+        assert_eq!(modifiers[0].slot.as_deref(), Some("mainhand"));
+    }
+
+    #[test]
+    fn test_plain_item_accessors() {
+        let item = item("minecraft:stone", None, None);
+        assert_eq!(item.enchantments(), vec![]);
+        assert_eq!(item.custom_name(TextFormat::Json), None);
+        assert_eq!(item.lore(TextFormat::Json), Vec::<String>::new());
+        assert_eq!(item.damage(), None);
+        assert!(!item.unbreakable());
+        assert_eq!(item.attribute_modifiers(), vec![]);
+    }
+
+    #[test_case(TextFormat::Json => Some("10064-200".to_string()); "JSON")]
+    #[test_case(TextFormat::Nbt => Some("[100, 64, -200]".to_string()); "NBT")]
+    fn test_custom_name_format(format: TextFormat) -> Option<String> {
+        item(
+            "minecraft:paper",
+            None,
+            Some(compound(vec![(
+                "minecraft:custom_name",
+                string_tag("[100, 64, -200]"),
+            )])),
+        )
+        .custom_name(format)
+    }
+
+    #[test_case(item("minecraft:enchanted_book", Some(compound(vec![(
+        "StoredEnchantments",
+        Tag::List(List::from(vec![Tag::Compound(compound(vec![
+            ("id", string_tag("minecraft:mending")),
+            ("lvl", Tag::Short(1)),
+        ]))])),
+    )])), None); "Legacy")]
+    #[test_case(item("minecraft:enchanted_book", None, Some(compound(vec![(
+        "minecraft:stored_enchantments",
+        Tag::Compound(compound(vec![(
+            "levels",
+            Tag::Compound(compound(vec![("minecraft:mending", Tag::Int(1))])),
+        )])),
+    )]))); "Components")]
+    fn test_stored_enchantments(item: Item) {
+        assert_eq!(
+            item.stored_enchantments(),
+            vec![Enchantment {
+                id: "minecraft:mending".to_string(),
+                level: 1
+            }]
+        );
+        assert_eq!(item.enchantments(), vec![]);
+    }
+
+    #[test_case(item("minecraft:potion", Some(compound(vec![("Potion", string_tag("minecraft:healing"))])), None) => Some("minecraft:healing".to_string()); "Legacy")]
+    #[test_case(item("minecraft:potion", None, Some(compound(vec![(
+        "minecraft:potion_contents",
+        Tag::Compound(compound(vec![("potion", string_tag("minecraft:healing"))])),
+    )]))) => Some("minecraft:healing".to_string()); "Components")]
+    #[test_case(item("minecraft:potion", None, Some(compound(vec![(
+        "minecraft:potion_contents",
+        string_tag("minecraft:healing"),
+    )]))) => Some("minecraft:healing".to_string()); "Components shorthand")]
+    #[test_case(item("minecraft:potion", None, Some(compound(vec![(
+        "minecraft:potion_contents",
+        Tag::Compound(compound(vec![("custom_color", Tag::Int(1))])),
+    )]))) => None; "Custom potion")]
+    fn test_potion(item: Item) -> Option<String> {
+        item.potion()
+    }
+
+    fn expected_book() -> Option<WrittenBook> {
+        Some(WrittenBook {
+            title: "Coords".to_string(),
+            author: "Steve".to_string(),
+            generation: 1,
+            pages: vec!["Page 1".to_string(), "Page 2".to_string()],
+            resolved: true,
+        })
+    }
+
+    #[test_case(item("minecraft:written_book", Some(compound(vec![
+        ("title", string_tag("Coords")),
+        ("author", string_tag("Steve")),
+        ("generation", Tag::Int(1)),
+        ("resolved", Tag::Byte(1)),
+        ("pages", Tag::List(List::from(vec![
+            string_tag(r#"{"text":"Page 1"}"#),
+            string_tag(r#""Page 2""#),
+        ]))),
+    ])), None), TextFormat::Json => expected_book(); "Legacy")]
+    #[test_case(item("minecraft:written_book", None, Some(compound(vec![(
+        "minecraft:written_book_content",
+        Tag::Compound(compound(vec![
+            ("title", Tag::Compound(compound(vec![("raw", string_tag("Coords"))]))),
+            ("author", string_tag("Steve")),
+            ("generation", Tag::Int(1)),
+            ("resolved", Tag::Byte(1)),
+            ("pages", Tag::List(List::from(vec![
+                Tag::Compound(compound(vec![("raw", string_tag(r#"{"text":"Page 1"}"#))])),
+                string_tag(r#""Page 2""#),
+            ]))),
+        ])),
+    )]))), TextFormat::Json => expected_book(); "Components")]
+    #[test_case(item("minecraft:written_book", None, Some(compound(vec![(
+        "minecraft:written_book_content",
+        Tag::Compound(compound(vec![
+            ("title", Tag::Compound(compound(vec![("raw", string_tag("Coords"))]))),
+            ("author", string_tag("Steve")),
+            ("generation", Tag::Int(1)),
+            ("resolved", Tag::Byte(1)),
+            ("pages", Tag::List(List::from(vec![
+                Tag::Compound(compound(vec![("raw", string_tag("Page 1"))])),
+                Tag::Compound(compound(vec![("text", string_tag("Page 2"))])),
+            ]))),
+        ])),
+    )]))), TextFormat::Nbt => expected_book(); "Components 1.21.5")]
+    #[test_case(item("minecraft:writable_book", Some(compound(vec![
+        ("pages", Tag::List(List::from(vec![string_tag("draft")]))),
+    ])), None), TextFormat::Json => None; "Writable book")]
+    fn test_written_book(item: Item, format: TextFormat) -> Option<WrittenBook> {
+        item.written_book(format)
+    }
+}
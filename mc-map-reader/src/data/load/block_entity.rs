@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 
 use crate::{
-    data::{block_entity::*, load::entity::EntityError, FieldError},
-    nbt::Tag,
+    data::{
+        block_entity::*,
+        item::{Item, ItemWithSlot},
+        load::entity::EntityError,
+        FieldError,
+    },
+    nbt::{List, Tag},
 };
 
 use crate::data::load::item::*;
@@ -15,12 +20,15 @@ mod_try_from_tag!(
         Beehive,
         BlastFurnace,
         BrewingStand,
+        BrushableBlock,
         Campfire,
         ChiseledBookshelf,
         Chest,
         Comparator,
         CommandBlock,
         Conduit,
+        Crafter,
+        DecoratedPot,
         Dispenser,
         Dropper,
         EnchantingTable,
@@ -32,6 +40,9 @@ mod_try_from_tag!(
         Lectern,
         MobSpawner,
         Piston,
+        SculkCatalyst,
+        SculkSensor,
+        SculkShrieker,
         ShulkerBox,
         Sign,
         Skull,
@@ -39,6 +50,8 @@ mod_try_from_tag!(
         SoulCampfire,
         StructureBlock,
         TrappedChest,
+        TrialSpawner,
+        Vault,
     ],
     Beehive: [
         "Bees" => set_bees test(List::from(vec![BeeInHive_test_data_provider().into()]) => bees = Some(List::from(vec![BeeInHive_test_result()]))),
@@ -85,6 +98,14 @@ mod_try_from_tag!(
         "Items" => set_items test(List::from(vec![crate::data::load::item::tests::item_with_slot_test_data_provider().into()]) => items = Some(List::from(vec![crate::data::load::item::tests::item_with_slot_test_result()]))),
         "Lock" => set_lock test("Lock".to_string() => lock = Some("Lock".to_string())),
     ] ? [ ItemWithSlot, ],
+    BrushableBlock: [
+        "hit_direction" => set_hit_direction test(1i32 => hit_direction = Some(1)),
+        "item" => set_item test(crate::data::load::item::tests::Item_test_data_provider() => item = Some(crate::data::load::item::tests::Item_test_result())),
+        "LootTable" => set_loot_table test("loot_table".to_string() => loot_table = Some("loot_table".to_string())),
+        "LootTableSeed" => set_loot_table_seed test(1i64 => loot_table_seed = Some(1)),
+    ] ? [
+        Item,
+    ],
     Campfire: [
         "CookingTimes" => set_cooking_times test(Array::from(vec![1_i32]) => cooking_times = Array::from(vec![1]); CampfireBuilderError::UnsetCookingTimes),
         "CookingTotalTimes" => set_cooking_total_times test(Array::from(vec![1_i32]) => cooking_total_times = Array::from(vec![1_i32]); CampfireBuilderError::UnsetCookingTotalTimes),
@@ -117,6 +138,8 @@ mod_try_from_tag!(
     Conduit: [
         "Target" => set_target test(Array::from(vec![10_i32,32]) => target = Array::from(vec![10_i32,32]); ConduitBuilderError::UnsetTarget),
     ],
+    Crafter: parse_crafter ? [ ItemWithSlot, ],
+    DecoratedPot: parse_decorated_pot ? [ Item, ],
     Dispenser: parse_inventory_block_entity ? [ ItemWithSlot, ],
     Dropper: parse_inventory_block_entity ? [ ItemWithSlot, ],
     EnchantingTable: [
@@ -145,14 +168,14 @@ mod_try_from_tag!(
     ],
     Jukebox: [
         "IsPlaying" => set_is_playing test(1i8 => is_playing = true; JukeboxBuilderError::UnsetIsPlaying),
-        "RecordItem" => set_record_item test(crate::data::load::item::macro_tests::Item_test_data_provider() => record_item = crate::data::load::item::macro_tests::Item_test_result(); JukeboxBuilderError::UnsetRecordItem),
+        "RecordItem" => set_record_item test(crate::data::load::item::tests::Item_test_data_provider() => record_item = crate::data::load::item::tests::Item_test_result(); JukeboxBuilderError::UnsetRecordItem),
         "RecordStartTick" => set_record_start_tick test(1i64 => record_start_tick = 1; JukeboxBuilderError::UnsetRecordStartTick),
         "TickCount" => set_tick_count test(1i64 => tick_count = 1; JukeboxBuilderError::UnsetTickCount),
     ] ? [
         Item,
     ],
     Lectern: [
-        "Book" => set_book test(crate::data::load::item::macro_tests::Item_test_data_provider() => book = Some(crate::data::load::item::macro_tests::Item_test_result())),
+        "Book" => set_book test(crate::data::load::item::tests::Item_test_data_provider() => book = Some(crate::data::load::item::tests::Item_test_result())),
         "Page" => set_page test(1i32 => page = Some(1)),
     ] ? [
         Item,
@@ -187,15 +210,29 @@ mod_try_from_tag!(
         "Name" => set_name test("name".to_string() => name = "name".to_string(); PistonBlockStateBuilderError::UnsetName),
         "Properties" => set_properties test(HashMap::new() => properties = HashMap::new(); PistonBlockStateBuilderError::UnsetProperties),
     ],
-    ShulkerBox: parse_inventory_block_entity ? [ ItemWithSlot, ],
-    Sign: [
-        "GlowingText" => set_glowing_text test(1i8 => glowing_text = true; SignBuilderError::UnsetGlowingText),
-        "Color" => set_color test("color".to_string() => color = "color".to_string(); SignBuilderError::UnsetColor),
-        "Text1" => set_text1 test("text1".to_string() => text1 = "text1".to_string(); SignBuilderError::UnsetText1),
-        "Text2" => set_text2 test("text2".to_string() => text2 = "text2".to_string(); SignBuilderError::UnsetText2),
-        "Text3" => set_text3 test("text3".to_string() => text3 = "text3".to_string(); SignBuilderError::UnsetText3),
-        "Text4" => set_text4 test("text4".to_string() => text4 = "text4".to_string(); SignBuilderError::UnsetText4),
+    SculkCatalyst: [
+        "cursors" => set_cursors test(List::from(vec![SculkCursor_test_data_provider().into()]) => cursors = Some(List::from(vec![SculkCursor_test_result()]))),
+    ] ? [
+        SculkCursor,
     ],
+    SculkCursor: [
+        "charge" => set_charge test(10i32 => charge = 10; SculkCursorBuilderError::UnsetCharge),
+        "decay_delay" => set_decay_delay test(1i32 => decay_delay = 1; SculkCursorBuilderError::UnsetDecayDelay),
+        "facings" => set_facings test(List::from(vec![Tag::from("up".to_string())]) => facings = Some(List::from(vec!["up".to_string()]))),
+        "pos" => set_pos test(Array::from(vec![1i32, 2, 3]) => pos = Array::from(vec![1, 2, 3]); SculkCursorBuilderError::UnsetPos),
+        "update_delay" => set_update_delay test(1i32 => update_delay = 1; SculkCursorBuilderError::UnsetUpdateDelay),
+    ],
+    SculkSensor: [
+        "last_vibration_frequency" => set_last_vibration_frequency test(1i32 => last_vibration_frequency = Some(1)),
+        "listener" => set_listener test(HashMap::<String, Tag>::new() => listener = Some(HashMap::new())),
+    ],
+    SculkShrieker: [
+        "listener" => set_listener test(HashMap::<String, Tag>::new() => listener = Some(HashMap::new())),
+        "warning_level" => set_warning_level test(1i32 => warning_level = Some(1)),
+    ],
+    ShulkerBox: parse_inventory_block_entity ? [ ItemWithSlot, ],
+    Sign: parse_sign ? [ SignText, ],
+    SignText: parse_sign_text,
     Skull: [
         "note_block_sound" => set_note_block_sound test("sound".to_string() => note_block_sound = Some("sound".to_string())),
         "ExtraType" => set_extra_type test("extra_type".to_string() => extra_type = Some("extra_type".to_string())),
@@ -251,6 +288,32 @@ mod_try_from_tag!(
         "sizeZ" => set_size_z test(1i32 => size_z = 1; StructureBlockBuilderError::UnsetSizeZ),
     ],
     TrappedChest: parse_inventory_block_entity ? [ ItemWithSlot, ],
+    TrialSpawner: [
+        "cooldown_ends_at" => set_cooldown_ends_at test(1i64 => cooldown_ends_at = Some(1)),
+        "current_mobs" => set_current_mobs test(List::from(vec![Tag::from(Array::from(vec![1i32, 2, 3, 4]))]) => current_mobs = Some(List::from(vec![Array::from(vec![1, 2, 3, 4])]))),
+        "ejecting_loot_table" => set_ejecting_loot_table test("loot_table".to_string() => ejecting_loot_table = Some("loot_table".to_string())),
+        "next_mob_spawns_at" => set_next_mob_spawns_at test(1i64 => next_mob_spawns_at = Some(1)),
+        "registered_players" => set_registered_players test(List::from(vec![Tag::from(Array::from(vec![1i32, 2, 3, 4]))]) => registered_players = Some(List::from(vec![Array::from(vec![1, 2, 3, 4])]))),
+        "required_player_range" => set_required_player_range test(14i32 => required_player_range = Some(14)),
+        "spawn_data" => set_spawn_data test(HashMap::<String, Tag>::new() => spawn_data = Some(HashMap::new())),
+        "target_cooldown_length" => set_target_cooldown_length test(36000i32 => target_cooldown_length = Some(36000)),
+        "total_mobs_spawned" => set_total_mobs_spawned test(1i32 => total_mobs_spawned = Some(1)),
+    ],
+    Vault: [
+        "config" => set_config test(HashMap::<String, Tag>::new() => config = Some(HashMap::new())),
+        "server_data" => set_server_data test(VaultServerData_test_data_provider() => server_data = Some(VaultServerData_test_result())),
+        "shared_data" => set_shared_data test(HashMap::<String, Tag>::new() => shared_data = Some(HashMap::new())),
+    ] ? [
+        VaultServerData,
+    ],
+    VaultServerData: [
+        "items_to_eject" => set_items_to_eject test(List::from(vec![crate::data::load::item::tests::Item_test_data_provider().into()]) => items_to_eject = Some(List::from(vec![crate::data::load::item::tests::Item_test_result()]))),
+        "rewarded_players" => set_rewarded_players test(List::from(vec![Tag::from(Array::from(vec![1i32, 2, 3, 4]))]) => rewarded_players = Some(List::from(vec![Array::from(vec![1, 2, 3, 4])]))),
+        "state_updating_resumes_at" => set_state_updating_resumes_at test(1i64 => state_updating_resumes_at = Some(1)),
+        "total_ejections_needed" => set_total_ejections_needed test(1i32 => total_ejections_needed = Some(1)),
+    ] ? [
+        Item,
+    ],
     MobSpawner: parse_mob_spawner ? [ Spawner, ],
 );
 
@@ -272,7 +335,7 @@ fn parse_block_entity(
 
     const ENTITY_TYPE_KEY: &str = "<internal> entity_type";
     let ty = match id.as_str() {
-        "minecraft:banners" => nbt_data
+        "minecraft:banner" => nbt_data
             .try_into()
             .map(BlockEntityType::Banner)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
@@ -298,6 +361,10 @@ fn parse_block_entity(
             .try_into()
             .map(BlockEntityType::BrewingStand)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:brushable_block" => nbt_data
+            .try_into()
+            .map(BlockEntityType::BrushableBlock)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         "minecraft:campfire" => nbt_data
             .try_into()
             .map(BlockEntityType::Campfire)
@@ -322,7 +389,15 @@ fn parse_block_entity(
             .try_into()
             .map(BlockEntityType::Conduit)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:crafter" => nbt_data
+            .try_into()
+            .map(BlockEntityType::Crafter)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         "minecraft:daylight_detector" => BlockEntityType::DaylightDetector,
+        "minecraft:decorated_pot" => nbt_data
+            .try_into()
+            .map(BlockEntityType::DecoratedPot)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         "minecraft:dispenser" => nbt_data
             .try_into()
             .map(BlockEntityType::Dispenser)
@@ -345,6 +420,10 @@ fn parse_block_entity(
             .try_into()
             .map(BlockEntityType::Furnace)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:hanging_sign" => nbt_data
+            .try_into()
+            .map(BlockEntityType::HangingSign)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         "minecraft:hopper" => nbt_data
             .try_into()
             .map(BlockEntityType::Hopper)
@@ -369,6 +448,18 @@ fn parse_block_entity(
             .try_into()
             .map(BlockEntityType::Piston)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:sculk_catalyst" => nbt_data
+            .try_into()
+            .map(BlockEntityType::SculkCatalyst)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:sculk_sensor" => nbt_data
+            .try_into()
+            .map(BlockEntityType::SculkSensor)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:sculk_shrieker" => nbt_data
+            .try_into()
+            .map(BlockEntityType::SculkShrieker)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         "minecraft:shulker_box" => nbt_data
             .try_into()
             .map(BlockEntityType::ShulkerBox)
@@ -397,6 +488,14 @@ fn parse_block_entity(
             .try_into()
             .map(BlockEntityType::TrappedChest)
             .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:trial_spawner" => nbt_data
+            .try_into()
+            .map(BlockEntityType::TrialSpawner)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
+        "minecraft:vault" => nbt_data
+            .try_into()
+            .map(BlockEntityType::Vault)
+            .map_err(|e| FieldError::new(ENTITY_TYPE_KEY, e))?,
         _ => BlockEntityType::Other(nbt_data),
     };
     builder.set_entity_type(ty);
@@ -471,14 +570,115 @@ fn parse_hopper(
     Ok(())
 }
 
+fn parse_sign(
+    builder: &mut SignBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), SignError> {
+    if !nbt_data.contains_key("front_text") {
+        let front_text =
+            parse_legacy_sign_text(nbt_data).map_err(|e| FieldError::new("front_text", e))?;
+        builder.set_front_text(front_text);
+        return Ok(());
+    }
+    add_data_to_builder!(builder, nbt_data => [
+        "back_text": set_back_text,
+        "front_text": set_front_text,
+        "is_waxed": set_is_waxed,
+    ]);
+    Ok(())
+}
+
+/// Signs written before 1.20 store the text of their only side in the block entity itself.
+fn parse_legacy_sign_text(mut nbt_data: HashMap<String, Tag>) -> Result<SignText, SignTextError> {
+    const TEXT_KEYS: [&str; SIGN_LINES] = ["Text1", "Text2", "Text3", "Text4"];
+    let mut builder = SignTextBuilder::default();
+    add_data_to_builder!(builder, nbt_data => [
+        "Color": set_color,
+        "GlowingText": set_has_glowing_text,
+    ]);
+    let messages = TEXT_KEYS
+        .into_iter()
+        .map(|key| match nbt_data.remove(key) {
+            Some(text) => text
+                .get_as_string()
+                .map(Tag::String)
+                .map_err(|e| FieldError::new(key, e)),
+            None => Ok(Tag::String(String::new())),
+        })
+        .collect::<Result<_, _>>()?;
+    builder.set_messages(messages);
+    Ok(builder.try_build()?)
+}
+
+fn parse_sign_text(
+    builder: &mut SignTextBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), SignTextError> {
+    add_data_to_builder!(builder, nbt_data => [
+        "color": set_color,
+        "has_glowing_text": set_has_glowing_text,
+    ]);
+    if let Some(messages) = nbt_data.remove("messages") {
+        builder.set_messages(
+            messages
+                .get_as_list()
+                .map_err(|e| FieldError::new("messages", e))?,
+        );
+    }
+    if let Some(messages) = nbt_data.remove("filtered_messages") {
+        builder.set_filtered_messages(
+            messages
+                .get_as_list()
+                .map_err(|e| FieldError::new("filtered_messages", e))?,
+        );
+    }
+    Ok(())
+}
+
+fn parse_crafter(
+    builder: &mut CrafterBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), CrafterError> {
+    add_data_to_builder!(builder, nbt_data => [
+        "crafting_ticks_remaining": set_crafting_ticks_remaining,
+        "disabled_slots": set_disabled_slots,
+    ]);
+    // The crafter stores its triggered state as an int instead of a byte.
+    if let Some(triggered) = nbt_data.remove("triggered") {
+        let triggered: i32 = triggered
+            .try_into()
+            .map_err(|e| FieldError::new("triggered", e))?;
+        builder.set_triggered(triggered != 0);
+    }
+    parse_inventory_block_entity(builder, nbt_data)?;
+    Ok(())
+}
+
+fn parse_decorated_pot(
+    builder: &mut DecoratedPotBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), DecoratedPotError> {
+    add_data_to_builder!(builder, nbt_data => [
+        "LootTable": set_loot_table,
+        "LootTableSeed": set_loot_table_seed,
+        "sherds": set_sherds,
+    ]);
+    if let Some(item) = nbt_data.remove("item") {
+        let item: Item = item.try_into().map_err(|e| FieldError::new("item", e))?;
+        builder.set_items(List::from(vec![ItemWithSlot { slot: 0, item }]));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::macro_tests::*;
     use super::{
-        parse_block_entity, parse_inventory_block_entity, BlastFurnaceBuilder, BlockEntityError,
+        parse_block_entity, parse_crafter, parse_decorated_pot, parse_inventory_block_entity,
+        parse_sign, BlastFurnaceBuilder, BlockEntityError, DecoratedPotError, SignError,
     };
     use crate::{
-        data::{block_entity::*, load::block_entity::parse_hopper},
+        data::{block_entity::*, load::block_entity::parse_hopper, text_component::TextFormat},
         nbt::*,
     };
     use std::collections::HashMap;
@@ -486,9 +686,9 @@ mod tests {
 
     #[test_case(
         crate::test_util::merge(
-            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:banners".to_string().into()),
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:banner".to_string().into()),
             Banner_test_data_provider()
-        ) => Ok(()); "minecraft:banners"
+        ) => Ok(()); "minecraft:banner"
     )]
     #[test_case(
         crate::test_util::merge(
@@ -520,6 +720,12 @@ mod tests {
             BrewingStand_test_data_provider()
         ) => Ok(()); "minecraft:brewing_stand"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:brushable_block".to_string().into()),
+            BrushableBlock_test_data_provider()
+        ) => Ok(()); "minecraft:brushable_block"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:campfire".to_string().into()),
@@ -556,6 +762,18 @@ mod tests {
             Conduit_test_data_provider()
         ) => Ok(()); "minecraft:conduit"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:crafter".to_string().into()),
+            crafter_test_data_provider()
+        ) => Ok(()); "minecraft:crafter"
+    )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:decorated_pot".to_string().into()),
+            decorated_pot_test_data_provider()
+        ) => Ok(()); "minecraft:decorated_pot"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:dispenser".to_string().into()),
@@ -586,6 +804,12 @@ mod tests {
             cooking_block_test_data_provider()
         ) => Ok(()); "minecraft:furnace"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:hanging_sign".to_string().into()),
+            sign_test_data_provider()
+        ) => Ok(()); "minecraft:hanging_sign"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:hopper".to_string().into()),
@@ -622,6 +846,24 @@ mod tests {
             Piston_test_data_provider()
         ) => Ok(()); "minecraft:piston"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:sculk_catalyst".to_string().into()),
+            SculkCatalyst_test_data_provider()
+        ) => Ok(()); "minecraft:sculk_catalyst"
+    )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:sculk_sensor".to_string().into()),
+            SculkSensor_test_data_provider()
+        ) => Ok(()); "minecraft:sculk_sensor"
+    )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:sculk_shrieker".to_string().into()),
+            SculkShrieker_test_data_provider()
+        ) => Ok(()); "minecraft:sculk_shrieker"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:shulker_box".to_string().into()),
@@ -631,9 +873,15 @@ mod tests {
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:sign".to_string().into()),
-            Sign_test_data_provider()
+            sign_test_data_provider()
         ) => Ok(()); "minecraft:sign"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:sign".to_string().into()),
+            legacy_sign_test_data_provider()
+        ) => Ok(()); "minecraft:sign legacy"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:skull".to_string().into()),
@@ -664,6 +912,18 @@ mod tests {
             inventory_block_test_data_provider()
         ) => Ok(()); "minecraft:trapped_chest"
     )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:trial_spawner".to_string().into()),
+            TrialSpawner_test_data_provider()
+        ) => Ok(()); "minecraft:trial_spawner"
+    )]
+    #[test_case(
+        crate::test_util::merge(
+            crate::test_util::with(block_entity_test_data_provider(), "id", "minecraft:vault".to_string().into()),
+            Vault_test_data_provider()
+        ) => Ok(()); "minecraft:vault"
+    )]
     #[test_case(
         crate::test_util::merge(
             crate::test_util::with(block_entity_test_data_provider(), "id", "unknonwn".to_string().into()),
@@ -716,6 +976,196 @@ mod tests {
         map
     }
 
+    fn crafter_test_data_provider() -> HashMap<String, Tag> {
+        let mut map = HashMap::from_iter([
+            ("crafting_ticks_remaining".to_string(), Tag::Int(6)),
+            (
+                "disabled_slots".to_string(),
+                Tag::IntArray(Array::from(vec![0, 4])),
+            ),
+            ("triggered".to_string(), Tag::Int(1)),
+        ]);
+        map.extend(inventory_block_test_data_provider());
+        map
+    }
+
+    fn decorated_pot_test_data_provider() -> HashMap<String, Tag> {
+        HashMap::from_iter([
+            (
+                "item".to_string(),
+                crate::data::load::item::tests::Item_test_data_provider().into(),
+            ),
+            ("LootTable".to_string(), Tag::String("test".to_string())),
+            ("LootTableSeed".to_string(), Tag::Long(123)),
+            (
+                "sherds".to_string(),
+                Tag::List(List::from(vec![
+                    Tag::String("minecraft:brick".to_string()),
+                    Tag::String("minecraft:skull_pottery_sherd".to_string()),
+                ])),
+            ),
+        ])
+    }
+
+    fn sign_text_test_data_provider(lines: [&str; 4]) -> Tag {
+        Tag::Compound(HashMap::from_iter([
+            ("color".to_string(), Tag::String("red".to_string())),
+            ("has_glowing_text".to_string(), Tag::Byte(1)),
+            (
+                "messages".to_string(),
+                Tag::List(List::from(
+                    lines
+                        .into_iter()
+                        .map(|line| Tag::String(line.to_string()))
+                        .collect::<Vec<_>>(),
+                )),
+            ),
+        ]))
+    }
+
+    fn sign_test_data_provider() -> HashMap<String, Tag> {
+        HashMap::from_iter([
+            (
+                "front_text".to_string(),
+                sign_text_test_data_provider([
+                    r#"{"text":"Hello"}"#,
+                    r#""World""#,
+                    r#""""#,
+                    r#"{"text":"A","extra":["B"]}"#,
+                ]),
+            ),
+            (
+                "back_text".to_string(),
+                sign_text_test_data_provider(["Back", "", "", ""]),
+            ),
+            ("is_waxed".to_string(), Tag::Byte(1)),
+        ])
+    }
+
+    fn legacy_sign_test_data_provider() -> HashMap<String, Tag> {
+        HashMap::from_iter([
+            ("Color".to_string(), Tag::String("blue".to_string())),
+            ("GlowingText".to_string(), Tag::Byte(0)),
+            (
+                "Text1".to_string(),
+                Tag::String(r#"{"text":"Old"}"#.to_string()),
+            ),
+            ("Text2".to_string(), Tag::String(r#""sign""#.to_string())),
+            ("Text3".to_string(), Tag::String(r#""""#.to_string())),
+        ])
+    }
+
+    fn sign_text(color: &str, has_glowing_text: bool, lines: [&str; 4]) -> SignText {
+        SignText {
+            color: color.to_string(),
+            filtered_messages: None,
+            has_glowing_text,
+            messages: List::from(lines.map(|line| Tag::String(line.to_string())).to_vec()),
+        }
+    }
+
+    #[test_case(sign_test_data_provider() => Ok(Sign {
+        back_text: sign_text("red", true, ["Back", "", "", ""]),
+        front_text: sign_text("red", true, [
+            r#"{"text":"Hello"}"#,
+            r#""World""#,
+            r#""""#,
+            r#"{"text":"A","extra":["B"]}"#,
+        ]),
+        is_waxed: true,
+    }); "Two sided")]
+    #[test_case(legacy_sign_test_data_provider() => Ok(Sign {
+        back_text: SignText::default(),
+        front_text: sign_text("blue", false, [r#"{"text":"Old"}"#, r#""sign""#, r#""""#, ""]),
+        is_waxed: false,
+    }); "Legacy")]
+    #[test_case(HashMap::from_iter([(
+        "front_text".to_string(),
+        Tag::Compound(HashMap::from_iter([
+            ("messages".to_string(), Tag::List(List::from(vec![Tag::String(r#""a""#.to_string())]))),
+            ("filtered_messages".to_string(), Tag::List(List::from(vec![Tag::String(r#""b""#.to_string())]))),
+        ])),
+    )]) => Ok(Sign {
+        back_text: SignText::default(),
+        front_text: SignText {
+            color: "black".to_string(),
+            filtered_messages: Some(List::from(vec![Tag::String(r#""b""#.to_string())])),
+            has_glowing_text: false,
+            messages: List::from(vec![Tag::String(r#""a""#.to_string())]),
+        },
+        is_waxed: false,
+    }); "Defaults")]
+    fn test_parse_sign(nbt_data: HashMap<String, Tag>) -> Result<Sign, SignError> {
+        let mut builder = SignBuilder::default();
+        parse_sign(&mut builder, nbt_data)?;
+        Ok(builder.try_build()?)
+    }
+
+    #[test]
+    fn test_sign_messages() {
+        let sign = Sign::try_from(sign_test_data_provider()).expect("Error parsing sign");
+        assert_eq!(
+            sign.messages(TextFormat::Json)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>(),
+            vec!["Hello", "World", "AB", "Back"]
+        );
+    }
+
+    #[test]
+    fn test_sign_messages_nbt() {
+        // Since 1.21.5 plain lines are stored as bare strings
+        let sign = Sign::try_from(HashMap::from_iter([(
+            "front_text".to_string(),
+            sign_text_test_data_provider(["[100, 64, -200]", r#""quoted""#, "", ""]),
+        )]))
+        .expect("Error parsing sign");
+        assert_eq!(
+            sign.messages(TextFormat::Nbt)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>(),
+            vec!["[100, 64, -200]", r#""quoted""#]
+        );
+    }
+
+    #[test]
+    fn test_parse_trial_spawner() {
+        // Saved by a 1.21 server shortly after a player defeated the spawner
+        let nbt_data = HashMap::from_iter([
+            ("cooldown_ends_at".to_string(), Tag::Long(1234567)),
+            ("current_mobs".to_string(), Tag::List(List::from(vec![]))),
+            ("next_mob_spawns_at".to_string(), Tag::Long(0)),
+            (
+                "registered_players".to_string(),
+                Tag::List(List::from(vec![Tag::IntArray(Array::from(vec![
+                    -1234, 5678, -9012, 3456,
+                ]))])),
+            ),
+            ("required_player_range".to_string(), Tag::Int(14)),
+            (
+                "spawn_data".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "entity".to_string(),
+                    Tag::Compound(HashMap::from_iter([(
+                        "id".to_string(),
+                        Tag::String("minecraft:zombie".to_string()),
+                    )])),
+                )])),
+            ),
+            ("target_cooldown_length".to_string(), Tag::Int(36000)),
+            ("total_mobs_spawned".to_string(), Tag::Int(6)),
+        ]);
+        let spawner = TrialSpawner::try_from(nbt_data).expect("Error parsing trial spawner");
+        assert_eq!(spawner.cooldown_ends_at, Some(1234567));
+        assert_eq!(
+            spawner.registered_players,
+            Some(List::from(vec![Array::from(vec![
+                -1234, 5678, -9012, 3456
+            ])]))
+        );
+        assert_eq!(spawner.total_mobs_spawned, Some(6));
+    }
+
     #[test_case(BarrelBuilder::default() => Ok(Barrel {
         custom_name: Some("test".to_string()),
         items: Some(List::from(vec![])),
@@ -785,6 +1235,59 @@ mod tests {
         assert_eq!(builder.try_build(), expected);
     }
 
+    #[test]
+    fn test_parse_crafter() {
+        let expected = Ok(Crafter {
+            crafting_ticks_remaining: 6,
+            custom_name: Some("test".to_string()),
+            disabled_slots: Some(Array::from(vec![0, 4])),
+            items: Some(List::from(vec![])),
+            lock: Some("test".to_string()),
+            loot_table: Some("test".to_string()),
+            loot_table_seed: Some(123),
+            triggered: true,
+        });
+        let mut builder = CrafterBuilder::default();
+        parse_crafter(&mut builder, crafter_test_data_provider()).expect("Error parsing crafter");
+        assert_eq!(builder.try_build(), expected);
+    }
+
+    #[test_case(decorated_pot_test_data_provider() => Ok(DecoratedPot {
+        items: Some(List::from(vec![crate::data::item::ItemWithSlot {
+            slot: 0,
+            item: crate::data::load::item::tests::Item_test_result(),
+        }])),
+        loot_table: Some("test".to_string()),
+        loot_table_seed: Some(123),
+        sherds: Some(List::from(vec![
+            "minecraft:brick".to_string(),
+            "minecraft:skull_pottery_sherd".to_string(),
+        ])),
+    }); "With item")]
+    #[test_case(HashMap::new() => Ok(DecoratedPot {
+        items: None,
+        loot_table: None,
+        loot_table_seed: None,
+        sherds: None,
+    }); "Empty")]
+    fn test_parse_decorated_pot(
+        nbt_data: HashMap<String, Tag>,
+    ) -> Result<DecoratedPot, DecoratedPotError> {
+        let mut builder = DecoratedPotBuilder::default();
+        parse_decorated_pot(&mut builder, nbt_data)?;
+        Ok(builder.try_build()?)
+    }
+
+    #[test]
+    fn test_decorated_pot_inventory() {
+        let pot = DecoratedPot::try_from(decorated_pot_test_data_provider())
+            .expect("Error parsing decorated pot");
+        let inventory: &dyn InventoryBlock = &pot;
+        assert_eq!(inventory.items().map(|items| items.len()), Some(1));
+        assert_eq!(inventory.custom_name(), None);
+        assert_eq!(inventory.loot_table(), Some(&"test".to_string()));
+    }
+
     #[test_case(HashMap::from_iter([
         ("BurnTime".to_string(), Tag::Short(10)),
         ("CookTime".to_string(), Tag::Short(10)),
@@ -3,7 +3,8 @@ use thiserror::Error;
 use crate::{
     compression::{self, decompress},
     data::chunk::*,
-    data::file_format::anvil::ChunkInfo,
+    data::file_format::anvil::{ChunkInfo, MC_REGION_HEADER_SIZE},
+    data::structure::StructuresError,
 };
 
 #[cfg(feature = "block_entity")]
@@ -13,6 +14,8 @@ use crate::data::load::block_entity::BlockEntityError;
 const KIB: u32 = 1024;
 /// The alignment of chunks in the region file.
 const CHUNK_ALIGNMENT: u32 = KIB * 4;
+/// The number of sectors used by the header. Chunk offsets include the header.
+const HEADER_SECTORS: u32 = MC_REGION_HEADER_SIZE as u32 / CHUNK_ALIGNMENT;
 
 /// Errors that can occur when loading chunk data.
 #[derive(Debug, Error, PartialEq)]
@@ -30,8 +33,24 @@ pub enum LoadChunkDataError {
 
 /// Load chunk data from a region file.
 pub fn load_chunk(raw: &[u8], chunk_info: &ChunkInfo) -> Result<ChunkData, LoadChunkDataError> {
-    let offset = ((chunk_info.offset - 2) * CHUNK_ALIGNMENT) as usize;
-    let chunk_data = &raw[offset..];
+    let tag = load_chunk_tag(raw, chunk_info)?;
+    let chunk_data = tag.try_into()?;
+    Ok(chunk_data)
+}
+
+/// Load the raw NBT data of a chunk from a region file.
+/// This is shared by all region kinds because they only differ in the content of the chunk.
+pub(crate) fn load_chunk_tag(
+    raw: &[u8],
+    chunk_info: &ChunkInfo,
+) -> Result<crate::nbt::Tag, LoadChunkDataError> {
+    let offset = chunk_info
+        .offset
+        .checked_sub(HEADER_SECTORS)
+        .ok_or(LoadChunkDataError::ChunkDataLengthError)?;
+    let chunk_data = raw
+        .get((offset * CHUNK_ALIGNMENT) as usize..)
+        .ok_or(LoadChunkDataError::ChunkDataLengthError)?;
     if chunk_data.len() < 6 {
         return Err(LoadChunkDataError::ChunkDataLengthError);
     }
@@ -50,8 +69,7 @@ pub fn load_chunk(raw: &[u8], chunk_info: &ChunkInfo) -> Result<ChunkData, LoadC
 
     let data = decompress(data, &compression).map_err(LoadChunkDataError::Compression)?;
     let tag = crate::nbt::parse(data.as_slice()).map_err(ChunkDataError::Nbt)?;
-    let chunk_data = tag.try_into()?;
-    Ok(chunk_data)
+    Ok(tag)
 }
 
 mod_try_from_tag!(ChunkData: [
@@ -62,12 +80,48 @@ mod_try_from_tag!(ChunkData: [
     "Status" => set_status test(crate::nbt::Tag::String("empty".to_string()) => status = ChunkStatus::Empty),
     "LastUpdate" => set_last_update test(crate::nbt::Tag::Long(5) => last_update = 5),
     if feature = "chunk_section" "sections" => set_sections test(crate::nbt::Tag::List(crate::nbt::List::from(vec![])) => sections = crate::nbt::List::from(vec![])),
+    "Heightmaps" => set_height_maps test(std::collections::HashMap::new() => height_maps = Some(Heightmaps {
+        motion_blocking: None,
+        motion_blocking_no_leaves: None,
+        ocean_floor: None,
+        ocean_floor_wg: None,
+        world_surface: None,
+        world_surface_wg: None,
+    })),
+    "structures" => set_structures test(std::collections::HashMap::from_iter([
+        ("starts".to_string(), crate::nbt::Tag::Compound(std::collections::HashMap::new())),
+        ("References".to_string(), crate::nbt::Tag::Compound(std::collections::HashMap::new())),
+    ]) => structures = Some(crate::data::structure::Structures {
+        starts: std::collections::HashMap::new(),
+        references: std::collections::HashMap::new(),
+    })),
+    "block_ticks" => set_block_ticks test(crate::nbt::Tag::List(crate::nbt::List::from(vec![])) => block_ticks = Some(crate::nbt::List::from(vec![]))),
+    "fluid_ticks" => set_fluid_ticks test(crate::nbt::Tag::List(crate::nbt::List::from(vec![])) => fluid_ticks = Some(crate::nbt::List::from(vec![]))),
     if feature = "block_entity" "block_entities" => set_block_entities test(crate::nbt::Tag::List(crate::nbt::List::from(vec![])) => block_entities = Some(crate::nbt::List::from(vec![]))),
 ] ? [
     ChunkStatus,
+    Heightmaps,
+    Structures,
+    ScheduledTick,
     if feature = "chunk_section" Section,
     if feature = "block_entity" BlockEntity,
 ],
+ScheduledTick: [
+    "i" => set_id test("minecraft:water".to_string() => id = "minecraft:water".to_string(); ScheduledTickBuilderError::UnsetId),
+    "x" => set_x test(1i32 => x = 1; ScheduledTickBuilderError::UnsetX),
+    "y" => set_y test(2i32 => y = 2; ScheduledTickBuilderError::UnsetY),
+    "z" => set_z test(3i32 => z = 3; ScheduledTickBuilderError::UnsetZ),
+    "t" => set_delay test(4i32 => delay = 4; ScheduledTickBuilderError::UnsetDelay),
+    "p" => set_priority test(0i32 => priority = 0; ScheduledTickBuilderError::UnsetPriority),
+],
+Heightmaps: [
+    "MOTION_BLOCKING" => set_motion_blocking test(crate::nbt::Array::from(vec![1i64]) => motion_blocking = Some(crate::nbt::Array::from(vec![1]))),
+    "MOTION_BLOCKING_NO_LEAVES" => set_motion_blocking_no_leaves test(crate::nbt::Array::from(vec![1i64]) => motion_blocking_no_leaves = Some(crate::nbt::Array::from(vec![1]))),
+    "OCEAN_FLOOR" => set_ocean_floor test(crate::nbt::Array::from(vec![1i64]) => ocean_floor = Some(crate::nbt::Array::from(vec![1]))),
+    "OCEAN_FLOOR_WG" => set_ocean_floor_wg test(crate::nbt::Array::from(vec![1i64]) => ocean_floor_wg = Some(crate::nbt::Array::from(vec![1]))),
+    "WORLD_SURFACE" => set_world_surface test(crate::nbt::Array::from(vec![1i64]) => world_surface = Some(crate::nbt::Array::from(vec![1]))),
+    "WORLD_SURFACE_WG" => set_world_surface_wg test(crate::nbt::Array::from(vec![1i64]) => world_surface_wg = Some(crate::nbt::Array::from(vec![1]))),
+],
 if feature = "chunk_section" Section: [
     "Y" => set_y test(1i8 => y = 1),
     "block_states" => set_block_states test(std::collections::HashMap::from_iter([
@@ -127,11 +181,11 @@ impl TryFrom<crate::nbt::Tag> for ChunkStatus {
     type Error = ChunkStatusError;
 
     fn try_from(value: crate::nbt::Tag) -> Result<Self, Self::Error> {
-        let status = match value
+        let value = value
             .get_as_string()
-            .or(Err(crate::nbt::Error::InvalidValue))?
-            .as_str()
-        {
+            .or(Err(crate::nbt::Error::InvalidValue))?;
+        // Since 1.20 the status is stored with namespace
+        let status = match value.strip_prefix("minecraft:").unwrap_or(&value) {
             "empty" => Self::Empty,
             "structure_starts" => Self::StructureStarts,
             "structure_references" => Self::StructureReferences,
@@ -141,6 +195,7 @@ impl TryFrom<crate::nbt::Tag> for ChunkStatus {
             "carvers" => Self::Carvers,
             "liquid_carvers" => Self::LiquidCarvers,
             "features" => Self::Features,
+            "initialize_light" => Self::InitializeLight,
             "light" => Self::Light,
             "spawn" => Self::Spawn,
             "heightmaps" => Self::Heightmaps,
@@ -177,12 +232,25 @@ mod tests {
     #[test_case(Tag::String("spawn".to_string()) => Ok(ChunkStatus::Spawn); "spawn")]
     #[test_case(Tag::String("heightmaps".to_string()) => Ok(ChunkStatus::Heightmaps); "heightmaps")]
     #[test_case(Tag::String("full".to_string()) => Ok(ChunkStatus::Full); "full")]
+    #[test_case(Tag::String("initialize_light".to_string()) => Ok(ChunkStatus::InitializeLight); "initialize_light")]
+    #[test_case(Tag::String("minecraft:full".to_string()) => Ok(ChunkStatus::Full); "With namespace")]
+    #[test_case(Tag::String("other:full".to_string()) => Err(ChunkStatusError::Nbt(crate::nbt::Error::InvalidValue)); "Other namespace")]
     #[test_case(Tag::String("invalid".to_string()) => Err(ChunkStatusError::Nbt(crate::nbt::Error::InvalidValue)); "invalid")]
     #[test_case(Tag::Int(1) => Err(ChunkStatusError::Nbt(crate::nbt::Error::InvalidValue)); "invalid type")]
     fn test_chunk_status_try_from_tag(tag: Tag) -> Result<ChunkStatus, ChunkStatusError> {
         ChunkStatus::try_from(tag)
     }
 
+    #[test_case(ChunkStatus::LiquidCarvers; "liquid_carvers")]
+    #[test_case(ChunkStatus::InitializeLight; "initialize_light")]
+    #[test_case(ChunkStatus::Full; "full")]
+    fn test_chunk_status_id(status: ChunkStatus) {
+        assert_eq!(
+            ChunkStatus::try_from(Tag::String(status.id().to_string())),
+            Ok(status)
+        );
+    }
+
     #[test_case(&[] => Err(LoadChunkDataError::ChunkDataLengthError); "empty")]
     #[test_case(&[0, 0, 0, 0] => Err(LoadChunkDataError::ChunkDataLengthError); "missing compression type")]
     #[test_case(&[0, 0, 0, 0, 0] => Err(LoadChunkDataError::ChunkDataLengthError); "no sectors")]
@@ -198,6 +266,10 @@ mod tests {
         status: ChunkStatus::Full,
         last_update: 10,
         sections: crate::nbt::List::from(vec![]),
+        height_maps: None,
+        structures: None,
+        block_ticks: None,
+        fluid_ticks: None,
         block_entities: None
     }); "Success")]
     fn test_load_chunk_status(raw: &[u8]) -> Result<ChunkData, LoadChunkDataError> {
@@ -22,15 +22,25 @@ Entity: [
     "OnGround" => set_on_ground test(0i8 => on_ground = false),
     "Passengers" => set_passengers test(List::from_iter([]) => passengers = Some(List::from_iter([]))),
     "PortalCooldown" => set_portal_colldown test(4i32 => portal_colldown = 4),
-    "Pos" => set_pos test(List::from_iter([]) => pos = Some(List::from_iter([]))),
+    "Pos" => set_pos test(List::<Tag>::from(vec![1_f64.into(), 2f64.into(), 3f64.into()]) => pos = Some(List::from_iter([1., 2., 3.]))),
     "Rotation" => set_rotation test(List::from_iter([]) => rotation = Some(List::from_iter([]))),
     "Silent" => set_silent test(1i8 => silent = true),
-    "Tags" => set_tags test(HashMap::new() => tags = Some(HashMap::new())),
+    "Tags" => set_tags test(List::<Tag>::from(vec!["tag".to_string().into()]) => tags = Some(List::from(vec!["tag".to_string()]))),
     "TicksFrozen" => set_ticks_frozen test(5i32 => ticks_frozen = Some(5)),
     "UUID" => set_uuid test(Array::<i32>::from(vec![]) => uuid = Some(Array::from_iter([]))),
 ] ? [
     Entity,
 ],
+EntityChunk: [
+    "DataVersion" => set_data_version test(1i32 => data_version = 1),
+    "Position" => set_position test(Array::from(vec![1i32, 2]) => position = Array::from(vec![1, 2])),
+    "Entities" => set_entities test(List::<Tag>::from(vec![]) => entities = List::from(vec![])),
+] ? [
+    Entity,
+],
+CommandBlockMinecart: parse_command_block_minecart ? [
+    Entity,
+],
 Mob: parse_mob ? [
     Entity,
     ActiveEffect,
@@ -81,6 +91,23 @@ fn parse_mob(builder: &mut MobBuilder, mut nbt_data: HashMap<String, Tag>) -> Re
     );
     Ok(())
 }
+fn parse_command_block_minecart(
+    builder: &mut CommandBlockMinecartBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), CommandBlockMinecartError> {
+    add_data_to_builder!(builder, nbt_data => [
+        "Command": set_command,
+        "LastOutput": set_last_output,
+        "SuccessCount": set_success_count,
+        "TrackOutput": set_track_output,
+    ]);
+    builder.set_entity(
+        nbt_data
+            .try_into()
+            .map_err(|e| FieldError::new("<internal> entity", e))?,
+    );
+    Ok(())
+}
 fn parse_leash(mut nbt_data: HashMap<String, Tag>) -> Result<Leash, LeashError> {
     if let Some(Tag::IntArray(uuid)) = nbt_data.remove("UUID") {
         return Ok(Leash::Entity(uuid));
@@ -131,6 +158,36 @@ pub mod tests {
         data.try_into()
     }
 
+    #[test]
+    fn test_parse_command_block_minecart() {
+        let mut data = Entity_test_data_provider();
+        data.extend([
+            ("Command".to_string(), Tag::String("say hi".to_string())),
+            ("LastOutput".to_string(), Tag::String("output".to_string())),
+            ("SuccessCount".to_string(), Tag::Int(3)),
+            ("TrackOutput".to_string(), Tag::Byte(0)),
+        ]);
+        assert_eq!(
+            CommandBlockMinecart::try_from(data),
+            Ok(CommandBlockMinecart {
+                command: "say hi".to_string(),
+                entity: Entity_test_result(),
+                last_output: Some("output".to_string()),
+                success_count: 3,
+                track_output: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_command_block_minecart_defaults() {
+        let minecart = CommandBlockMinecart::try_from(Entity_test_data_provider())
+            .expect("Error parsing minecart");
+        assert_eq!(minecart.command, "");
+        assert_eq!(minecart.success_count, 0);
+        assert!(minecart.track_output);
+    }
+
     #[test_case(None, None => Ok(mob_test_result()); "Success")]
     #[test_case(
         Some("Fire"), Some(Tag::Double(42.)) =>
@@ -205,9 +262,9 @@ pub mod tests {
                 ("PortalCooldown", Tag::Int(0)),
                 (
                     "Pos",
-                    List::from(vec![Tag::Float(0.), Tag::Float(0.), Tag::Float(0.)]).into(),
+                    List::from(vec![Tag::Double(0.), Tag::Double(0.), Tag::Double(0.)]).into(),
                 ),
-                ("Tags", HashMap::new().into()),
+                ("Tags", List::from(vec![]).into()),
                 ("TicksFrozen", Tag::Int(0)),
                 (
                     "Rotation",
@@ -126,7 +126,7 @@ pub mod tests {
                     macro_tests::{Entity_test_data_provider, Entity_test_result},
                     tests::{mob_test_data_provider, mob_test_result},
                 },
-                item::macro_tests::{Item_test_data_provider, Item_test_result},
+                item::tests::{Item_test_data_provider, Item_test_result},
             },
         },
         nbt::{List, Tag},
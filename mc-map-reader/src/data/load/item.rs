@@ -6,14 +6,40 @@ use crate::{
 };
 
 mod_try_from_tag!(
-    Item: [
-        "Count" => set_count test(10_i8 => count = 10; ItemBuilderError::UnsetCount),
-        "id" => set_id test("test_id".to_string() => id = "test_id".to_string(); ItemBuilderError::UnsetId),
-        "tag" => set_tag test(HashMap::new() => tag = Some(HashMap::new())),
-    ],
+    Item: parse_item,
     ItemWithSlot: parse_item_with_slot ? [ Item, ],
 );
 
+fn parse_item(
+    builder: &mut ItemBuilder,
+    mut nbt_data: HashMap<String, Tag>,
+) -> Result<(), ItemError> {
+    let format = ItemFormat::detect(&nbt_data);
+    add_data_to_builder!(builder, nbt_data => [
+        "id": set_id,
+    ]);
+    match format {
+        ItemFormat::Legacy => {
+            if let Some(count) = nbt_data.remove("Count") {
+                let count = i8::try_from(count).map_err(|e| FieldError::new("Count", e))?;
+                builder.set_count(count as i32);
+            }
+            add_data_to_builder!(builder, nbt_data => [
+                "tag": set_tag,
+            ]);
+        }
+        ItemFormat::Components => {
+            // The count is only omitted for single items
+            builder.set_count(1);
+            add_data_to_builder!(builder, nbt_data => [
+                "count": set_count,
+                "components": set_components,
+            ]);
+        }
+    }
+    Ok(())
+}
+
 fn parse_item_with_slot(
     builder: &mut ItemWithSlotBuilder,
     mut nbt_data: HashMap<String, Tag>,
@@ -45,6 +71,7 @@ pub mod tests {
             count: 10,
             id: "test_id".to_string(),
             tag: Some(HashMap::new()),
+            components: None,
         },
     }); "Success")]
     #[test_case(vec![
@@ -72,6 +99,7 @@ pub mod tests {
             count: 10,
             id: "test_id".to_string(),
             tag: None,
+            components: None,
         },
     }); "Success without tag")]
     fn test_parse_item_with_slot(
@@ -83,6 +111,65 @@ pub mod tests {
         nbt_data.try_into()
     }
 
+    #[test_case(vec![
+        ("Slot", Tag::Byte(2)),
+        ("count", Tag::Int(64)),
+        ("id", Tag::String("minecraft:diamond".to_string())),
+        ("components", Tag::Compound(HashMap::new())),
+    ] => Ok(ItemWithSlot {
+        slot: 2,
+        item: Item {
+            count: 64,
+            id: "minecraft:diamond".to_string(),
+            tag: None,
+            components: Some(HashMap::new()),
+        },
+    }); "Components")]
+    #[test_case(vec![
+        ("Slot", Tag::Byte(2)),
+        ("id", Tag::String("minecraft:diamond".to_string())),
+    ] => Ok(ItemWithSlot {
+        slot: 2,
+        item: Item {
+            count: 1,
+            id: "minecraft:diamond".to_string(),
+            tag: None,
+            components: None,
+        },
+    }); "Components without count")]
+    #[test_case(vec![
+        ("Slot", Tag::Byte(2)),
+        ("count", Tag::Byte(1)),
+        ("id", Tag::String("minecraft:diamond".to_string())),
+    ] => Err(ItemWithSlotError::ItemField(FieldError::new("<internal> item", ItemError::NbtField(FieldError::new("count", crate::nbt::Error::InvalidValue))))); "Components with byte count")]
+    fn test_parse_item_with_slot_components(
+        nbt_data: Vec<(&str, Tag)>,
+    ) -> Result<ItemWithSlot, ItemWithSlotError> {
+        let nbt_data = Tag::Compound(HashMap::from_iter(
+            nbt_data.into_iter().map(|(k, v)| (k.to_string(), v)),
+        ));
+        nbt_data.try_into()
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Item_test_data_provider() -> HashMap<String, Tag> {
+        HashMap::from_iter([
+            ("Count".to_string(), Tag::Byte(10)),
+            ("id".to_string(), Tag::String("test_id".to_string())),
+            ("tag".to_string(), Tag::Compound(HashMap::new())),
+        ])
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Item_test_result() -> Item {
+        Item {
+            id: "test_id".to_string(),
+            tag: Some(HashMap::new()),
+            components: None,
+            count: 10,
+        }
+    }
+
     pub fn item_with_slot_test_data_provider() -> HashMap<String, Tag> {
         let map = [("Slot", Tag::Byte(0))].map(|(k, v)| (k.to_string(), v));
         let mut map = HashMap::from_iter(map);
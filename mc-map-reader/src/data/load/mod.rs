@@ -229,6 +229,11 @@ pub mod dimension;
 pub mod entity;
 pub mod file_format;
 pub mod item;
+#[cfg(feature = "poi")]
+pub mod poi;
+#[cfg(feature = "region_file")]
+pub mod structure;
+pub mod world_border;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
 #[error("{field} -> {error}")]
@@ -0,0 +1,116 @@
+use crate::data::poi::*;
+
+mod_try_from_tag!(
+    PoiChunk: [
+        "DataVersion" => set_data_version test(1i32 => data_version = Some(1)),
+        "Sections" => set_sections test(std::collections::HashMap::new() => sections = std::collections::HashMap::new(); PoiChunkBuilderError::UnsetSections),
+    ] ? [
+        PoiSection,
+    ],
+    PoiSection: [
+        "Valid" => set_valid test(1i8 => valid = true; PoiSectionBuilderError::UnsetValid),
+        "Records" => set_records test(crate::nbt::List::from(vec![]) => records = crate::nbt::List::from(vec![]); PoiSectionBuilderError::UnsetRecords),
+    ] ? [
+        PoiRecord,
+    ],
+    PoiRecord: [
+        "pos" => set_pos test(crate::nbt::Array::from(vec![1i32, 2, 3]) => pos = crate::nbt::Array::from(vec![1, 2, 3]); PoiRecordBuilderError::UnsetPos),
+        "type" => set_poi_type test("minecraft:nether_portal".to_string() => poi_type = "minecraft:nether_portal".to_string(); PoiRecordBuilderError::UnsetPoiType),
+        "free_tickets" => set_free_tickets test(0i32 => free_tickets = 0; PoiRecordBuilderError::UnsetFreeTickets),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::macro_tests::*;
+    use crate::{
+        data::poi::{PoiChunk, PoiRecord, PoiSection},
+        nbt::{Array, List, Tag},
+    };
+    use test_case::test_case;
+
+    #[test_case(vec![1, 2, 3] => Some((1, 2, 3)); "Valid position")]
+    #[test_case(vec![1, 2] => None; "Too few values")]
+    #[test_case(vec![1, 2, 3, 4] => None; "Too many values")]
+    fn test_poi_record_position(pos: Vec<i32>) -> Option<(i32, i32, i32)> {
+        PoiRecord {
+            pos: Array::from(pos),
+            ..PoiRecord_test_result()
+        }
+        .position()
+        .map(|pos| (pos.x, pos.y, pos.z))
+    }
+
+    #[test]
+    fn test_poi_chunk_records() {
+        let section = |y: &str| {
+            (
+                y.to_string(),
+                Tag::Compound(HashMap::from_iter([
+                    ("Valid".to_string(), Tag::Byte(1)),
+                    (
+                        "Records".to_string(),
+                        Tag::List(List::from(vec![PoiRecord_test_data_provider().into()])),
+                    ),
+                ])),
+            )
+        };
+        let chunk = PoiChunk::try_from(HashMap::from_iter([
+            ("DataVersion".to_string(), Tag::Int(3465)),
+            (
+                "Sections".to_string(),
+                Tag::Compound(HashMap::from_iter([section("-1"), section("4")])),
+            ),
+        ]))
+        .expect("Error parsing POI chunk");
+        assert_eq!(
+            chunk.sections.get("4"),
+            Some(&PoiSection {
+                valid: true,
+                records: List::from(vec![PoiRecord_test_result()]),
+            })
+        );
+        assert_eq!(chunk.records().count(), 2);
+        assert!(chunk
+            .records()
+            .all(|record| record.poi_type == crate::data::poi::NETHER_PORTAL));
+    }
+
+    #[test]
+    fn test_poi_chunk_records_order() {
+        let record = |x: i32, y: i32| PoiRecord {
+            pos: Array::from(vec![x, y, 0]),
+            ..PoiRecord_test_result()
+        };
+        let section = |records: Vec<PoiRecord>| PoiSection {
+            valid: true,
+            records: List::from(records),
+        };
+        let chunk = PoiChunk {
+            data_version: None,
+            sections: HashMap::from_iter(
+                [
+                    ("10", section(vec![record(3, 160), record(1, 170)])),
+                    ("-2", section(vec![record(2, -20)])),
+                    ("2", section(vec![record(5, 40), record(4, 40)])),
+                ]
+                .map(|(y, section)| (y.to_string(), section)),
+            ),
+        };
+        assert_eq!(
+            chunk
+                .records()
+                .map(|record| record.position().map(|pos| (pos.x, pos.y)))
+                .collect::<Vec<_>>(),
+            vec![
+                Some((2, -20)),
+                Some((4, 40)),
+                Some((5, 40)),
+                Some((1, 170)),
+                Some((3, 160)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::data::structure::*;
+
+mod_try_from_tag!(
+    Structures: [
+        "starts" => set_starts test(std::collections::HashMap::new() => starts = std::collections::HashMap::new(); StructuresBuilderError::UnsetStarts),
+        "References" => set_references test(std::collections::HashMap::new() => references = std::collections::HashMap::new(); StructuresBuilderError::UnsetReferences),
+    ] ? [
+        StructureStart,
+    ],
+    StructureStart: [
+        "id" => set_id test("minecraft:village_plains".to_string() => id = "minecraft:village_plains".to_string(); StructureStartBuilderError::UnsetId),
+        "ChunkX" => set_chunk_x test(1i32 => chunk_x = Some(1)),
+        "ChunkZ" => set_chunk_z test(2i32 => chunk_z = Some(2)),
+        "references" => set_references test(3i32 => references = Some(3)),
+        "Children" => set_children test(crate::nbt::List::from(vec![]) => children = Some(crate::nbt::List::from(vec![]))),
+    ] ? [
+        StructurePiece,
+    ],
+    StructurePiece: [
+        "id" => set_id test("minecraft:vi".to_string() => id = "minecraft:vi".to_string(); StructurePieceBuilderError::UnsetId),
+        "BB" => set_bounding_box test(crate::nbt::Array::from(vec![1i32, 2, 3, 4, 5, 6]) => bounding_box = crate::nbt::Array::from(vec![1, 2, 3, 4, 5, 6]); StructurePieceBuilderError::UnsetBoundingBox),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::macro_tests::*;
+    use crate::{
+        coordinates::{BoundingBox, ChunkPos},
+        data::structure::{StructurePiece, StructureStart, Structures},
+        nbt::{Array, List, Tag},
+    };
+    use test_case::test_case;
+
+    fn piece(bounding_box: Vec<i32>) -> StructurePiece {
+        StructurePiece {
+            bounding_box: Array::from(bounding_box),
+            ..StructurePiece_test_result()
+        }
+    }
+
+    #[test_case(vec![1, 2, 3, 4, 5, 6] => Some(BoundingBox { min_x: 1, min_y: 2, min_z: 3, max_x: 4, max_y: 5, max_z: 6 }); "Valid")]
+    #[test_case(vec![1, 2, 3] => None; "Too few values")]
+    fn test_piece_bounding_box(bounding_box: Vec<i32>) -> Option<BoundingBox> {
+        piece(bounding_box).bounding_box()
+    }
+
+    #[test_case(None => None; "Without children")]
+    #[test_case(Some(vec![]) => None; "Empty children")]
+    #[test_case(Some(vec![piece(vec![0, 60, 0, 10, 70, 10]), piece(vec![-5, 40, 5, 5, 65, 20]), piece(vec![1])]) => Some(BoundingBox { min_x: -5, min_y: 40, min_z: 0, max_x: 10, max_y: 70, max_z: 20 }); "Union of children")]
+    fn test_start_bounding_box(children: Option<Vec<StructurePiece>>) -> Option<BoundingBox> {
+        StructureStart {
+            children: children.map(List::from),
+            ..StructureStart_test_result()
+        }
+        .bounding_box()
+    }
+
+    #[test]
+    fn test_structures() {
+        let structures = Structures::try_from(HashMap::from_iter([
+            (
+                "starts".to_string(),
+                Tag::Compound(HashMap::from_iter([
+                    (
+                        "minecraft:village_plains".to_string(),
+                        StructureStart_test_data_provider().into(),
+                    ),
+                    (
+                        "minecraft:stronghold".to_string(),
+                        Tag::Compound(HashMap::from_iter([(
+                            "id".to_string(),
+                            Tag::String("INVALID".to_string()),
+                        )])),
+                    ),
+                ])),
+            ),
+            (
+                "References".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "minecraft:village_plains".to_string(),
+                    Tag::LongArray(Array::from(vec![(-2_i64 << 32) | 5, 3_i64 << 32])),
+                )])),
+            ),
+        ]))
+        .expect("Error parsing structures");
+        assert_eq!(
+            structures.valid_starts().collect::<Vec<_>>(),
+            vec![&StructureStart_test_result()]
+        );
+        assert_eq!(
+            structures.reference_chunks("minecraft:village_plains"),
+            vec![ChunkPos::new(5, -2), ChunkPos::new(0, 3)]
+        );
+        assert_eq!(structures.reference_chunks("minecraft:stronghold"), vec![]);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::data::world_border::*;
+
+mod_try_from_tag!(
+    WorldBorder: [
+        "BorderCenterX" => set_center_x test(1f64 => center_x = 1.),
+        "BorderCenterZ" => set_center_z test(2f64 => center_z = 2.),
+        "BorderSize" => set_size test(100f64 => size = 100.),
+    ],
+);
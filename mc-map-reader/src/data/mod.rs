@@ -9,4 +9,12 @@ pub mod entity;
 pub mod file_format;
 pub mod item;
 mod load;
+#[cfg(feature = "region_file")]
+mod packed_array;
+#[cfg(feature = "poi")]
+pub mod poi;
+#[cfg(feature = "region_file")]
+pub mod structure;
+pub mod text_component;
+pub mod world_border;
 pub use load::FieldError;
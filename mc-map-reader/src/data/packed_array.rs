@@ -0,0 +1,51 @@
+//! Decoding of the packed long arrays used by block states, biomes and heightmaps.
+//!
+//! Since 1.16 entries are not split across two longs. If the bits per entry do not divide 64
+//! the unused high bits of every long are left empty.
+
+#[cfg(feature = "chunk_section")]
+/// Number of bits used per entry for a palette with `palette_len` entries.
+pub(crate) fn bits_per_entry(palette_len: usize, min_bits: u32) -> u32 {
+    let bits = usize::BITS - palette_len.saturating_sub(1).leading_zeros();
+    bits.max(min_bits)
+}
+
+/// Read the entry at `index` from `data`.
+/// Returns `None` if `data` is too short or `bits` is not in `1..=32`.
+pub(crate) fn get(data: &[i64], bits: u32, index: usize) -> Option<usize> {
+    if !(1..=32).contains(&bits) {
+        return None;
+    }
+    let entries_per_long = (u64::BITS / bits) as usize;
+    let long = *data.get(index / entries_per_long)? as u64;
+    let shift = (index % entries_per_long) as u32 * bits;
+    Some(((long >> shift) & ((1 << bits) - 1)) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(feature = "chunk_section")]
+    #[test_case(1, 4 => 4; "Single entry palette uses min bits")]
+    #[test_case(16, 4 => 4; "Exactly min bits")]
+    #[test_case(17, 4 => 5; "More than min bits")]
+    #[test_case(2, 0 => 1; "Two entries")]
+    #[test_case(1, 0 => 0; "Single entry without min bits")]
+    #[test_case(385, 0 => 9; "Heightmap")]
+    fn test_bits_per_entry(palette_len: usize, min_bits: u32) -> u32 {
+        bits_per_entry(palette_len, min_bits)
+    }
+
+    #[test_case(&[0x0000_0000_0000_0021], 4, 0 => Some(1); "First entry")]
+    #[test_case(&[0x0000_0000_0000_0021], 4, 1 => Some(2); "Second entry")]
+    #[test_case(&[0x7000_0000_0000_0000], 4, 15 => Some(7); "Last entry of long")]
+    #[test_case(&[0, 3], 4, 16 => Some(3); "Entry in second long")]
+    #[test_case(&[0, 3], 4, 32 => None; "Out of bounds")]
+    #[test_case(&[-1, 5], 5, 12 => Some(5); "No spanning with padding bits")]
+    #[test_case(&[1], 0, 0 => None; "Zero bits")]
+    fn test_get(data: &[i64], bits: u32, index: usize) -> Option<usize> {
+        get(data, bits, index)
+    }
+}
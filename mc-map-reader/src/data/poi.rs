@@ -0,0 +1,71 @@
+//! Points of interest stored in the `poi` directory of a dimension.
+
+use std::collections::HashMap;
+
+use jbe::Builder;
+
+use crate::{
+    coordinates::BlockPos,
+    nbt::{Array, List},
+};
+
+pub use super::load::poi::*;
+
+/// The POI type of a nether portal block.
+pub const NETHER_PORTAL: &str = "minecraft:nether_portal";
+
+/// Points of interest of a single chunk.
+/// [Minecraft Wiki](https://minecraft.wiki/w/Point_of_Interest_format)
+#[derive(Debug, Builder, PartialEq)]
+pub struct PoiChunk {
+    pub data_version: Option<i32>,
+    /// Sections of the chunk. The key is the Y coordinate of the section.
+    pub sections: HashMap<String, PoiSection>,
+}
+
+/// A 16x16x16 section containing points of interest.
+#[derive(Debug, Builder, PartialEq)]
+pub struct PoiSection {
+    /// If this is false the game recomputes the section the next time it is loaded.
+    pub valid: bool,
+    pub records: List<PoiRecord>,
+}
+
+/// A single point of interest like a bed, a job site block, a bell, a lodestone or a nether portal.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct PoiRecord {
+    /// Position of the block as `[x, y, z]`.
+    pub pos: Array<i32>,
+    /// The POI type e.g. `minecraft:home` or `minecraft:nether_portal`.
+    pub poi_type: String,
+    /// The number of villagers that can still claim this point of interest.
+    pub free_tickets: i32,
+}
+
+impl PoiChunk {
+    /// Iterate over the records of all sections in this chunk ordered by the Y coordinate of the section
+    /// and then by position. Sections with an invalid Y coordinate and records with an invalid position come first.
+    pub fn records(&self) -> impl Iterator<Item = &PoiRecord> {
+        let mut records = self
+            .sections
+            .iter()
+            .flat_map(|(y, section)| {
+                let y = y.parse::<i32>().ok();
+                section.records.iter().map(move |record| (y, record))
+            })
+            .collect::<Vec<_>>();
+        records.sort_by_key(|(y, record)| (*y, record.position()));
+        records.into_iter().map(|(_, record)| record)
+    }
+}
+
+impl PoiRecord {
+    /// The block position of the point of interest.
+    /// Returns `None` if the stored position does not contain exactly three values.
+    pub fn position(&self) -> Option<BlockPos> {
+        match self.pos.as_slice() {
+            [x, y, z] => Some(BlockPos::new(*x, *y, *z)),
+            _ => None,
+        }
+    }
+}
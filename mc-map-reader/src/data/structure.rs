@@ -0,0 +1,105 @@
+//! Structures like villages, strongholds or ancient cities stored in chunks.
+
+use std::collections::HashMap;
+
+use jbe::Builder;
+
+use crate::{
+    coordinates::{BoundingBox, ChunkPos},
+    nbt::{Array, List},
+};
+
+pub use super::load::structure::*;
+
+/// The id of a structure start that was not generated.
+pub const INVALID_STRUCTURE_ID: &str = "INVALID";
+
+/// The `structures` compound of a chunk.
+#[derive(Debug, Builder, PartialEq)]
+pub struct Structures {
+    /// Structures starting in this chunk by structure type.
+    pub starts: HashMap<String, StructureStart>,
+    /// Chunks containing the start of a structure that reaches into this chunk by structure type.
+    /// Every chunk position is stored as a single long.
+    pub references: HashMap<String, Array<i64>>,
+}
+
+/// The start of a structure.
+/// [Minecraft Wiki](https://minecraft.wiki/w/Chunk_format#NBT_structure)
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct StructureStart {
+    /// The structure id or [`INVALID_STRUCTURE_ID`] if the structure was not generated.
+    pub id: String,
+    pub chunk_x: Option<i32>,
+    pub chunk_z: Option<i32>,
+    /// Number of chunks referencing this structure.
+    pub references: Option<i32>,
+    pub children: Option<List<StructurePiece>>,
+}
+
+/// A single piece of a structure like a room of a stronghold.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct StructurePiece {
+    pub id: String,
+    /// The bounding box as `[min_x, min_y, min_z, max_x, max_y, max_z]`.
+    pub bounding_box: Array<i32>,
+}
+
+impl Structures {
+    /// Iterate over all generated structures starting in this chunk.
+    pub fn valid_starts(&self) -> impl Iterator<Item = &StructureStart> {
+        self.starts.values().filter(|start| start.is_valid())
+    }
+
+    /// Get the chunk positions of all starts of the given structure type
+    /// that reach into this chunk.
+    pub fn reference_chunks(&self, structure: &str) -> Vec<ChunkPos> {
+        self.references
+            .get(structure)
+            .map(|references| {
+                references
+                    .iter()
+                    .map(|reference| ChunkPos::new(*reference as i32, (*reference >> 32) as i32))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl StructureStart {
+    /// Returns false if the structure was not generated.
+    pub fn is_valid(&self) -> bool {
+        self.id != INVALID_STRUCTURE_ID
+    }
+
+    /// The chunk the structure starts in.
+    pub fn chunk_pos(&self) -> Option<ChunkPos> {
+        Some(ChunkPos::new(self.chunk_x?, self.chunk_z?))
+    }
+
+    /// The bounding box containing all pieces of the structure.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.children
+            .as_ref()?
+            .iter()
+            .filter_map(StructurePiece::bounding_box)
+            .reduce(|a, b| a.union(&b))
+    }
+}
+
+impl StructurePiece {
+    /// Returns `None` if the stored bounding box does not contain exactly six values.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        match self.bounding_box.as_slice() {
+            [min_x, min_y, min_z, max_x, max_y, max_z] => Some(BoundingBox {
+                min_x: *min_x,
+                min_y: *min_y,
+                min_z: *min_z,
+                max_x: *max_x,
+                max_y: *max_y,
+                max_z: *max_z,
+            }),
+            _ => None,
+        }
+    }
+}
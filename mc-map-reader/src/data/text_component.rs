@@ -0,0 +1,177 @@
+//! Text components are used for sign texts, custom names and lore.
+//! Older versions store them as JSON strings, newer versions as NBT.
+//! The functions in this module reduce a component to the plain text a player would see.
+//! Both formats store plain text as a bare string, so the [`TextFormat`] has to be known to read them.
+
+use serde_json::Value;
+
+use crate::nbt::Tag;
+
+/// Keys containing the content of a component ordered by priority.
+const CONTENT_KEYS: [&str; 3] = ["text", "keybind", "selector"];
+
+/// The first DataVersion storing text components as NBT (1.21.5).
+pub const NBT_TEXT_DATA_VERSION: i32 = 4325;
+
+/// The ways text components are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFormat {
+    /// Before 1.21.5 components are JSON strings e.g. `{"text":"Hello"}` or `"Hello"`.
+    Json,
+    /// Since 1.21.5 components are NBT and a string tag is the text itself.
+    Nbt,
+}
+
+impl TextFormat {
+    /// The format used by a chunk, entity chunk or player file with the DataVersion.
+    pub fn from_data_version(data_version: i32) -> Self {
+        if data_version >= NBT_TEXT_DATA_VERSION {
+            Self::Nbt
+        } else {
+            Self::Json
+        }
+    }
+
+    /// Get the plain text of a component stored as a string in this format.
+    pub fn plain_text(self, component: &str) -> String {
+        match self {
+            Self::Json => plain_text(component),
+            Self::Nbt => component.to_string(),
+        }
+    }
+}
+
+/// Get the plain text of a JSON text component.
+/// Strings which are not valid JSON are returned unchanged.
+pub fn plain_text(component: &str) -> String {
+    match serde_json::from_str::<Value>(component) {
+        Ok(value) => {
+            let mut text = String::new();
+            push_json(&value, &mut text);
+            text
+        }
+        Err(_) => component.to_string(),
+    }
+}
+
+/// Get the plain text of a text component stored as a tag.
+/// A string tag is parsed as JSON in the [`TextFormat::Json`] format and taken as is in the [`TextFormat::Nbt`] format.
+pub fn plain_text_from_tag(component: &Tag, format: TextFormat) -> String {
+    let mut text = String::new();
+    push_tag(component, format, &mut text);
+    text
+}
+
+fn push_json(value: &Value, text: &mut String) {
+    match value {
+        Value::String(value) => text.push_str(value),
+        Value::Number(value) => text.push_str(&value.to_string()),
+        Value::Bool(value) => text.push_str(&value.to_string()),
+        Value::Array(components) => components
+            .iter()
+            .for_each(|component| push_json(component, text)),
+        Value::Object(component) => {
+            if let Some(content) = CONTENT_KEYS.iter().find_map(|key| component.get(*key)) {
+                push_json(content, text);
+            } else if let Some(key) = component.get("translate") {
+                push_json(component.get("fallback").unwrap_or(key), text);
+            }
+            if let Some(extra) = component.get("extra") {
+                push_json(extra, text);
+            }
+        }
+        Value::Null => {}
+    }
+}
+
+fn push_tag(tag: &Tag, format: TextFormat, text: &mut String) {
+    match tag {
+        Tag::String(component) => text.push_str(&format.plain_text(component)),
+        Tag::List(components) => components
+            .iter()
+            .for_each(|component| push_tag_content(component, text)),
+        Tag::Compound(component) => {
+            if let Some(content) = CONTENT_KEYS.iter().find_map(|key| component.get(*key)) {
+                push_tag_content(content, text);
+            } else if let Some(key) = component.get("translate") {
+                push_tag_content(component.get("fallback").unwrap_or(key), text);
+            }
+            if let Some(extra) = component.get("extra") {
+                push_tag_content(extra, text);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Strings inside of NBT components are plain text and must not be parsed as JSON.
+fn push_tag_content(tag: &Tag, text: &mut String) {
+    match tag {
+        Tag::String(content) => text.push_str(content),
+        Tag::Byte(value) => text.push_str(&value.to_string()),
+        Tag::Int(value) => text.push_str(&value.to_string()),
+        // Nested components are always NBT
+        other => push_tag(other, TextFormat::Nbt, text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::nbt::List;
+
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(r#""Hello""# => "Hello"; "String")]
+    #[test_case(r#"{"text":"Hello"}"# => "Hello"; "Text")]
+    #[test_case(r#"{"text":"Hello","extra":[" ",{"text":"World","color":"red"}]}"# => "Hello World"; "Extra")]
+    #[test_case(r#"["A",{"text":"B"},"C"]"# => "ABC"; "Array")]
+    #[test_case(r#"{"translate":"block.minecraft.chest"}"# => "block.minecraft.chest"; "Translate")]
+    #[test_case(r#"{"translate":"custom.key","fallback":"Chest"}"# => "Chest"; "Translate with fallback")]
+    #[test_case(r#"{"keybind":"key.jump"}"# => "key.jump"; "Keybind")]
+    #[test_case(r#""""# => ""; "Empty")]
+    #[test_case("" => ""; "Empty string")]
+    #[test_case("Plain text" => "Plain text"; "Not JSON")]
+    #[test_case("42" => "42"; "Number")]
+    fn test_plain_text(component: &str) -> String {
+        plain_text(component)
+    }
+
+    #[test_case(Tag::String(r#"{"text":"Hello"}"#.to_string()) => "Hello"; "JSON string")]
+    #[test_case(Tag::String("Hello".to_string()) => "Hello"; "Plain string")]
+    #[test_case(Tag::String("[100, 64, -200]".to_string()) => "10064-200"; "JSON array")]
+    #[test_case(Tag::Compound(HashMap::from_iter([
+        ("text".to_string(), Tag::String("{Hello}".to_string())),
+        ("extra".to_string(), Tag::List(List::from(vec![
+            Tag::String(" World".to_string()),
+            Tag::Compound(HashMap::from_iter([("text".to_string(), Tag::String("!".to_string()))])),
+        ]))),
+    ])) => "{Hello} World!"; "Compound")]
+    #[test_case(Tag::Compound(HashMap::from_iter([
+        ("translate".to_string(), Tag::String("custom.key".to_string())),
+        ("fallback".to_string(), Tag::String("Chest".to_string())),
+    ])) => "Chest"; "Translate with fallback")]
+    #[test_case(Tag::Int(1) => ""; "Invalid")]
+    fn test_plain_text_from_tag(component: Tag) -> String {
+        plain_text_from_tag(&component, TextFormat::Json)
+    }
+
+    #[test_case(Tag::String("[100, 64, -200]".to_string()) => "[100, 64, -200]"; "Coordinates")]
+    #[test_case(Tag::String(r#""quoted""#.to_string()) => r#""quoted""#; "Quoted")]
+    #[test_case(Tag::String(r#"{"text":"Hello"}"#.to_string()) => r#"{"text":"Hello"}"#; "JSON string")]
+    #[test_case(Tag::Compound(HashMap::from_iter([
+        ("text".to_string(), Tag::String("Hello".to_string())),
+        ("extra".to_string(), Tag::List(List::from(vec![Tag::String(" [1, 2]".to_string())]))),
+    ])) => "Hello [1, 2]"; "Compound")]
+    fn test_plain_text_from_nbt_tag(component: Tag) -> String {
+        plain_text_from_tag(&component, TextFormat::Nbt)
+    }
+
+    #[test_case(4189 => TextFormat::Json; "1.21.4")]
+    #[test_case(NBT_TEXT_DATA_VERSION => TextFormat::Nbt; "1.21.5")]
+    fn test_format_from_data_version(data_version: i32) -> TextFormat {
+        TextFormat::from_data_version(data_version)
+    }
+}
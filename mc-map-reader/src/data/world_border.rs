@@ -0,0 +1,108 @@
+//! The world border stored in the level.dat file.
+
+use jbe::Builder;
+
+use crate::coordinates::{BlockPos, ChunkPos};
+#[cfg(feature = "level_dat")]
+use crate::data::file_format::level_dat::LevelDat;
+pub use crate::data::load::world_border::*;
+
+/// Width of the world border of a new world in blocks.
+pub const DEFAULT_WORLD_BORDER_SIZE: f64 = 59_999_968.;
+
+/// The world border of the overworld.
+/// Only the border fields of the level.dat file are read, missing fields use the values of a new world.
+#[derive(Debug, Builder, Clone, PartialEq)]
+pub struct WorldBorder {
+    #[builder({default: 0.})]
+    pub center_x: f64,
+    #[builder({default: 0.})]
+    pub center_z: f64,
+    /// Width of the border in blocks.
+    #[builder({default: DEFAULT_WORLD_BORDER_SIZE})]
+    pub size: f64,
+}
+
+impl Default for WorldBorder {
+    fn default() -> Self {
+        Self {
+            center_x: 0.,
+            center_z: 0.,
+            size: DEFAULT_WORLD_BORDER_SIZE,
+        }
+    }
+}
+
+#[cfg(feature = "level_dat")]
+impl From<&LevelDat> for WorldBorder {
+    fn from(level: &LevelDat) -> Self {
+        Self {
+            center_x: level.border_center_x,
+            center_z: level.border_center_z,
+            size: level.border_size,
+        }
+    }
+}
+
+impl WorldBorder {
+    /// The block with the smallest coordinates inside of the border at the given height.
+    pub fn min_block(&self, y: i32) -> BlockPos {
+        let half = self.size / 2.;
+        BlockPos::new(
+            (self.center_x - half).ceil() as i32,
+            y,
+            (self.center_z - half).ceil() as i32,
+        )
+    }
+
+    /// The block with the largest coordinates inside of the border at the given height.
+    pub fn max_block(&self, y: i32) -> BlockPos {
+        let half = self.size / 2.;
+        BlockPos::new(
+            (self.center_x + half).floor() as i32 - 1,
+            y,
+            (self.center_z + half).floor() as i32 - 1,
+        )
+    }
+
+    /// The chunks containing the corners of the border.
+    pub fn chunk_corners(&self) -> (ChunkPos, ChunkPos) {
+        (self.min_block(0).chunk(), self.max_block(0).chunk())
+    }
+
+    /// Returns true if any block of the chunk is inside of the border.
+    pub fn overlaps_chunk(&self, chunk: ChunkPos) -> bool {
+        let (min, max) = self.chunk_corners();
+        (min.x..=max.x).contains(&chunk.x) && (min.z..=max.z).contains(&chunk.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn border(center_x: f64, center_z: f64, size: f64) -> WorldBorder {
+        WorldBorder {
+            center_x,
+            center_z,
+            size,
+        }
+    }
+
+    #[test_case(border(0., 0., 100.) => (BlockPos::new(-50, 0, -50), BlockPos::new(49, 0, 49)); "Centered")]
+    #[test_case(border(0.5, 0.5, 100.) => (BlockPos::new(-49, 0, -49), BlockPos::new(49, 0, 49)); "Block center")]
+    #[test_case(border(1000., -1000., 32.) => (BlockPos::new(984, 0, -1016), BlockPos::new(1015, 0, -985)); "Moved")]
+    #[test_case(border(0., 0., DEFAULT_WORLD_BORDER_SIZE) => (BlockPos::new(-29_999_984, 0, -29_999_984), BlockPos::new(29_999_983, 0, 29_999_983)); "Default")]
+    fn test_corners(border: WorldBorder) -> (BlockPos, BlockPos) {
+        (border.min_block(0), border.max_block(0))
+    }
+
+    #[test_case(0, 0 => true; "Center")]
+    #[test_case(-4, 3 => true; "Partly inside")]
+    #[test_case(-5, 0 => false; "Outside x")]
+    #[test_case(0, 4 => false; "Outside z")]
+    fn test_overlaps_chunk(x: i32, z: i32) -> bool {
+        border(0., 0., 100.).overlaps_chunk(ChunkPos::new(x, z))
+    }
+}
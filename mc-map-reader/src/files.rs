@@ -1,108 +1,107 @@
 use std::path::{Path, PathBuf};
 
+use crate::coordinates::{ChunkPos, RegionPos};
+
 #[derive(Debug)]
 pub struct RegionFile {
-    x: i32,
-    z: i32,
+    position: RegionPos,
     path: PathBuf,
 }
 
+/// The kind of data stored in a region file. Every kind is stored in its own directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionKind {
+    /// Terrain, sections and block entities stored in `region/`.
+    Chunks,
+    /// Points of interest like beds, job sites and nether portals stored in `poi/`.
+    Poi,
+    /// Entities stored in `entities/`.
+    Entities,
+}
+
+impl RegionKind {
+    /// The name of the directory the region files are stored in.
+    pub fn directory_name(&self) -> &'static str {
+        match self {
+            RegionKind::Chunks => "region",
+            RegionKind::Poi => "poi",
+            RegionKind::Entities => "entities",
+        }
+    }
+}
+
 impl RegionFile {
     pub fn x(&self) -> i32 {
-        self.x
+        self.position.x
     }
     pub fn z(&self) -> i32 {
-        self.z
+        self.position.z
+    }
+    pub fn position(&self) -> RegionPos {
+        self.position
     }
     pub fn as_path(&self) -> &Path {
         &self.path
     }
 }
 
-/// Return a list of all region files in the given area.
+/// Return the path of a region file. The file might not exist.
+pub fn region_file_path(
+    world_directory: &Path,
+    dimension_directory: Option<&Path>,
+    kind: RegionKind,
+    region: RegionPos,
+) -> PathBuf {
+    let mut region_file = PathBuf::from(world_directory);
+    if let Some(dimension) = dimension_directory {
+        region_file.push(dimension)
+    }
+    region_file.push(kind.directory_name());
+    region_file.push(region.file_name());
+    region_file
+}
+
+/// Return a list of all region files in the area spanned by both chunks.
 pub fn get_region_files_in_area(
     world_directory: &Path,
     dimension_directory: Option<&Path>,
-    chunk1_x: i64,
-    chunk1_z: i64,
-    chunk2_x: i64,
-    chunk2_z: i64,
+    chunk1: ChunkPos,
+    chunk2: ChunkPos,
 ) -> Vec<PathBuf> {
-    let chunk1_x = chunk1_x >> 5;
-    let chunk1_z = chunk1_z >> 5;
-    let chunk2_x = chunk2_x >> 5;
-    let chunk2_z = chunk2_z >> 5;
-
-    let x_axis_values = if chunk1_x < chunk2_x {
-        chunk1_x..=chunk2_x
-    } else {
-        chunk2_x..=chunk1_x
-    };
-    let z_axis_values = if chunk1_z < chunk2_z {
-        chunk1_z..=chunk2_z
-    } else {
-        chunk2_z..=chunk1_z
-    };
-    x_axis_values
+    get_regions_in_area(world_directory, dimension_directory, chunk1, chunk2)
         .into_iter()
-        .fold(Vec::new(), |mut vec, x| {
-            vec.extend(z_axis_values.clone().map(|z| (x, z)));
-            vec
-        })
-        .into_iter()
-        .map(|(x, z)| {
-            let mut region_file = PathBuf::from(world_directory);
-            if let Some(dimension) = dimension_directory {
-                region_file.push(dimension)
-            }
-            region_file.push(format!("region/r.{x}.{z}.mca"));
-            region_file
-        })
-        .filter(|region_file| region_file.exists())
+        .map(|region| region.path)
         .collect()
 }
 
+/// Return a list of all region files in the area spanned by both chunks.
 pub fn get_regions_in_area(
     world_directory: &Path,
     dimension_directory: Option<&Path>,
-    chunk1_x: i32,
-    chunk1_z: i32,
-    chunk2_x: i32,
-    chunk2_z: i32,
+    chunk1: ChunkPos,
+    chunk2: ChunkPos,
 ) -> Vec<RegionFile> {
-    let chunk1_x = chunk1_x >> 5;
-    let chunk1_z = chunk1_z >> 5;
-    let chunk2_x = chunk2_x >> 5;
-    let chunk2_z = chunk2_z >> 5;
-
-    let x_axis_values = if chunk1_x < chunk2_x {
-        chunk1_x..=chunk2_x
-    } else {
-        chunk2_x..=chunk1_x
-    };
-    let z_axis_values = if chunk1_z < chunk2_z {
-        chunk1_z..=chunk2_z
-    } else {
-        chunk2_z..=chunk1_z
-    };
-    x_axis_values
-        .into_iter()
-        .fold(Vec::new(), |mut vec, x| {
-            vec.extend(z_axis_values.clone().map(|z| (x, z)));
-            vec
-        })
-        .into_iter()
-        .map(|(x, z)| {
-            let mut region_file = PathBuf::from(world_directory);
-            if let Some(dimension) = dimension_directory {
-                region_file.push(dimension)
-            }
-            region_file.push(format!("region/r.{x}.{z}.mca"));
-            RegionFile {
-                z,
-                x,
-                path: region_file,
-            }
+    get_regions_of_kind_in_area(
+        world_directory,
+        dimension_directory,
+        RegionKind::Chunks,
+        chunk1,
+        chunk2,
+    )
+}
+
+/// Return a list of all region files of the given kind in the area spanned by both chunks.
+pub fn get_regions_of_kind_in_area(
+    world_directory: &Path,
+    dimension_directory: Option<&Path>,
+    kind: RegionKind,
+    chunk1: ChunkPos,
+    chunk2: ChunkPos,
+) -> Vec<RegionFile> {
+    RegionPos::area(chunk1.region(), chunk2.region())
+        .map(|position| RegionFile {
+            position,
+            path: region_file_path(world_directory, dimension_directory, kind, position),
         })
         .filter(|region_file| region_file.path.exists())
         .collect()
@@ -126,24 +125,31 @@ pub fn get_region_files(
 pub fn get_regions(
     world_dir: &Path,
     dimension_directory: Option<&Path>,
+) -> std::io::Result<Vec<RegionFile>> {
+    get_regions_of_kind(world_dir, dimension_directory, RegionKind::Chunks)
+}
+
+/// Return a list of all region files of the given kind.
+pub fn get_regions_of_kind(
+    world_dir: &Path,
+    dimension_directory: Option<&Path>,
+    kind: RegionKind,
 ) -> std::io::Result<Vec<RegionFile>> {
     let mut region_dir = PathBuf::from(world_dir);
     if let Some(dimension) = dimension_directory {
         region_dir.push(dimension)
     }
-    region_dir.push("region");
+    region_dir.push(kind.directory_name());
     std::fs::read_dir(region_dir)?
         .map(|entry| entry.map(|e| e.path()))
         .filter_map(|entry| {
             let res = entry.map(|path| {
-                let path_cow = path.to_string_lossy();
-                let mut split = path_cow.split('.').skip(1);
-                if let Some((x, z)) = split
-                    .next()
-                    .zip(split.next())
-                    .and_then(|(x, z)| x.parse().ok().zip(z.parse().ok()))
-                {
-                    Some(RegionFile { z, x, path })
+                let position = path
+                    .file_name()
+                    .and_then(|file_name| file_name.to_str())
+                    .and_then(RegionPos::from_file_name);
+                if let Some(position) = position {
+                    Some(RegionFile { position, path })
                 } else {
                     log::info!("Found file with unexpected format {}", path.display());
                     None
@@ -160,6 +166,8 @@ pub fn get_regions(
 
 #[cfg(test)]
 mod tests {
+    use super::RegionKind;
+    use crate::coordinates::{ChunkPos, RegionPos};
     use std::path::PathBuf;
     use test_case::test_case;
 
@@ -203,11 +211,11 @@ mod tests {
     #[test_case(64, 64, 96, 96, &[(2, 2)], false; "Region files out ouf range")]
     #[test_case(-10, -10, 10, 10, &[(0, 0), (-1,0), (0,-1), (-1,-1)], false; "Negative coordinates")]
     fn get_files_in_area(
-        x1: i64,
-        z1: i64,
-        x2: i64,
-        z2: i64,
-        expected: &'static [(i64, i64)],
+        x1: i32,
+        z1: i32,
+        x2: i32,
+        z2: i32,
+        expected: &'static [(i32, i32)],
         test_dim: bool,
     ) {
         let (world_dir, dimension) = if test_dim {
@@ -220,10 +228,8 @@ mod tests {
         let actual = super::get_region_files_in_area(
             &world_dir,
             dimension.as_ref().map(AsRef::as_ref),
-            x1,
-            z1,
-            x2,
-            z2,
+            ChunkPos::new(x1, z1),
+            ChunkPos::new(x2, z2),
         );
         let expected = expected
             .iter()
@@ -238,4 +244,16 @@ mod tests {
         assert!(expected.iter().all(|file_name| actual.contains(file_name)));
         assert!(actual.iter().all(|file_name| expected.contains(file_name)));
     }
+
+    #[test_case(None, RegionKind::Chunks, -1, 2 => PathBuf::from("world/region/r.-1.2.mca"); "Chunks")]
+    #[test_case(Some("DIM-1"), RegionKind::Poi, 0, 0 => PathBuf::from("world/DIM-1/poi/r.0.0.mca"); "Poi in nether")]
+    #[test_case(Some("dimensions/ns/name"), RegionKind::Entities, 3, -4 => PathBuf::from("world/dimensions/ns/name/entities/r.3.-4.mca"); "Entities in custom dimension")]
+    fn test_region_file_path(dimension: Option<&str>, kind: RegionKind, x: i32, z: i32) -> PathBuf {
+        super::region_file_path(
+            &PathBuf::from("world"),
+            dimension.map(std::path::Path::new),
+            kind,
+            RegionPos::new(x, z),
+        )
+    }
 }
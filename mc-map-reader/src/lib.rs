@@ -4,11 +4,17 @@
 
 //! This crate provides a way to read Minecraft saves.
 
+pub mod coordinates;
+pub use coordinates::{BlockPos, BoundingBox, ChunkPos, RegionPos, SectionPos};
 pub mod data;
 mod load;
 pub use load::*;
 mod compression;
 pub mod files;
 pub mod nbt;
+#[cfg(feature = "region_file")]
+pub mod world;
+#[cfg(feature = "region_file")]
+pub use world::World;
 #[cfg(test)]
 pub mod test_util;
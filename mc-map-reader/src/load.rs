@@ -1,5 +1,5 @@
 #[cfg(all(feature = "parallel", feature = "region_file"))]
-use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rayon::prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use thiserror::Error;
 
 use crate::data;
@@ -30,6 +30,13 @@ pub enum RegionLoadError {
     /// Error while loading the data of a chunk.
     #[error(transparent)]
     LoadChunkData(#[from] data::chunk::LoadChunkDataError),
+    /// Error while loading the entities of a chunk.
+    #[error(transparent)]
+    LoadEntityChunk(#[from] data::entity::EntityChunkError),
+    #[cfg(feature = "poi")]
+    /// Error while loading the points of interest of a chunk.
+    #[error(transparent)]
+    LoadPoiChunk(#[from] data::poi::PoiChunkError),
 }
 
 /// Errors that can occur when loading a level.dat file.
@@ -45,6 +52,34 @@ pub enum LevelDatLoadError {
     /// Some data in the level.dat file is not valid.
     #[error(transparent)]
     LevelDat(#[from] data::file_format::level_dat::LevelDatError),
+    /// The world border in the level.dat file is not valid.
+    #[error(transparent)]
+    WorldBorder(#[from] data::world_border::WorldBorderError),
+}
+
+/// Errors that can occur when loading a player data file.
+#[derive(Error, Debug)]
+pub enum PlayerDatLoadError {
+    /// Some data in the player data file is not valid NBT.
+    #[error(transparent)]
+    NBT(#[from] crate::nbt::Error),
+    /// Some data in the player data file could not be decompressed.
+    #[error(transparent)]
+    Compression(crate::compression::Error),
+    /// Some data in the player data file is not valid.
+    #[error(transparent)]
+    Player(#[from] data::file_format::player_dat::PlayerError),
+}
+
+#[cfg(not(tarpaulin_include))]
+/// Parse a player data file from the `playerdata` directory.
+pub fn parse_player_dat(
+    data: &[u8],
+) -> std::result::Result<data::file_format::player_dat::Player, PlayerDatLoadError> {
+    let data = crate::compression::decompress(data, &crate::compression::Compression::GZip)
+        .map_err(PlayerDatLoadError::Compression)?;
+    let data = crate::nbt::parse(data.as_slice())?;
+    data::file_format::player_dat::Player::try_from(data).map_err(PlayerDatLoadError::Player)
 }
 
 #[cfg(feature = "level_dat")]
@@ -60,13 +95,176 @@ pub fn parse_level_dat(data: &[u8]) -> std::result::Result<level_dat::LevelDat,
     LevelDat::try_from(data).map_err(LevelDatLoadError::LevelDat)
 }
 
+/// Read the world border from a level.dat file without parsing the other fields of the file.
+/// Missing values are set to the defaults of a new world.
+pub fn parse_world_border(
+    data: &[u8],
+) -> std::result::Result<data::world_border::WorldBorder, LevelDatLoadError> {
+    let data = crate::compression::decompress(data, &crate::compression::Compression::GZip)
+        .map_err(LevelDatLoadError::Compression)?;
+    let data = crate::nbt::parse(data.as_slice())?
+        .get_as_map()?
+        .remove("Data")
+        .ok_or(crate::nbt::Error::InvalidValue)?;
+    Ok(data::world_border::WorldBorder::try_from(data)?)
+}
+
 #[cfg(feature = "region_file")]
 #[cfg(not(tarpaulin_include))]
 /// Load a region file.
 pub fn load_region(
-    mut read: impl Read,
+    read: impl Read,
     ignore_saved_before: Option<i32>,
 ) -> Result<AnvilSave, RegionLoadError> {
+    load_anvil(
+        read,
+        ignore_saved_before,
+        |_| true,
+        |raw_chunk_data, chunk| Ok(data::chunk::load_chunk(raw_chunk_data, chunk)?),
+    )
+}
+
+#[cfg(feature = "region_file")]
+#[cfg(not(tarpaulin_include))]
+/// Load only the chunks of a region file that overlap the bounding box.
+/// All other chunks are skipped without being decompressed.
+pub fn load_region_in_box(
+    read: impl Read,
+    region: crate::coordinates::RegionPos,
+    bounding_box: &crate::coordinates::BoundingBox,
+) -> Result<AnvilSave, RegionLoadError> {
+    load_anvil(
+        read,
+        None,
+        |index| bounding_box.overlaps_chunk(region.chunk_at_index(index)),
+        |raw_chunk_data, chunk| Ok(data::chunk::load_chunk(raw_chunk_data, chunk)?),
+    )
+}
+
+#[cfg(all(feature = "region_file", feature = "block_entity"))]
+#[cfg(not(tarpaulin_include))]
+/// Get all block entities inside of the bounding box.
+/// Only the region files and chunks overlapping the bounding box are read.
+pub fn block_entities_in(
+    world_directory: &std::path::Path,
+    dimension_directory: Option<&std::path::Path>,
+    bounding_box: &crate::coordinates::BoundingBox,
+) -> Result<Vec<data::block_entity::BlockEntity>, RegionLoadError> {
+    let mut block_entities = Vec::new();
+    for region in bounding_box.regions() {
+        let path = crate::files::region_file_path(
+            world_directory,
+            dimension_directory,
+            crate::files::RegionKind::Chunks,
+            region,
+        );
+        if !path.exists() {
+            continue;
+        }
+        let region = load_region_in_box(std::fs::File::open(path)?, region, bounding_box)?;
+        block_entities.extend(
+            region
+                .chunks
+                .into_iter()
+                .filter_map(|chunk| chunk.block_entities)
+                .flatten()
+                .filter(|block_entity| bounding_box.contains(block_entity.position())),
+        );
+    }
+    Ok(block_entities)
+}
+
+#[cfg(feature = "poi")]
+#[cfg(not(tarpaulin_include))]
+/// Load a region file from the `poi` directory of a dimension.
+pub fn load_poi_region(read: impl Read) -> Result<AnvilSave<data::poi::PoiChunk>, RegionLoadError> {
+    load_anvil(
+        read,
+        None,
+        |_| true,
+        |raw_chunk_data, chunk| {
+            let tag = data::chunk::load_chunk_tag(raw_chunk_data, chunk)?;
+            Ok(data::poi::PoiChunk::try_from(tag)?)
+        },
+    )
+}
+
+#[cfg(feature = "region_file")]
+#[cfg(not(tarpaulin_include))]
+/// Load a region file from the `entities` directory of a dimension.
+pub fn load_entity_region(
+    read: impl Read,
+) -> Result<AnvilSave<data::entity::EntityChunk>, RegionLoadError> {
+    load_anvil(
+        read,
+        None,
+        |_| true,
+        |raw_chunk_data, chunk| {
+            let tag = data::chunk::load_chunk_tag(raw_chunk_data, chunk)?;
+            Ok(data::entity::EntityChunk::try_from(tag)?)
+        },
+    )
+}
+
+#[cfg(feature = "region_file")]
+#[cfg(not(tarpaulin_include))]
+/// Load a region file from the `entities` directory without parsing the chunks.
+/// Use this to read entity data which is not part of [`data::entity::Entity`].
+pub fn load_entity_region_tags(
+    read: impl Read,
+) -> Result<AnvilSave<crate::nbt::Tag>, RegionLoadError> {
+    load_anvil(
+        read,
+        None,
+        |_| true,
+        |raw_chunk_data, chunk| Ok(data::chunk::load_chunk_tag(raw_chunk_data, chunk)?),
+    )
+}
+
+#[cfg(all(feature = "region_file", feature = "chunk_section"))]
+#[cfg(not(tarpaulin_include))]
+/// Look up the biome at a block position in a world.
+/// Only the chunk containing the position is decoded.
+/// Returns `None` if the chunk containing the position has not been generated.
+pub fn biome_at(
+    world_directory: &std::path::Path,
+    dimension_directory: Option<&std::path::Path>,
+    pos: crate::coordinates::BlockPos,
+) -> Result<Option<String>, RegionLoadError> {
+    let path = crate::files::region_file_path(
+        world_directory,
+        dimension_directory,
+        crate::files::RegionKind::Chunks,
+        pos.region(),
+    );
+    if !path.exists() {
+        return Ok(None);
+    }
+    let bounding_box = crate::coordinates::BoundingBox::from_corners(pos, pos);
+    let region = load_region_in_box(std::fs::File::open(path)?, pos.region(), &bounding_box)?;
+    Ok(region
+        .chunks
+        .iter()
+        .find(|chunk| chunk.position() == pos.chunk())
+        .and_then(|chunk| chunk.biome_at(pos))
+        .cloned())
+}
+
+#[cfg(feature = "region_file")]
+#[cfg(not(tarpaulin_include))]
+/// Read the header of an anvil file and load every chunk with `load_chunk`.
+/// Chunks whose index in the header is rejected by `include_chunk` are skipped.
+fn load_anvil<C, I, F>(
+    mut read: impl Read,
+    ignore_saved_before: Option<i32>,
+    include_chunk: I,
+    load_chunk: F,
+) -> Result<AnvilSave<C>, RegionLoadError>
+where
+    C: Send,
+    I: Fn(usize) -> bool + Sync,
+    F: Fn(&[u8], &anvil::ChunkInfo) -> Result<C, RegionLoadError> + Sync,
+{
     let mut raw_header = [0; anvil::MC_REGION_HEADER_SIZE];
     if read.read(&mut raw_header)? != anvil::MC_REGION_HEADER_SIZE {
         return Err(std::io::Error::new(
@@ -84,13 +282,15 @@ pub fn load_region(
     #[cfg(not(feature = "parallel"))]
     let chunk_info = header.get_chunk_info().iter();
     let chunks = chunk_info
-        .filter_map(|ci| ci.as_ref())
+        .enumerate()
+        .filter(|(index, _)| include_chunk(*index))
+        .filter_map(|(_, ci)| ci.as_ref())
         .filter(|chunk_info| {
             ignore_saved_before.map_or(true, |ignore_saved_before| {
                 chunk_info.timestamp as i32 >= ignore_saved_before
             })
         })
-        .map(|chunk| data::chunk::load_chunk(&raw_chunk_data, chunk))
+        .map(|chunk| load_chunk(&raw_chunk_data, chunk))
         .collect::<std::result::Result<_, _>>()?;
 
     Ok(AnvilSave::new(header, chunks))
@@ -106,4 +306,189 @@ mod tests {
 
         data.push(0);
     }
+
+    #[test]
+    fn test_parse_world_border() {
+        use crate::{
+            data::world_border::WorldBorder,
+            nbt::Tag,
+            test_util::{gzip, nbt_bytes},
+        };
+        use std::collections::HashMap;
+
+        // Only the border fields are read, other fields of the level.dat file are ignored
+        let level = HashMap::from_iter([(
+            "Data".to_string(),
+            Tag::Compound(HashMap::from_iter([
+                ("BorderCenterX".to_string(), Tag::Double(8.)),
+                ("BorderSize".to_string(), Tag::Double(1000.)),
+                ("LevelName".to_string(), Tag::String("world".to_string())),
+            ])),
+        )]);
+        let border = super::parse_world_border(&gzip(&nbt_bytes(level)))
+            .expect("Error parsing world border");
+        assert_eq!(
+            border,
+            WorldBorder {
+                center_x: 8.,
+                center_z: 0.,
+                size: 1000.,
+            }
+        );
+    }
+
+    #[cfg(feature = "block_entity")]
+    mod block_entities_in {
+        use std::collections::HashMap;
+
+        use crate::{
+            coordinates::{BlockPos, BoundingBox},
+            nbt::{List, Tag},
+            test_util::{region_file_bytes, TempDir},
+        };
+
+        fn compound(values: Vec<(&str, Tag)>) -> HashMap<String, Tag> {
+            values
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect()
+        }
+
+        fn chunk(x: i32, z: i32, chests: &[(i32, i32, i32)]) -> HashMap<String, Tag> {
+            let chests = chests
+                .iter()
+                .map(|(x, y, z)| {
+                    Tag::Compound(compound(vec![
+                        ("id", Tag::String("minecraft:chest".to_string())),
+                        ("x", Tag::Int(*x)),
+                        ("y", Tag::Int(*y)),
+                        ("z", Tag::Int(*z)),
+                        ("Items", Tag::List(List::from(vec![]))),
+                    ]))
+                })
+                .collect::<Vec<_>>();
+            compound(vec![
+                ("DataVersion", Tag::Int(3465)),
+                ("xPos", Tag::Int(x)),
+                ("yPos", Tag::Int(-4)),
+                ("zPos", Tag::Int(z)),
+                ("Status", Tag::String("full".to_string())),
+                ("LastUpdate", Tag::Long(0)),
+                ("sections", Tag::List(List::from(vec![]))),
+                ("block_entities", Tag::List(List::from(chests))),
+            ])
+        }
+
+        #[test]
+        fn test_block_entities_in() {
+            let dir = TempDir::new("load_block_entities_in");
+            dir.write(
+                "region/r.0.0.mca",
+                &region_file_bytes(vec![
+                    (0, chunk(0, 0, &[(1, 64, 1), (2, 100, 2)])),
+                    (1, chunk(1, 0, &[(17, 64, 1)])),
+                    // Chunks outside of the box are not decoded
+                    (2, compound(vec![("DataVersion", Tag::Int(3465))])),
+                ]),
+            );
+            dir.write(
+                "region/r.-1.0.mca",
+                &region_file_bytes(vec![(31, chunk(-1, 0, &[(-1, 64, 15)]))]),
+            );
+            let mut positions = super::super::block_entities_in(
+                dir.path(),
+                None,
+                &BoundingBox::from_corners(BlockPos::new(-16, 0, 0), BlockPos::new(20, 70, 15)),
+            )
+            .expect("Error loading block entities")
+            .into_iter()
+            .map(|block_entity| block_entity.position())
+            .collect::<Vec<_>>();
+            positions.sort();
+            assert_eq!(
+                positions,
+                vec![
+                    BlockPos::new(-1, 64, 15),
+                    BlockPos::new(1, 64, 1),
+                    BlockPos::new(17, 64, 1)
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "chunk_section")]
+    mod biome_at {
+        use std::collections::HashMap;
+
+        use crate::{
+            coordinates::BlockPos,
+            nbt::{List, Tag},
+            test_util::{region_file_bytes, TempDir},
+        };
+
+        fn compound(values: Vec<(&str, Tag)>) -> HashMap<String, Tag> {
+            values
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect()
+        }
+
+        fn chunk(x: i32, z: i32, biome: &str) -> HashMap<String, Tag> {
+            let section = compound(vec![
+                ("Y", Tag::Byte(0)),
+                (
+                    "block_states",
+                    Tag::Compound(compound(vec![(
+                        "palette",
+                        Tag::List(List::from(vec![Tag::Compound(compound(vec![(
+                            "Name",
+                            Tag::String("minecraft:air".to_string()),
+                        )]))])),
+                    )])),
+                ),
+                (
+                    "biomes",
+                    Tag::Compound(compound(vec![(
+                        "palette",
+                        Tag::List(List::from(vec![Tag::String(biome.to_string())])),
+                    )])),
+                ),
+            ]);
+            compound(vec![
+                ("DataVersion", Tag::Int(3465)),
+                ("xPos", Tag::Int(x)),
+                ("yPos", Tag::Int(-4)),
+                ("zPos", Tag::Int(z)),
+                ("Status", Tag::String("full".to_string())),
+                ("LastUpdate", Tag::Long(0)),
+                (
+                    "sections",
+                    Tag::List(List::from(vec![Tag::Compound(section)])),
+                ),
+            ])
+        }
+
+        #[test]
+        fn test_biome_at() {
+            let dir = TempDir::new("load_biome_at");
+            dir.write(
+                "region/r.0.0.mca",
+                &region_file_bytes(vec![
+                    (0, chunk(0, 0, "minecraft:plains")),
+                    (1, chunk(1, 0, "minecraft:desert")),
+                    // Other chunks of the region are not decoded
+                    (2, compound(vec![("DataVersion", Tag::Int(3465))])),
+                ]),
+            );
+            let biome = |x, y, z| {
+                super::super::biome_at(dir.path(), None, BlockPos::new(x, y, z))
+                    .expect("Error loading biome")
+            };
+            assert_eq!(biome(17, 5, 3), Some("minecraft:desert".to_string()));
+            assert_eq!(biome(1, 5, 3), Some("minecraft:plains".to_string()));
+            assert_eq!(biome(1, 100, 3), None);
+            assert_eq!(biome(100, 5, 3), None);
+            assert_eq!(biome(-1, 5, 3), None);
+        }
+    }
 }
@@ -1,4 +1,9 @@
-use std::{collections::HashMap, ops::Deref, vec::IntoIter};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    vec::IntoIter,
+};
 
 use thiserror::Error;
 
@@ -315,6 +320,66 @@ impl<T> Deref for List<T> {
     }
 }
 
+/// Hashes tags independent of the order of compound entries.
+/// `-0.0` and `0.0` as well as all NaN values hash the same, so tags which are equal always have the same hash.
+impl Hash for Tag {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_id().hash(state);
+        match self {
+            Self::End => {}
+            Self::Byte(value) => value.hash(state),
+            Self::Short(value) => value.hash(state),
+            Self::Int(value) => value.hash(state),
+            Self::Long(value) => value.hash(state),
+            Self::Float(value) => canonical_f32_bits(*value).hash(state),
+            Self::Double(value) => canonical_f64_bits(*value).hash(state),
+            Self::ByteArray(values) => values.hash(state),
+            Self::String(value) => value.hash(state),
+            Self::List(values) => values.hash(state),
+            Self::Compound(values) => hash_compound(values, state),
+            Self::IntArray(values) => values.hash(state),
+            Self::LongArray(values) => values.hash(state),
+        }
+    }
+}
+
+impl<T: Hash> Hash for Array<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+/// Hashes the entries of a compound tag sorted by their keys.
+/// Hashes the same as [Tag::Compound] without the tag id.
+pub fn hash_compound<H: Hasher>(values: &HashMap<String, Tag>, state: &mut H) {
+    let mut entries = values.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    entries.hash(state)
+}
+
+fn canonical_f32_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        // Adding zero turns -0.0 into 0.0
+        (value + 0.0).to_bits()
+    }
+}
+
+fn canonical_f64_bits(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        (value + 0.0).to_bits()
+    }
+}
+
 /// Parse a NBT tag from a byte slice.
 pub fn parse(data: &[u8]) -> Result<Tag, Error> {
     match data[0] {
@@ -688,4 +753,37 @@ mod tests {
         result.sort_by(|a, b| a.0.cmp(&b.0));
         result
     }
+
+    fn hash(tag: &Tag) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        tag.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_hash_ignores_compound_order() {
+        let entries = (0..32)
+            .map(|i| (format!("key{i}"), Tag::Int(i)))
+            .collect::<Vec<_>>();
+        let forward = Tag::Compound(HashMap::from_iter(entries.clone()));
+        let backward = Tag::Compound(HashMap::from_iter(entries.into_iter().rev()));
+        assert_eq!(hash(&forward), hash(&backward));
+    }
+
+    #[test_case(Tag::Float(0.0), Tag::Float(-0.0) => true; "Float zero")]
+    #[test_case(Tag::Double(0.0), Tag::Double(-0.0) => true; "Double zero")]
+    #[test_case(Tag::Float(f32::NAN), Tag::Float(-f32::NAN) => true; "Float NaN")]
+    #[test_case(Tag::Double(f64::NAN), Tag::Double(f64::from_bits(f64::NAN.to_bits() + 1)) => true; "Double NaN")]
+    #[test_case(Tag::Float(1.0), Tag::Double(1.0) => false; "Different types")]
+    #[test_case(Tag::Int(1), Tag::Long(1) => false; "Different integer types")]
+    #[test_case(Tag::String("a".to_string()), Tag::String("b".to_string()) => false; "Different strings")]
+    #[test_case(
+        Tag::List(List(vec![Tag::Byte(1), Tag::Byte(2)])),
+        Tag::List(List(vec![Tag::Byte(2), Tag::Byte(1)])) => false;
+        "List order"
+    )]
+    fn test_hash_equal(a: Tag, b: Tag) -> bool {
+        hash(&a) == hash(&b)
+    }
 }
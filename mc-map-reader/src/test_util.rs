@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+#[cfg(feature = "region_file")]
+use crate::data::chunk::{ChunkData, ChunkStatus};
 use crate::nbt::Tag;
 
 pub type TestDataProvider<T> = dyn Fn() -> T;
@@ -19,3 +21,164 @@ pub fn merge(mut map: HashMap<String, Tag>, other: HashMap<String, Tag>) -> Hash
     map.extend(other);
     map
 }
+
+/// A fully generated chunk of 1.20.1 without sections, heightmaps, structures, ticks or block entities.
+/// Chunks with specific data can be built from it using struct update syntax.
+#[cfg(feature = "region_file")]
+pub fn empty_chunk(x_pos: i32, z_pos: i32) -> ChunkData {
+    ChunkData {
+        data_version: 3465,
+        x_pos,
+        y_pos: -4,
+        z_pos,
+        status: ChunkStatus::Full,
+        last_update: 0,
+        #[cfg(feature = "chunk_section")]
+        sections: crate::nbt::List::from(vec![]),
+        height_maps: None,
+        structures: None,
+        block_ticks: None,
+        fluid_ticks: None,
+        block_entities: None,
+    }
+}
+
+/// Serialize a compound as an unnamed NBT root tag.
+pub fn nbt_bytes(compound: HashMap<String, Tag>) -> Vec<u8> {
+    let mut data = vec![10, 0, 0];
+    write_payload(&mut data, &Tag::Compound(compound));
+    data
+}
+
+/// Compress data like a level.dat or player data file.
+pub fn gzip(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoded = Vec::new();
+    let mut encoder = libflate::gzip::Encoder::new(&mut encoded).expect("Could not create encoder");
+    encoder.write_all(data).expect("Could not compress data");
+    encoder
+        .finish()
+        .into_result()
+        .expect("Could not compress data");
+    encoded
+}
+
+fn tag_id(tag: &Tag) -> u8 {
+    match tag {
+        Tag::End => 0,
+        Tag::Byte(_) => 1,
+        Tag::Short(_) => 2,
+        Tag::Int(_) => 3,
+        Tag::Long(_) => 4,
+        Tag::Float(_) => 5,
+        Tag::Double(_) => 6,
+        Tag::ByteArray(_) => 7,
+        Tag::String(_) => 8,
+        Tag::List(_) => 9,
+        Tag::Compound(_) => 10,
+        Tag::IntArray(_) => 11,
+        Tag::LongArray(_) => 12,
+    }
+}
+
+fn write_string(data: &mut Vec<u8>, string: &str) {
+    data.extend((string.len() as u16).to_be_bytes());
+    data.extend(string.as_bytes());
+}
+
+fn write_payload(data: &mut Vec<u8>, tag: &Tag) {
+    match tag {
+        Tag::End => {}
+        Tag::Byte(v) => data.extend(v.to_be_bytes()),
+        Tag::Short(v) => data.extend(v.to_be_bytes()),
+        Tag::Int(v) => data.extend(v.to_be_bytes()),
+        Tag::Long(v) => data.extend(v.to_be_bytes()),
+        Tag::Float(v) => data.extend(v.to_be_bytes()),
+        Tag::Double(v) => data.extend(v.to_be_bytes()),
+        Tag::ByteArray(v) => {
+            data.extend((v.len() as i32).to_be_bytes());
+            v.iter().for_each(|b| data.extend(b.to_be_bytes()));
+        }
+        Tag::String(v) => write_string(data, v),
+        Tag::List(v) => {
+            data.push(v.first().map_or(0, tag_id));
+            data.extend((v.len() as i32).to_be_bytes());
+            v.iter().for_each(|tag| write_payload(data, tag));
+        }
+        Tag::Compound(v) => {
+            for (key, tag) in v {
+                data.push(tag_id(tag));
+                write_string(data, key);
+                write_payload(data, tag);
+            }
+            data.push(0);
+        }
+        Tag::IntArray(v) => {
+            data.extend((v.len() as i32).to_be_bytes());
+            v.iter().for_each(|i| data.extend(i.to_be_bytes()));
+        }
+        Tag::LongArray(v) => {
+            data.extend((v.len() as i32).to_be_bytes());
+            v.iter().for_each(|l| data.extend(l.to_be_bytes()));
+        }
+    }
+}
+
+/// Build an uncompressed region file.
+/// Every chunk is given by its index in the region and its NBT data.
+pub fn region_file_bytes(chunks: Vec<(usize, HashMap<String, Tag>)>) -> Vec<u8> {
+    const SECTOR_SIZE: usize = 4096;
+    let mut header = vec![0_u8; 2 * SECTOR_SIZE];
+    let mut body = Vec::new();
+    for (index, chunk) in chunks {
+        let nbt = nbt_bytes(chunk);
+        let sector = 2 + body.len() / SECTOR_SIZE;
+        // The stored length includes the length itself and the compression type.
+        let mut chunk_data = ((nbt.len() + 5) as u32).to_be_bytes().to_vec();
+        chunk_data.push(3);
+        chunk_data.extend(nbt);
+        let sectors = chunk_data.len().div_ceil(SECTOR_SIZE);
+        chunk_data.resize(sectors * SECTOR_SIZE, 0);
+        header[index * 4..index * 4 + 4].copy_from_slice(&[
+            (sector >> 16) as u8,
+            (sector >> 8) as u8,
+            sector as u8,
+            sectors as u8,
+        ]);
+        body.extend(chunk_data);
+    }
+    header.extend(body);
+    header
+}
+
+/// A temporary directory that is removed when it is dropped.
+pub struct TempDir(std::path::PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("mc-map-reader-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).expect("Could not create temp dir");
+        Self(path)
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.0
+    }
+
+    /// Write a file relative to the temporary directory and create missing parent directories.
+    pub fn write(&self, file: &str, content: &[u8]) {
+        let path = self.0.join(file);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("Could not create directory");
+        }
+        std::fs::write(path, content).expect("Could not write file");
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
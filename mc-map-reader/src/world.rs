@@ -0,0 +1,802 @@
+//! High level access to a Minecraft save directory.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+#[cfg(feature = "block_entity")]
+use crate::data::block_entity::BlockEntity;
+#[cfg(feature = "level_dat")]
+use crate::data::file_format::level_dat::LevelDat;
+#[cfg(feature = "poi")]
+use crate::data::poi::PoiChunk;
+use crate::{
+    coordinates::{BoundingBox, ChunkPos, RegionPos},
+    data::{
+        chunk::ChunkData,
+        entity::{Entity, EntityChunk},
+        file_format::{anvil::AnvilSave, player_dat::Player},
+    },
+    files::{self, RegionKind},
+    nbt::Tag,
+    PlayerDatLoadError, RegionLoadError,
+};
+
+/// Number of decoded regions kept in memory by [`World::open`].
+pub const DEFAULT_CACHE_SIZE: usize = 16;
+
+/// Errors that can occur when reading a world.
+#[derive(Error, Debug)]
+pub enum WorldError {
+    /// Error while reading from the save directory.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// Error while loading a region file.
+    #[error(transparent)]
+    RegionLoad(#[from] RegionLoadError),
+    /// Error while loading a player data file.
+    #[error(transparent)]
+    PlayerDatLoad(#[from] PlayerDatLoadError),
+    #[cfg(feature = "level_dat")]
+    /// Error while loading the level.dat file.
+    #[error(transparent)]
+    LevelDatLoad(#[from] crate::LevelDatLoadError),
+    /// The world does not contain the requested dimension.
+    #[error("Unknown dimension {0}")]
+    UnknownDimension(String),
+}
+
+/// A dimension found in the save directory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    id: String,
+    directory: Option<PathBuf>,
+}
+
+impl Dimension {
+    /// Id of the overworld.
+    pub const OVERWORLD: &'static str = "minecraft:overworld";
+    /// Id of the nether.
+    pub const NETHER: &'static str = "minecraft:the_nether";
+    /// Id of the end.
+    pub const END: &'static str = "minecraft:the_end";
+
+    /// The overworld, stored directly in the save directory.
+    pub fn overworld() -> Self {
+        Self {
+            id: Self::OVERWORLD.to_string(),
+            directory: None,
+        }
+    }
+
+    /// The nether, stored in `DIM-1`.
+    pub fn nether() -> Self {
+        Self {
+            id: Self::NETHER.to_string(),
+            directory: Some(PathBuf::from("DIM-1")),
+        }
+    }
+
+    /// The end, stored in `DIM1`.
+    pub fn end() -> Self {
+        Self {
+            id: Self::END.to_string(),
+            directory: Some(PathBuf::from("DIM1")),
+        }
+    }
+
+    /// The namespaced id of the dimension e.g. `minecraft:overworld`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The directory of the dimension relative to the save directory. `None` for the overworld.
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+}
+
+/// A Minecraft save directory.
+///
+/// Decoded regions are kept in a least recently used cache so repeated queries
+/// in the same area do not read the region files again.
+pub struct World {
+    directory: PathBuf,
+    dimensions: Vec<Dimension>,
+    chunk_regions: RegionCache<ChunkData>,
+    entity_regions: RegionCache<EntityChunk>,
+}
+
+impl World {
+    /// Open a save directory and discover its dimensions.
+    pub fn open(directory: impl AsRef<Path>) -> Result<Self, WorldError> {
+        Self::with_cache_size(directory, DEFAULT_CACHE_SIZE)
+    }
+
+    /// Like [`World::open`] but keeps up to `cache_size` decoded regions per region kind in memory.
+    pub fn with_cache_size(
+        directory: impl AsRef<Path>,
+        cache_size: usize,
+    ) -> Result<Self, WorldError> {
+        let directory = directory.as_ref().to_path_buf();
+        let dimensions = discover_dimensions(&directory)?;
+        Ok(Self {
+            directory,
+            dimensions,
+            chunk_regions: RegionCache::new(cache_size),
+            entity_regions: RegionCache::new(cache_size),
+        })
+    }
+
+    /// The save directory.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// All dimensions found in the save directory.
+    pub fn dimensions(&self) -> &[Dimension] {
+        &self.dimensions
+    }
+
+    /// Get a dimension by its id e.g. `minecraft:the_nether`.
+    pub fn dimension(&self, id: &str) -> Option<&Dimension> {
+        self.dimensions.iter().find(|dimension| dimension.id == id)
+    }
+
+    /// Get a chunk. Returns `None` if the chunk has not been generated.
+    pub fn chunk(
+        &mut self,
+        dimension: &str,
+        position: ChunkPos,
+    ) -> Result<Option<&ChunkData>, WorldError> {
+        let region = self.chunk_region(dimension, position.region())?;
+        Ok(region.and_then(|region| {
+            region
+                .chunks
+                .iter()
+                .find(|chunk| chunk.position() == position)
+        }))
+    }
+
+    #[cfg(feature = "block_entity")]
+    /// Get all block entities inside of the bounding box.
+    pub fn block_entities_in(
+        &mut self,
+        dimension: &str,
+        bounding_box: &BoundingBox,
+    ) -> Result<Vec<BlockEntity>, WorldError> {
+        let mut block_entities = Vec::new();
+        for region in bounding_box.regions() {
+            let Some(region) = self.chunk_region(dimension, region)? else {
+                continue;
+            };
+            block_entities.extend(
+                region
+                    .chunks
+                    .iter()
+                    .filter(|chunk| bounding_box.overlaps_chunk(chunk.position()))
+                    .filter_map(|chunk| chunk.block_entities.as_ref())
+                    .flat_map(|block_entities| block_entities.iter())
+                    .filter(|block_entity| bounding_box.contains(block_entity.position()))
+                    .cloned(),
+            );
+        }
+        Ok(block_entities)
+    }
+
+    /// Get all entities inside of the bounding box.
+    /// Entities are read from the `entities` directory used since Minecraft 1.17.
+    pub fn entities_in(
+        &mut self,
+        dimension: &str,
+        bounding_box: &BoundingBox,
+    ) -> Result<Vec<Entity>, WorldError> {
+        let mut entities = Vec::new();
+        for region in bounding_box.regions() {
+            let path = self.region_path(dimension, RegionKind::Entities, region)?;
+            let region = self
+                .entity_regions
+                .get_or_load(&path, || Ok(crate::load_entity_region(File::open(&path)?)?))?;
+            let Some(region) = region else {
+                continue;
+            };
+            entities.extend(
+                region
+                    .chunks
+                    .iter()
+                    .filter(|chunk| {
+                        chunk
+                            .chunk_position()
+                            .is_some_and(|position| bounding_box.overlaps_chunk(position))
+                    })
+                    .flat_map(|chunk| chunk.entities.iter())
+                    .filter(|entity| {
+                        entity
+                            .block_pos()
+                            .is_some_and(|position| bounding_box.contains(position))
+                    })
+                    .cloned(),
+            );
+        }
+        Ok(entities)
+    }
+
+    /// Positions of the region files of a kind in a dimension. If a bounding box is given only
+    /// regions overlapping it are returned. A dimension without a directory for the kind has no regions.
+    ///
+    /// Use this with [`World::load_chunk_region`], [`World::load_entity_region_tags`] or
+    /// [`World::load_poi_region`] to scan a whole dimension without keeping the regions in memory.
+    pub fn region_positions(
+        &self,
+        dimension: &str,
+        kind: RegionKind,
+        bounding_box: Option<&BoundingBox>,
+    ) -> Result<Vec<RegionPos>, WorldError> {
+        let dimension = self.dimension_directory(dimension)?;
+        let regions = match bounding_box {
+            Some(bounding_box) => files::get_regions_of_kind_in_area(
+                &self.directory,
+                dimension.as_deref(),
+                kind,
+                bounding_box.min().chunk(),
+                bounding_box.max().chunk(),
+            ),
+            None => match files::get_regions_of_kind(&self.directory, dimension.as_deref(), kind) {
+                Ok(regions) => regions,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(e) => return Err(e.into()),
+            },
+        };
+        let mut positions = regions
+            .iter()
+            .map(files::RegionFile::position)
+            .collect::<Vec<_>>();
+        positions.sort();
+        Ok(positions)
+    }
+
+    /// Decode a region file of chunks without caching it. If a bounding box is given only the
+    /// chunks overlapping it are decoded.
+    pub fn load_chunk_region(
+        &self,
+        dimension: &str,
+        region: RegionPos,
+        bounding_box: Option<&BoundingBox>,
+    ) -> Result<AnvilSave, WorldError> {
+        let file = File::open(self.region_path(dimension, RegionKind::Chunks, region)?)?;
+        Ok(match bounding_box {
+            Some(bounding_box) => crate::load_region_in_box(file, region, bounding_box)?,
+            None => crate::load_region(file, None)?,
+        })
+    }
+
+    /// Read a region file of entities without parsing the chunks and without caching it.
+    /// See [`crate::load_entity_region_tags`].
+    pub fn load_entity_region_tags(
+        &self,
+        dimension: &str,
+        region: RegionPos,
+    ) -> Result<AnvilSave<Tag>, WorldError> {
+        let file = File::open(self.region_path(dimension, RegionKind::Entities, region)?)?;
+        Ok(crate::load_entity_region_tags(file)?)
+    }
+
+    #[cfg(feature = "poi")]
+    /// Decode a region file of points of interest without caching it.
+    pub fn load_poi_region(
+        &self,
+        dimension: &str,
+        region: RegionPos,
+    ) -> Result<AnvilSave<PoiChunk>, WorldError> {
+        let file = File::open(self.region_path(dimension, RegionKind::Poi, region)?)?;
+        Ok(crate::load_poi_region(file)?)
+    }
+
+    /// The UUIDs of all players with a data file in the `playerdata` directory, sorted.
+    pub fn player_uuids(&self) -> Result<Vec<String>, WorldError> {
+        let directory = self.directory.join("playerdata");
+        if !directory.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut uuids = Vec::new();
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "dat") {
+                uuids.extend(
+                    path.file_stem()
+                        .map(|uuid| uuid.to_string_lossy().to_string()),
+                );
+            }
+        }
+        uuids.sort();
+        Ok(uuids)
+    }
+
+    /// Read the data file of a player.
+    pub fn player(&self, uuid: &str) -> Result<Player, WorldError> {
+        let path = self
+            .directory
+            .join("playerdata")
+            .join(format!("{uuid}.dat"));
+        Ok(crate::parse_player_dat(&std::fs::read(path)?)?)
+    }
+
+    /// Read all player data files from the `playerdata` directory.
+    pub fn players(&self) -> Result<Vec<Player>, WorldError> {
+        self.player_uuids()?
+            .iter()
+            .map(|uuid| self.player(uuid))
+            .collect()
+    }
+
+    #[cfg(feature = "level_dat")]
+    /// Read the level.dat file.
+    pub fn level(&self) -> Result<LevelDat, WorldError> {
+        let data = std::fs::read(self.directory.join("level.dat"))?;
+        Ok(crate::parse_level_dat(&data)?)
+    }
+
+    fn dimension_directory(&self, dimension: &str) -> Result<Option<PathBuf>, WorldError> {
+        self.dimension(dimension)
+            .map(|dimension| dimension.directory.clone())
+            .ok_or_else(|| WorldError::UnknownDimension(dimension.to_string()))
+    }
+
+    fn region_path(
+        &self,
+        dimension: &str,
+        kind: RegionKind,
+        region: RegionPos,
+    ) -> Result<PathBuf, WorldError> {
+        let dimension = self.dimension_directory(dimension)?;
+        Ok(files::region_file_path(
+            &self.directory,
+            dimension.as_deref(),
+            kind,
+            region,
+        ))
+    }
+
+    fn chunk_region(
+        &mut self,
+        dimension: &str,
+        region: RegionPos,
+    ) -> Result<Option<&AnvilSave>, WorldError> {
+        let path = self.region_path(dimension, RegionKind::Chunks, region)?;
+        self.chunk_regions
+            .get_or_load(&path, || Ok(crate::load_region(File::open(&path)?, None)?))
+    }
+}
+
+/// Find the dimensions stored in a save directory.
+/// Custom dimensions are stored in `dimensions/<namespace>/<path>`.
+fn discover_dimensions(directory: &Path) -> std::io::Result<Vec<Dimension>> {
+    let mut dimensions = [
+        Dimension::overworld(),
+        Dimension::nether(),
+        Dimension::end(),
+    ]
+    .into_iter()
+    .filter(|dimension| {
+        let mut path = directory.to_path_buf();
+        if let Some(dimension_directory) = dimension.directory() {
+            path.push(dimension_directory);
+        }
+        path.join(RegionKind::Chunks.directory_name()).is_dir()
+    })
+    .collect::<Vec<_>>();
+
+    let custom_dimensions = directory.join("dimensions");
+    if custom_dimensions.is_dir() {
+        let mut custom = Vec::new();
+        for namespace in std::fs::read_dir(&custom_dimensions)? {
+            let namespace = namespace?.path();
+            if !namespace.is_dir() {
+                continue;
+            }
+            find_custom_dimensions(directory, &namespace, &namespace, &mut custom)?;
+        }
+        custom.sort_by(|a, b| a.id.cmp(&b.id));
+        custom.retain(|dimension| !dimensions.iter().any(|known| known.id == dimension.id));
+        dimensions.extend(custom);
+    }
+    Ok(dimensions)
+}
+
+fn find_custom_dimensions(
+    world_directory: &Path,
+    namespace_directory: &Path,
+    directory: &Path,
+    dimensions: &mut Vec<Dimension>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let is_dimension = [RegionKind::Chunks, RegionKind::Entities, RegionKind::Poi]
+            .iter()
+            .any(|kind| path.join(kind.directory_name()).is_dir());
+        if is_dimension {
+            let (Some(namespace), Ok(name), Ok(relative)) = (
+                namespace_directory.file_name(),
+                path.strip_prefix(namespace_directory),
+                path.strip_prefix(world_directory),
+            ) else {
+                continue;
+            };
+            let name = name
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            dimensions.push(Dimension {
+                id: format!("{}:{name}", namespace.to_string_lossy()),
+                directory: Some(relative.to_path_buf()),
+            });
+        } else {
+            find_custom_dimensions(world_directory, namespace_directory, &path, dimensions)?;
+        }
+    }
+    Ok(())
+}
+
+/// Least recently used cache of decoded region files.
+/// Missing region files are cached as `None`.
+struct RegionCache<C> {
+    capacity: usize,
+    regions: VecDeque<(PathBuf, Option<AnvilSave<C>>)>,
+}
+
+impl<C> RegionCache<C> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            regions: VecDeque::new(),
+        }
+    }
+
+    fn get_or_load(
+        &mut self,
+        path: &Path,
+        load: impl FnOnce() -> Result<AnvilSave<C>, WorldError>,
+    ) -> Result<Option<&AnvilSave<C>>, WorldError> {
+        let cached = self
+            .regions
+            .iter()
+            .position(|(cached_path, _)| cached_path == path)
+            .and_then(|index| self.regions.remove(index));
+        let region = match cached {
+            Some((_, region)) => region,
+            None if path.exists() => Some(load()?),
+            None => None,
+        };
+        self.regions.push_back((path.to_path_buf(), region));
+        if self.regions.len() > self.capacity {
+            self.regions.pop_front();
+        }
+        Ok(self.regions.back().and_then(|(_, region)| region.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        coordinates::BlockPos,
+        nbt::{List, Tag},
+        test_util::{region_file_bytes, TempDir},
+    };
+    use test_case::test_case;
+
+    fn chunk_nbt(x: i32, z: i32, block_entities: Vec<Tag>) -> HashMap<String, Tag> {
+        HashMap::from_iter(
+            [
+                ("DataVersion", Tag::Int(3465)),
+                ("xPos", Tag::Int(x)),
+                ("yPos", Tag::Int(-4)),
+                ("zPos", Tag::Int(z)),
+                ("Status", Tag::String("full".to_string())),
+                ("LastUpdate", Tag::Long(0)),
+                ("sections", Tag::List(List::from(vec![]))),
+                ("block_entities", Tag::List(List::from(block_entities))),
+            ]
+            .map(|(k, v)| (k.to_string(), v)),
+        )
+    }
+
+    fn chest(x: i32, y: i32, z: i32) -> Tag {
+        Tag::Compound(HashMap::from_iter(
+            [
+                ("id", Tag::String("minecraft:chest".to_string())),
+                ("x", Tag::Int(x)),
+                ("y", Tag::Int(y)),
+                ("z", Tag::Int(z)),
+                ("Items", Tag::List(List::from(vec![]))),
+            ]
+            .map(|(k, v)| (k.to_string(), v)),
+        ))
+    }
+
+    fn entity_chunk_nbt(x: i32, z: i32, entities: Vec<Tag>) -> HashMap<String, Tag> {
+        HashMap::from_iter(
+            [
+                ("DataVersion", Tag::Int(3465)),
+                ("Position", Tag::IntArray(vec![x, z].into())),
+                ("Entities", Tag::List(List::from(entities))),
+            ]
+            .map(|(k, v)| (k.to_string(), v)),
+        )
+    }
+
+    fn entity(id: &str, pos: [f64; 3]) -> Tag {
+        Tag::Compound(HashMap::from_iter([
+            ("id".to_string(), Tag::String(id.to_string())),
+            (
+                "Pos".to_string(),
+                Tag::List(List::from(pos.map(Tag::Double).to_vec())),
+            ),
+        ]))
+    }
+
+    fn test_world(name: &str) -> TempDir {
+        let dir = TempDir::new(name);
+        dir.write(
+            "region/r.0.0.mca",
+            &region_file_bytes(vec![
+                (0, chunk_nbt(0, 0, vec![chest(1, 64, 1), chest(10, 10, 10)])),
+                (33, chunk_nbt(1, 1, vec![chest(20, 70, 20)])),
+            ]),
+        );
+        dir.write(
+            "region/r.-1.0.mca",
+            &region_file_bytes(vec![(31, chunk_nbt(-1, 0, vec![chest(-1, 64, 5)]))]),
+        );
+        dir.write(
+            "entities/r.0.0.mca",
+            &region_file_bytes(vec![(
+                0,
+                entity_chunk_nbt(
+                    0,
+                    0,
+                    vec![
+                        entity("minecraft:cow", [1.5, 64., 1.5]),
+                        entity("minecraft:zombie", [8.5, -20., 8.5]),
+                    ],
+                ),
+            )]),
+        );
+        std::fs::create_dir_all(dir.path().join("DIM-1/region"))
+            .expect("Could not create directory");
+        std::fs::create_dir_all(dir.path().join("dimensions/custom/mining/deep/region"))
+            .expect("Could not create directory");
+        std::fs::create_dir_all(dir.path().join("dimensions/custom/empty"))
+            .expect("Could not create directory");
+        dir
+    }
+
+    #[test]
+    fn test_discover_dimensions() {
+        let dir = test_world("discover_dimensions");
+        let world = World::open(dir.path()).expect("World should open");
+        assert_eq!(
+            world.dimensions(),
+            &[
+                Dimension::overworld(),
+                Dimension::nether(),
+                Dimension {
+                    id: "custom:mining/deep".to_string(),
+                    directory: Some(PathBuf::from("dimensions/custom/mining/deep")),
+                }
+            ]
+        );
+    }
+
+    #[test_case(0, 0 => Some((0, 0)); "Chunk in region")]
+    #[test_case(1, 1 => Some((1, 1)); "Other chunk in region")]
+    #[test_case(-1, 0 => Some((-1, 0)); "Chunk in negative region")]
+    #[test_case(2, 2 => None; "Missing chunk")]
+    #[test_case(100, 100 => None; "Missing region")]
+    fn test_chunk(x: i32, z: i32) -> Option<(i32, i32)> {
+        let dir = test_world(&format!("chunk_{x}_{z}"));
+        let mut world = World::open(dir.path()).expect("World should open");
+        world
+            .chunk(Dimension::OVERWORLD, ChunkPos::new(x, z))
+            .expect("Query should succeed")
+            .map(|chunk| (chunk.x_pos, chunk.z_pos))
+    }
+
+    #[test]
+    fn test_unknown_dimension() {
+        let dir = test_world("unknown_dimension");
+        let mut world = World::open(dir.path()).expect("World should open");
+        assert!(matches!(
+            world.chunk(Dimension::END, ChunkPos::new(0, 0)),
+            Err(WorldError::UnknownDimension(_))
+        ));
+    }
+
+    #[test]
+    fn test_block_entities_in() {
+        let dir = test_world("block_entities_in");
+        let mut world = World::with_cache_size(dir.path(), 1).expect("World should open");
+        let mut positions = world
+            .block_entities_in(
+                Dimension::OVERWORLD,
+                &BoundingBox::from_corners(BlockPos::new(-16, 0, 0), BlockPos::new(20, 70, 20)),
+            )
+            .expect("Query should succeed")
+            .into_iter()
+            .map(|block_entity| (block_entity.x, block_entity.y, block_entity.z))
+            .collect::<Vec<_>>();
+        positions.sort();
+        assert_eq!(
+            positions,
+            vec![(-1, 64, 5), (1, 64, 1), (10, 10, 10), (20, 70, 20)]
+        );
+
+        let positions = world
+            .block_entities_in(
+                Dimension::OVERWORLD,
+                &BoundingBox::from_corners(BlockPos::new(0, 60, 0), BlockPos::new(15, 70, 15)),
+            )
+            .expect("Query should succeed")
+            .into_iter()
+            .map(|block_entity| (block_entity.x, block_entity.y, block_entity.z))
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![(1, 64, 1)]);
+    }
+
+    #[test]
+    fn test_entities_in() {
+        let dir = test_world("entities_in");
+        let mut world = World::open(dir.path()).expect("World should open");
+        let entities = world
+            .entities_in(
+                Dimension::OVERWORLD,
+                &BoundingBox::from_corners(BlockPos::new(0, 0, 0), BlockPos::new(15, 100, 15)),
+            )
+            .expect("Query should succeed");
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].id.as_deref(), Some("minecraft:cow"));
+        assert!(world
+            .entities_in(
+                Dimension::NETHER,
+                &BoundingBox::from_corners(BlockPos::new(0, 0, 0), BlockPos::new(15, 100, 15)),
+            )
+            .expect("Query should succeed")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_region_positions() {
+        let dir = test_world("region_positions");
+        let world = World::open(dir.path()).expect("World should open");
+        let positions = |dimension, kind, bounding_box: Option<BoundingBox>| {
+            world
+                .region_positions(dimension, kind, bounding_box.as_ref())
+                .expect("Query should succeed")
+        };
+        assert_eq!(
+            positions(Dimension::OVERWORLD, RegionKind::Chunks, None),
+            vec![RegionPos::new(-1, 0), RegionPos::new(0, 0)]
+        );
+        assert_eq!(
+            positions(
+                Dimension::OVERWORLD,
+                RegionKind::Chunks,
+                Some(BoundingBox::from_corners(
+                    BlockPos::new(0, 0, 0),
+                    BlockPos::new(600, 0, 600)
+                ))
+            ),
+            vec![RegionPos::new(0, 0)]
+        );
+        assert_eq!(
+            positions(Dimension::OVERWORLD, RegionKind::Entities, None),
+            vec![RegionPos::new(0, 0)]
+        );
+        assert_eq!(positions(Dimension::NETHER, RegionKind::Poi, None), vec![]);
+        assert!(matches!(
+            world.region_positions(Dimension::END, RegionKind::Chunks, None),
+            Err(WorldError::UnknownDimension(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_chunk_region() {
+        let dir = test_world("load_chunk_region");
+        let world = World::open(dir.path()).expect("World should open");
+        let chunks = |bounding_box: Option<BoundingBox>| {
+            world
+                .load_chunk_region(
+                    Dimension::OVERWORLD,
+                    RegionPos::new(0, 0),
+                    bounding_box.as_ref(),
+                )
+                .expect("Query should succeed")
+                .chunks
+                .iter()
+                .map(|chunk| (chunk.x_pos, chunk.z_pos))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(chunks(None), vec![(0, 0), (1, 1)]);
+        assert_eq!(
+            chunks(Some(BoundingBox::from_corners(
+                BlockPos::new(16, 0, 16),
+                BlockPos::new(20, 0, 20)
+            ))),
+            vec![(1, 1)]
+        );
+        assert!(matches!(
+            world.load_chunk_region(Dimension::OVERWORLD, RegionPos::new(5, 5), None),
+            Err(WorldError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_entity_region_tags() {
+        let dir = test_world("load_entity_region_tags");
+        let world = World::open(dir.path()).expect("World should open");
+        let region = world
+            .load_entity_region_tags(Dimension::OVERWORLD, RegionPos::new(0, 0))
+            .expect("Query should succeed");
+        assert_eq!(region.chunks.len(), 1);
+    }
+
+    #[test]
+    fn test_player_uuids() {
+        let dir = test_world("player_uuids");
+        dir.write("playerdata/b.dat", b"");
+        dir.write("playerdata/a.dat", b"");
+        dir.write("playerdata/a.dat_old", b"");
+        let world = World::open(dir.path()).expect("World should open");
+        assert_eq!(
+            world.player_uuids().expect("Query should succeed"),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        assert!(world.player("a").is_err());
+    }
+
+    #[test]
+    fn test_players_without_player_data() {
+        let dir = test_world("players");
+        let world = World::open(dir.path()).expect("World should open");
+        assert!(world.players().expect("Query should succeed").is_empty());
+    }
+
+    #[test]
+    fn test_region_cache() {
+        let dir = TempDir::new("region_cache");
+        dir.write("a", b"");
+        dir.write("b", b"");
+        let (a, b) = (dir.path().join("a"), dir.path().join("b"));
+        let mut cache = RegionCache::<ChunkData>::new(1);
+        let empty = || Ok(AnvilSave::new([0; 8192].into(), vec![]));
+        assert!(cache
+            .get_or_load(&a, empty)
+            .expect("Query should succeed")
+            .is_some());
+        assert!(cache
+            .get_or_load(&a, || panic!("Region should be cached"))
+            .expect("Query should succeed")
+            .is_some());
+        assert!(cache
+            .get_or_load(&b, empty)
+            .expect("Query should succeed")
+            .is_some());
+        assert_eq!(cache.regions.len(), 1);
+        assert!(cache
+            .get_or_load(&dir.path().join("missing"), || panic!(
+                "File does not exist"
+            ))
+            .expect("Query should succeed")
+            .is_none());
+    }
+}
@@ -0,0 +1,116 @@
+//! Horizontal areas of blocks given on the command line.
+
+use mc_map_reader::{BlockPos, BoundingBox, ChunkPos};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Area {
+    /// X value of first point
+    pub x1: i32,
+    /// Z value of first point
+    pub z1: i32,
+    /// X value of second point
+    pub x2: i32,
+    /// Z value of second point
+    pub z2: i32,
+}
+
+impl Area {
+    /// The chunks containing the corners of the area.
+    pub fn chunk_corners(&self) -> (ChunkPos, ChunkPos) {
+        (
+            BlockPos::new(self.x1, 0, self.z1).chunk(),
+            BlockPos::new(self.x2, 0, self.z2).chunk(),
+        )
+    }
+
+    /// The area as a bounding box covering the full height of the world.
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_corners(
+            BlockPos::new(self.x1, i32::MIN, self.z1),
+            BlockPos::new(self.x2, i32::MAX, self.z2),
+        )
+    }
+
+    /// Returns true if the block is inside of the area. The height of the block is ignored.
+    pub fn contains(&self, pos: BlockPos) -> bool {
+        (self.x1.min(self.x2)..=self.x1.max(self.x2)).contains(&pos.x)
+            && (self.z1.min(self.z2)..=self.z1.max(self.z2)).contains(&pos.z)
+    }
+}
+
+pub fn parse_area(value: &str) -> Result<Area, String> {
+    let Some(((x1, z1), (x2, z2))) = value
+        .split_once(';')
+        .and_then(|(pos1, pos2)| parse_point(pos1).zip(parse_point(pos2)))
+    else {
+        return Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers."));
+    };
+    Ok(Area { x1, z1, x2, z2 })
+}
+
+fn parse_point(value: &str) -> Option<(i32, i32)> {
+    value
+        .split_once(',')
+        .and_then(|(x, z)| x.parse().ok().zip(z.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1,2" => Some((1, 2)); "Success")]
+    #[test_case("1,2,3" => None; "Too many values")]
+    #[test_case("1" => None; "Too few values")]
+    #[test_case("a,2" => None; "First value is not a number")]
+    #[test_case("1,b" => None; "Second value is not a number")]
+    #[test_case("1," => None; "Second value is missing")]
+    #[test_case(",2" => None; "First value is missing")]
+    #[test_case("-1,2" => Some((-1, 2)); "Negative values")]
+    fn test_parse_point(v: &str) -> Option<(i32, i32)> {
+        parse_point(v)
+    }
+
+    #[test_case("1,2;3,4" => Ok(Area { x1: 1, z1: 2, x2: 3, z2: 4 }); "Success")]
+    #[test_case("1,2;3,4,5" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "Too many values")]
+    #[test_case("1,2" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "Too few values")]
+    #[test_case("a,2;3,4" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "First value of first point is not a number")]
+    fn test_parse_area(v: &str) -> Result<Area, String> {
+        parse_area(v)
+    }
+
+    const AREA: Area = Area {
+        x1: 10,
+        z1: -10,
+        x2: -10,
+        z2: 10,
+    };
+
+    #[test_case(0, 0 => true; "Center")]
+    #[test_case(10, 10 => true; "Corner")]
+    #[test_case(-10, -10 => true; "Opposite corner")]
+    #[test_case(11, 0 => false; "Outside x")]
+    #[test_case(0, -11 => false; "Outside z")]
+    fn test_area_contains(x: i32, z: i32) -> bool {
+        AREA.contains(BlockPos::new(x, 100, z))
+    }
+
+    #[test]
+    fn test_area_chunk_corners() {
+        assert_eq!(
+            AREA.chunk_corners(),
+            (ChunkPos::new(0, -1), ChunkPos::new(-1, 0))
+        );
+    }
+
+    #[test_case(0, 0, true; "Center")]
+    #[test_case(10, -10, true; "Corner")]
+    #[test_case(11, 0, false; "Outside x")]
+    #[test_case(0, 11, false; "Outside z")]
+    fn test_area_bounding_box(x: i32, z: i32, expected: bool) {
+        let bounding_box = AREA.bounding_box();
+        for y in [i32::MIN, -64, 320, i32::MAX] {
+            assert_eq!(bounding_box.contains(BlockPos::new(x, y, z)), expected);
+        }
+    }
+}
@@ -24,6 +24,30 @@ pub enum Action {
     SearchDupeStashes(SearchDupeStashes),
     /// Find inventories of a specific type
     FindInventories(crate::find_inventories::config::SearchEntity),
+    /// Find points of interest like nether portals, beds or job site blocks
+    FindPois(crate::find_pois::args::FindPois),
+    /// Find generated structures like villages, strongholds or ancient cities
+    FindStructures(crate::find_structures::args::FindStructures),
+    /// Rank chunks by their number of scheduled block and fluid ticks
+    TickReport(crate::tick_report::args::TickReport),
+    /// Count blocks by block id for every dimension
+    BlockStats(crate::block_stats::args::BlockStats),
+    /// Find blocks by id and block state
+    FindBlocks(crate::find_blocks::args::FindBlocks),
+    /// Render a top-down map of a dimension to PNG images
+    Render(crate::render::args::Render),
+    /// Report how many chunks inside of the world border are fully generated
+    Coverage(crate::coverage::args::Coverage),
+    /// Search signs, books, item names, entity names and command blocks with a regular expression
+    Grep(crate::grep::args::Grep),
+    /// List command blocks and command block minecarts and flag risky commands
+    AuditCommandBlocks(crate::audit_command_blocks::args::AuditCommandBlocks),
+    /// List spawners with their mobs and flag spawners which were not generated with the world
+    SpawnerReport(crate::spawner_report::args::SpawnerReport),
+    /// Find items no survival player can have, like oversized stacks or unobtainable blocks
+    FindIllegalItems(crate::find_illegal_items::args::FindIllegalItems),
+    /// Find items which should be unique, like renamed gear or original books, in more than one container
+    FindDuplicateItems(crate::find_duplicate_items::args::FindDuplicateItems),
     #[cfg(feature = "experimental")]
     ReadLevelDat,
 }
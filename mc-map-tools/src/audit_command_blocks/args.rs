@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct AuditCommandBlocks {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// JSON file with the rules used to flag risky commands. Uses the built-in rules by default
+    #[arg(short, long)]
+    pub rules: Option<PathBuf>,
+    /// Only list command blocks with at least one flagged command
+    #[arg(short, long)]
+    pub flagged_only: bool,
+}
@@ -0,0 +1,329 @@
+use std::{fs::File, io::Write, path::Path};
+
+use mc_map_reader::{
+    data::{
+        block_entity::BlockEntityType,
+        chunk::ChunkData,
+        entity::{CommandBlockMinecart, COMMAND_BLOCK_MINECART},
+    },
+    files::RegionKind,
+    nbt::Tag,
+    BlockPos, World,
+};
+
+use self::{args::AuditCommandBlocks, rules::CommandRules};
+
+pub mod args;
+mod rules;
+
+const REPEATING_COMMAND_BLOCK: &str = "minecraft:repeating_command_block";
+
+/// A command block or minecart with a command block found in the world.
+#[derive(Debug, Clone, PartialEq)]
+struct FoundCommandBlock {
+    /// The block or entity id e.g. `minecraft:chain_command_block`.
+    id: String,
+    position: BlockPos,
+    command: String,
+    /// Whether the block runs without redstone. `None` for minecarts.
+    auto: Option<bool>,
+    /// Whether the block is powered. `None` for minecarts.
+    powered: Option<bool>,
+    success_count: i32,
+}
+
+impl FoundCommandBlock {
+    /// Repeating command blocks and minecarts on activator rails run their command over and over.
+    fn is_repeating(&self) -> bool {
+        self.id == REPEATING_COMMAND_BLOCK || self.id == COMMAND_BLOCK_MINECART
+    }
+
+    fn state(&self) -> &'static str {
+        match (self.auto, self.powered) {
+            (Some(true), _) => "always active",
+            (_, Some(true)) => "powered",
+            (Some(false), _) => "needs redstone",
+            _ => "activator rail",
+        }
+    }
+}
+
+pub fn main(world_dir: &Path, args: &AuditCommandBlocks, writer: &mut dyn Write) {
+    let rules = match &args.rules {
+        Some(path) => CommandRules::new(File::open(path).expect("Could not open rules file"))
+            .expect("Invalid rules file"),
+        None => CommandRules::default(),
+    };
+    let world = World::open(world_dir).expect("Could not open world");
+
+    let mut command_blocks = find_command_blocks(&world, args);
+    command_blocks.extend(find_command_block_minecarts(&world, args));
+    log::debug!("Found {} command blocks", command_blocks.len());
+
+    write_report(&command_blocks, &rules, args.flagged_only, writer)
+        .expect("Could not write result");
+}
+
+fn find_command_blocks(world: &World, args: &AuditCommandBlocks) -> Vec<FoundCommandBlock> {
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, bounding_box.as_ref())
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+    regions
+        .into_iter()
+        .flat_map(|region| {
+            let chunks = world
+                .load_chunk_region(dimension, region, bounding_box.as_ref())
+                .expect("Error reading file")
+                .chunks;
+            chunks
+                .iter()
+                .flat_map(|chunk| chunk_command_blocks(chunk, args))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn chunk_command_blocks(chunk: &ChunkData, args: &AuditCommandBlocks) -> Vec<FoundCommandBlock> {
+    chunk
+        .block_entities
+        .iter()
+        .flat_map(|block_entities| block_entities.iter())
+        .filter(|block_entity| in_area(args, block_entity.position()))
+        .filter_map(|block_entity| {
+            let BlockEntityType::CommandBlock(command_block) = &block_entity.entity_type else {
+                return None;
+            };
+            let position = block_entity.position();
+            // The block entity id is the same for impulse, chain and repeating command blocks
+            let id = chunk
+                .block_at(position)
+                .map(|block| block.name.clone())
+                .unwrap_or_else(|| block_entity.id.clone());
+            Some(FoundCommandBlock {
+                id,
+                position,
+                command: command_block.command.clone(),
+                auto: Some(command_block.auto),
+                powered: Some(command_block.powered),
+                success_count: command_block.success_count,
+            })
+        })
+        .collect()
+}
+
+fn find_command_block_minecarts(
+    world: &World,
+    args: &AuditCommandBlocks,
+) -> Vec<FoundCommandBlock> {
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let regions = world
+        .region_positions(dimension, RegionKind::Entities, bounding_box.as_ref())
+        .unwrap_or_else(|e| {
+            log::warn!("Could not read entities directory: {e}");
+            Vec::new()
+        });
+    log::debug!("Found {} entity region files", regions.len());
+    regions
+        .into_iter()
+        .flat_map(|region| {
+            world
+                .load_entity_region_tags(dimension, region)
+                .expect("Error reading file")
+                .chunks
+        })
+        .flat_map(chunk_minecarts)
+        .filter(|minecart| in_area(args, minecart.position))
+        .collect()
+}
+
+/// Command block minecarts in the entity data of a chunk. Minecarts without a position are skipped.
+fn chunk_minecarts(chunk: Tag) -> Vec<FoundCommandBlock> {
+    let Ok(mut chunk) = chunk.get_as_map() else {
+        return Vec::new();
+    };
+    let Some(Tag::List(entities)) = chunk.remove("Entities") else {
+        return Vec::new();
+    };
+    entities
+        .take()
+        .into_iter()
+        .filter(|entity| match entity {
+            Tag::Compound(entity) => {
+                matches!(entity.get("id"), Some(Tag::String(id)) if id == COMMAND_BLOCK_MINECART)
+            }
+            _ => false,
+        })
+        .filter_map(|entity| match CommandBlockMinecart::try_from(entity) {
+            Ok(minecart) => Some(minecart),
+            Err(e) => {
+                log::warn!("Could not parse command block minecart: {e:?}");
+                None
+            }
+        })
+        .filter_map(|minecart| {
+            Some(FoundCommandBlock {
+                id: COMMAND_BLOCK_MINECART.to_string(),
+                position: minecart.entity.block_pos()?,
+                command: minecart.command,
+                auto: None,
+                powered: None,
+                success_count: minecart.success_count,
+            })
+        })
+        .collect()
+}
+
+fn in_area(args: &AuditCommandBlocks, position: BlockPos) -> bool {
+    match &args.area {
+        Some(area) => area.contains(position),
+        None => true,
+    }
+}
+
+fn write_report(
+    command_blocks: &[FoundCommandBlock],
+    rules: &CommandRules,
+    flagged_only: bool,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let mut flagged_count = 0;
+    for command_block in command_blocks {
+        let flagged = rules
+            .flagged(
+                &command_block.command,
+                command_block.is_repeating(),
+                command_block.position,
+            )
+            .collect::<Vec<_>>();
+        if !flagged.is_empty() {
+            flagged_count += 1;
+        } else if flagged_only {
+            continue;
+        }
+        let position = command_block.position;
+        writeln!(
+            writer,
+            "{} at x:{} y:{} z:{} ({}, success count {}): {:?}",
+            command_block.id,
+            position.x,
+            position.y,
+            position.z,
+            command_block.state(),
+            command_block.success_count,
+            command_block.command
+        )?;
+        for rule in flagged {
+            writeln!(writer, "  {}: {}", rule.name, rule.description)?;
+        }
+    }
+    writeln!(
+        writer,
+        "Found {} command blocks, {} flagged",
+        command_blocks.len(),
+        flagged_count
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::nbt::List;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn command_block(id: &str, command: &str, auto: Option<bool>) -> FoundCommandBlock {
+        FoundCommandBlock {
+            id: id.to_string(),
+            position: BlockPos::new(1, 64, -3),
+            command: command.to_string(),
+            auto,
+            powered: auto.map(|_| false),
+            success_count: 2,
+        }
+    }
+
+    fn report(flagged_only: bool) -> String {
+        let command_blocks = [
+            command_block("minecraft:command_block", "say hi", Some(false)),
+            command_block(
+                REPEATING_COMMAND_BLOCK,
+                "summon minecraft:zombie",
+                Some(true),
+            ),
+            command_block(COMMAND_BLOCK_MINECART, "op Steve", None),
+        ];
+        let mut out = Vec::new();
+        write_report(
+            &command_blocks,
+            &CommandRules::default(),
+            flagged_only,
+            &mut out,
+        )
+        .expect("Error writing report");
+        String::from_utf8(out).expect("Invalid utf-8")
+    }
+
+    #[test_case(false => "minecraft:command_block at x:1 y:64 z:-3 (needs redstone, success count 2): \"say hi\"
+minecraft:repeating_command_block at x:1 y:64 z:-3 (always active, success count 2): \"summon minecraft:zombie\"
+  summon-loop: Summons entities every tick
+minecraft:command_block_minecart at x:1 y:64 z:-3 (activator rail, success count 2): \"op Steve\"
+  op: Changes the operator status of players
+Found 3 command blocks, 2 flagged
+"; "All")]
+    #[test_case(true => "minecraft:repeating_command_block at x:1 y:64 z:-3 (always active, success count 2): \"summon minecraft:zombie\"
+  summon-loop: Summons entities every tick
+minecraft:command_block_minecart at x:1 y:64 z:-3 (activator rail, success count 2): \"op Steve\"
+  op: Changes the operator status of players
+Found 3 command blocks, 2 flagged
+"; "Flagged only")]
+    fn test_write_report(flagged_only: bool) -> String {
+        report(flagged_only)
+    }
+
+    fn string(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn entity(id: &str, command: &str) -> Tag {
+        Tag::Compound(HashMap::from_iter([
+            ("id".to_string(), string(id)),
+            ("Command".to_string(), string(command)),
+            (
+                "Pos".to_string(),
+                Tag::List(List::from(vec![
+                    Tag::Double(1.5),
+                    Tag::Double(64.0),
+                    Tag::Double(-2.5),
+                ])),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_chunk_minecarts() {
+        let chunk = Tag::Compound(HashMap::from_iter([(
+            "Entities".to_string(),
+            Tag::List(List::from(vec![
+                entity(COMMAND_BLOCK_MINECART, "give @p minecraft:diamond"),
+                entity("minecraft:minecart", ""),
+            ])),
+        )]));
+        assert_eq!(
+            chunk_minecarts(chunk),
+            vec![FoundCommandBlock {
+                id: COMMAND_BLOCK_MINECART.to_string(),
+                position: BlockPos::new(1, 64, -3),
+                command: "give @p minecraft:diamond".to_string(),
+                auto: None,
+                powered: None,
+                success_count: 0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::Read;
+
+use mc_map_reader::BlockPos;
+use regex::Regex;
+use serde::Deserialize;
+
+/// Commands which change blocks in the volume given by their first six coordinates.
+const VOLUME_COMMANDS: [&str; 2] = ["fill", "clone"];
+
+/// Coordinates of commands are clamped to the extent of the world.
+const WORLD_LIMIT: f64 = 30_000_000.0;
+
+/// Rules used to flag risky commands.
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct CommandRules {
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct Rule {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub pattern: Pattern,
+    /// Only flag commands of repeating command blocks.
+    #[serde(default)]
+    pub repeating_only: bool,
+    /// Only flag `fill` and `clone` commands changing at least this many blocks.
+    pub min_volume: Option<u64>,
+}
+
+/// A regular expression which can be read from a rules file.
+#[derive(Debug)]
+pub struct Pattern(Regex);
+
+impl CommandRules {
+    pub fn new<R>(reader: R) -> Result<Self, serde_json::Error>
+    where
+        R: Read,
+    {
+        serde_json::from_reader(reader)
+    }
+
+    /// All rules flagging the command of a command block at the position.
+    pub fn flagged<'a>(
+        &'a self,
+        command: &'a str,
+        repeating: bool,
+        position: BlockPos,
+    ) -> impl Iterator<Item = &'a Rule> {
+        self.rules
+            .iter()
+            .filter(move |rule| rule.matches(command, repeating, position))
+    }
+}
+
+impl Default for CommandRules {
+    fn default() -> Self {
+        serde_json::from_str(include_str!("../../default-command-block-rules.json"))
+            .expect("Invalid default rules")
+    }
+}
+
+impl Rule {
+    fn matches(&self, command: &str, repeating: bool, position: BlockPos) -> bool {
+        if self.repeating_only && !repeating {
+            return false;
+        }
+        if !self.pattern.0.is_match(command) {
+            return false;
+        }
+        match self.min_volume {
+            Some(min_volume) => {
+                command_volume(command, position).is_some_and(|volume| volume >= min_volume)
+            }
+            None => true,
+        }
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Regex::new(&value)
+            .map(Pattern)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Number of blocks changed by a `fill` or `clone` command.
+/// Relative coordinates are resolved from the position of the command block.
+/// Coordinates are clamped to the extent of the world and the volume saturates at `u64::MAX`.
+/// Returns `None` for other commands and for local coordinates.
+fn command_volume(command: &str, position: BlockPos) -> Option<u64> {
+    let tokens = command.split_whitespace().collect::<Vec<_>>();
+    let start = tokens.iter().enumerate().position(|(index, token)| {
+        let name = token.trim_start_matches('/');
+        let name = name.strip_prefix("minecraft:").unwrap_or(name);
+        VOLUME_COMMANDS.contains(&name) && (index == 0 || tokens[index - 1] == "run")
+    })?;
+    let origin = [position.x, position.y, position.z];
+    let coordinates = tokens
+        .get(start + 1..start + 7)?
+        .iter()
+        .enumerate()
+        .map(|(index, token)| coordinate(token, origin[index % 3]))
+        .collect::<Option<Vec<_>>>()?;
+    let volume = (0..3)
+        .map(|axis| coordinates[axis].abs_diff(coordinates[axis + 3]) + 1)
+        .fold(1u64, u64::saturating_mul);
+    Some(volume)
+}
+
+/// Parse an absolute or relative coordinate, clamped to the extent of the world.
+fn coordinate(token: &str, origin: i32) -> Option<i64> {
+    let value = match token.strip_prefix('~') {
+        Some("") => origin as f64,
+        Some(offset) => origin as f64 + offset.parse::<f64>().ok()?,
+        None => token.parse::<f64>().ok()?,
+    };
+    Some(value.floor().clamp(-WORLD_LIMIT, WORLD_LIMIT) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn flagged(command: &str, repeating: bool) -> Vec<String> {
+        CommandRules::default()
+            .flagged(command, repeating, BlockPos::new(0, 64, 0))
+            .map(|rule| rule.name.clone())
+            .collect()
+    }
+
+    #[test_case("op Steve", false => vec!["op"]; "Op")]
+    #[test_case("/minecraft:deop Steve", false => vec!["op"]; "Deop with namespace")]
+    #[test_case("say op", false => Vec::<String>::new(); "Op in text")]
+    #[test_case("give @p minecraft:diamond 64", false => vec!["give"]; "Give")]
+    #[test_case("execute as @a at @s run give @s minecraft:diamond", false => vec!["give", "execute-as-all"]; "Execute as all players")]
+    #[test_case("fill ~-100 ~-10 ~-100 ~100 ~10 ~100 minecraft:air", false => vec!["large-fill"]; "Large fill")]
+    #[test_case("fill 0 0 0 10 10 10 minecraft:air", false => Vec::<String>::new(); "Small fill")]
+    #[test_case("execute positioned 0 0 0 run clone 0 0 0 100 100 100 200 0 0", false => vec!["large-fill"]; "Large clone")]
+    #[test_case("summon minecraft:zombie", false => Vec::<String>::new(); "Summon once")]
+    #[test_case("summon minecraft:zombie", true => vec!["summon-loop"]; "Summon loop")]
+    #[test_case("kill @e[type=minecraft:item]", false => vec!["kill-all"]; "Kill all")]
+    #[test_case("kill @p", false => Vec::<String>::new(); "Kill player")]
+    #[test_case("whitelist add Steve", false => vec!["player-management"]; "Whitelist")]
+    fn test_default_rules(command: &str, repeating: bool) -> Vec<String> {
+        flagged(command, repeating)
+    }
+
+    #[test_case("fill 0 0 0 9 9 9 minecraft:stone" => Some(1000); "Absolute")]
+    #[test_case("fill ~ ~ ~ ~-9 ~9 ~ minecraft:stone" => Some(100); "Relative")]
+    #[test_case("fill 0 64 0 ~ ~ ~ minecraft:stone" => Some(11 * 11); "Mixed")]
+    #[test_case("execute if block 0 0 0 minecraft:stone run fill 0 0 0 1 1 1 minecraft:air" => Some(8); "Execute")]
+    #[test_case("fill ~ ~ ~ ~ ~ ~1e300 minecraft:stone" => Some(30_000_001 - 10); "Relative huge")]
+    #[test_case("fill ~-1e300 0 0 ~1e300 0 0 minecraft:stone" => Some(60_000_001); "Relative huge both sides")]
+    #[test_case("fill 0 0 0 9e18 0 0 minecraft:stone" => Some(30_000_001); "Huge")]
+    #[test_case("fill 0 0 0 9e18 9e18 9e18 minecraft:stone" => Some(u64::MAX); "Huge volume")]
+    #[test_case("fill ^ ^ ^ ^1 ^1 ^1 minecraft:stone" => None; "Local")]
+    #[test_case("fill 0 0 0" => None; "Incomplete")]
+    #[test_case("say fill 0 0 0 1 1 1" => None; "Other command")]
+    fn test_command_volume(command: &str) -> Option<u64> {
+        command_volume(command, BlockPos::new(10, 64, 10))
+    }
+
+    #[test]
+    fn test_rules_file() {
+        let rules = CommandRules::new(
+            r#"{"rules": [{"name": "tp", "pattern": "^tp\\b", "repeating_only": true}]}"#
+                .as_bytes(),
+        )
+        .expect("Invalid rules");
+        assert_eq!(rules.rules.len(), 1);
+        assert_eq!(rules.rules[0].description, "");
+        assert_eq!(rules.rules[0].min_volume, None);
+        assert!(rules.rules[0].matches("tp @a 0 0 0", true, BlockPos::new(0, 0, 0)));
+        assert!(!rules.rules[0].matches("tp @a 0 0 0", false, BlockPos::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn test_invalid_rules_file() {
+        assert!(
+            CommandRules::new(r#"{"rules": [{"name": "x", "pattern": "("}]}"#.as_bytes()).is_err()
+        );
+    }
+}
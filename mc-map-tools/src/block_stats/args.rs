@@ -0,0 +1,22 @@
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct BlockStats {
+    /// Dimensions to count blocks in. Defaults to all dimensions of the world
+    #[arg(short, long, value_parser = parse_dimension)]
+    pub dimension: Vec<Dimension>,
+    /// Block ids to include. Wildcards are supported e.g. "minecraft:*_ore"
+    #[arg(short, long = "block", default_value = "*")]
+    pub blocks: Vec<String>,
+    /// Count blocks per Y level
+    #[arg(short = 'y', long)]
+    pub by_y: bool,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+}
@@ -0,0 +1,237 @@
+use std::{collections::HashMap, io::Write, path::Path};
+
+use mc_map_reader::{
+    data::chunk::{ChunkData, Section, BLOCKS_PER_SECTION, SECTION_SIZE},
+    files::RegionKind,
+    BlockPos, BoundingBox, SectionPos, World,
+};
+use wildmatch::WildMatch;
+
+use crate::find_inventories::{compile_wildcards, config::Dimension};
+
+use self::args::BlockStats;
+
+pub mod args;
+
+/// Number of blocks by block id and, if counted per level, by Y level.
+type BlockCounts = HashMap<(String, Option<i32>), u64>;
+
+pub fn main(world_dir: &Path, args: &BlockStats, writer: &mut dyn Write) {
+    let wildcards = compile_wildcards(args.blocks.as_slice());
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimensions = if args.dimension.is_empty() {
+        world.dimensions().iter().map(Dimension::from).collect()
+    } else {
+        args.dimension.clone()
+    };
+
+    for dimension in dimensions {
+        let regions =
+            match world.region_positions(dimension.id(), RegionKind::Chunks, bounding_box.as_ref())
+            {
+                Ok(regions) => regions,
+                Err(err) => {
+                    log::info!("Skipping {dimension}: {err}");
+                    continue;
+                }
+            };
+        log::debug!("Found {} region files in {dimension}", regions.len());
+
+        let mut counts = BlockCounts::new();
+        regions.into_iter().for_each(|region| {
+            let chunks = world
+                .load_chunk_region(dimension.id(), region, bounding_box.as_ref())
+                .expect("Error reading file")
+                .chunks;
+            chunks.iter().for_each(|chunk| {
+                count_chunk(chunk, bounding_box.as_ref(), args.by_y, &mut counts)
+            });
+        });
+        write_counts(&dimension, counts, &wildcards, writer).expect("Could not write result");
+    }
+}
+
+fn count_chunk(
+    chunk: &ChunkData,
+    bounding_box: Option<&BoundingBox>,
+    by_y: bool,
+    counts: &mut BlockCounts,
+) {
+    let mut chunk_counts = HashMap::<(&str, Option<i32>), u64>::new();
+    for section in chunk.sections.iter() {
+        // Sections without any blocks do not need to be decoded
+        if section.block_states.is_air() {
+            continue;
+        }
+        count_section(chunk, section, bounding_box, by_y, &mut chunk_counts);
+    }
+    for ((id, y), count) in chunk_counts {
+        *counts.entry((id.to_string(), y)).or_default() += count;
+    }
+}
+
+fn count_section<'a>(
+    chunk: &ChunkData,
+    section: &'a Section,
+    bounding_box: Option<&BoundingBox>,
+    by_y: bool,
+    counts: &mut HashMap<(&'a str, Option<i32>), u64>,
+) {
+    let min = SectionPos::new(chunk.x_pos, section.y as i32, chunk.z_pos).min_block();
+    let size = SECTION_SIZE as i32;
+    let fully_inside = match bounding_box {
+        Some(bounding_box) => {
+            let max = BlockPos::new(min.x + size - 1, min.y + size - 1, min.z + size - 1);
+            bounding_box.contains(min) && bounding_box.contains(max)
+        }
+        None => true,
+    };
+
+    let palette = &section.block_states.palette;
+    if fully_inside && palette.len() == 1 {
+        let block = &palette[0];
+        if by_y {
+            for y in min.y..min.y + size {
+                *counts.entry((&block.name, Some(y))).or_default() +=
+                    (BLOCKS_PER_SECTION / SECTION_SIZE) as u64;
+            }
+        } else {
+            *counts.entry((&block.name, None)).or_default() += BLOCKS_PER_SECTION as u64;
+        }
+        return;
+    }
+
+    for ((x, y, z), block) in section.block_states.iter() {
+        if block.is_air() {
+            continue;
+        }
+        let position = BlockPos::new(min.x + x as i32, min.y + y as i32, min.z + z as i32);
+        if !fully_inside
+            && bounding_box.is_some_and(|bounding_box| !bounding_box.contains(position))
+        {
+            continue;
+        }
+        let y = by_y.then_some(position.y);
+        *counts.entry((&block.name, y)).or_default() += 1;
+    }
+}
+
+fn write_counts(
+    dimension: &Dimension,
+    counts: BlockCounts,
+    wildcards: &[WildMatch],
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let mut counts = counts
+        .into_iter()
+        .filter(|((id, _), _)| wildcards.iter().any(|w| w.matches(id)))
+        .collect::<Vec<_>>();
+    // Blocks with the highest total come first, levels of the same block are sorted from bottom to top
+    let totals = counts.iter().fold(
+        HashMap::<String, u64>::new(),
+        |mut totals, ((id, _), count)| {
+            *totals.entry(id.clone()).or_default() += count;
+            totals
+        },
+    );
+    counts.sort_by(|((a_id, a_y), _), ((b_id, b_y), _)| {
+        totals[b_id]
+            .cmp(&totals[a_id])
+            .then(a_id.cmp(b_id))
+            .then(a_y.cmp(b_y))
+    });
+    counts.iter().try_for_each(|((id, y), count)| match y {
+        Some(y) => writeln!(writer, "Found {count} {id} at y:{y} in {dimension}"),
+        None => writeln!(writer, "Found {count} {id} in {dimension}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::test_util::{block_states, chunk_with_sections, floor, section};
+
+    fn test_chunk() -> ChunkData {
+        chunk_with_sections(
+            -1,
+            0,
+            vec![
+                section(-4, floor(&["minecraft:stone", "minecraft:diamond_ore"])),
+                section(-3, block_states(&["minecraft:deepslate"], None)),
+                section(
+                    -2,
+                    block_states(&["minecraft:air", "minecraft:cave_air"], Some(vec![0; 256])),
+                ),
+            ],
+        )
+    }
+
+    fn count(bounding_box: Option<BoundingBox>, by_y: bool) -> Vec<((String, Option<i32>), u64)> {
+        let mut counts = BlockCounts::new();
+        count_chunk(&test_chunk(), bounding_box.as_ref(), by_y, &mut counts);
+        let mut counts = counts.into_iter().collect::<Vec<_>>();
+        counts.sort();
+        counts
+    }
+
+    #[test]
+    fn test_count_chunk() {
+        assert_eq!(
+            count(None, false),
+            vec![
+                (("minecraft:deepslate".to_string(), None), 4096),
+                (("minecraft:diamond_ore".to_string(), None), 256),
+                (("minecraft:stone".to_string(), None), 3840),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_chunk_by_y() {
+        let counts = count(None, true);
+        assert_eq!(counts.len(), 1 + 15 + 16);
+        assert!(counts.contains(&(("minecraft:diamond_ore".to_string(), Some(-64)), 256)));
+        assert!(counts.contains(&(("minecraft:stone".to_string(), Some(-63)), 256)));
+        assert!(counts.contains(&(("minecraft:deepslate".to_string(), Some(-33)), 256)));
+    }
+
+    #[test_case(BlockPos::new(-16, -64, 0), BlockPos::new(-15, -40, 1) => vec![
+        (("minecraft:deepslate".to_string(), None), 4 * 9),
+        (("minecraft:diamond_ore".to_string(), None), 4),
+        (("minecraft:stone".to_string(), None), 4 * 15),
+    ]; "Part of the chunk")]
+    #[test_case(BlockPos::new(0, -64, 0), BlockPos::new(15, 0, 15) => Vec::<((String, Option<i32>), u64)>::new(); "Outside of the chunk")]
+    #[test_case(BlockPos::new(-16, -48, 0), BlockPos::new(-1, -33, 15) => vec![
+        (("minecraft:deepslate".to_string(), None), 4096),
+    ]; "Whole section")]
+    fn test_count_chunk_in_area(min: BlockPos, max: BlockPos) -> Vec<((String, Option<i32>), u64)> {
+        count(Some(BoundingBox::from_corners(min, max)), false)
+    }
+
+    #[test]
+    fn test_write_counts() {
+        let counts = BlockCounts::from_iter([
+            (("minecraft:stone".to_string(), Some(1)), 10),
+            (("minecraft:stone".to_string(), Some(0)), 5),
+            (("minecraft:diamond_ore".to_string(), Some(0)), 20),
+            (("minecraft:dirt".to_string(), Some(3)), 1),
+        ]);
+        let mut out = Vec::new();
+        write_counts(
+            &Dimension::Overworld,
+            counts,
+            &compile_wildcards(&["minecraft:*o*e".to_string()]),
+            &mut out,
+        )
+        .expect("Error writing result");
+        assert_eq!(
+            String::from_utf8(out).expect("Invalid utf-8"),
+            "Found 20 minecraft:diamond_ore at y:0 in overworld\n\
+             Found 5 minecraft:stone at y:0 in overworld\n\
+             Found 10 minecraft:stone at y:1 in overworld\n"
+        );
+    }
+}
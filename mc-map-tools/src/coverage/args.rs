@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct Coverage {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks. Only the part inside of the world border is checked
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// Write a PNG image with one pixel per chunk showing which chunks are generated to this file
+    #[arg(short, long)]
+    pub mask: Option<PathBuf>,
+    /// List every chunk that is missing or not fully generated
+    #[arg(short, long)]
+    pub list_chunks: bool,
+}
@@ -0,0 +1,421 @@
+use std::{io::Write, path::Path};
+
+use mc_map_reader::{
+    coordinates::CHUNKS_PER_REGION,
+    data::{
+        chunk::{ChunkData, ChunkStatus},
+        world_border::WorldBorder,
+    },
+    files::RegionKind,
+    BoundingBox, ChunkPos, RegionPos, World,
+};
+
+use crate::{
+    find_inventories::config::Dimension,
+    render::{
+        self,
+        image::{Image, Pixel},
+    },
+};
+
+use self::args::Coverage;
+
+pub mod args;
+
+/// Borders containing more regions are only checked where region files exist.
+const MAX_CHECKED_REGIONS: usize = 128 * 128;
+/// The nether uses one block for every eight blocks of the overworld.
+const NETHER_COORDINATE_SCALE: f64 = 8.;
+
+const FULL_COLOR: Pixel = [0, 200, 0, 255];
+const PARTIAL_COLOR: Pixel = [255, 200, 0, 255];
+const MISSING_COLOR: Pixel = [200, 0, 0, 255];
+
+/// How far a chunk inside of the border is generated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Generation {
+    Full,
+    Partial(ChunkStatus),
+    Missing,
+}
+
+/// The generation of every chunk of a region indexed like the region header.
+/// Chunks outside of the checked area are `None`.
+type RegionCoverage = Vec<Option<Generation>>;
+
+/// A rectangle of chunks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ChunkArea {
+    min: ChunkPos,
+    max: ChunkPos,
+}
+
+impl ChunkArea {
+    /// Create the area spanned by both corners. The corners can be given in any order.
+    fn new(a: ChunkPos, b: ChunkPos) -> Self {
+        Self {
+            min: ChunkPos::new(a.x.min(b.x), a.z.min(b.z)),
+            max: ChunkPos::new(a.x.max(b.x), a.z.max(b.z)),
+        }
+    }
+
+    fn contains(&self, chunk: ChunkPos) -> bool {
+        (self.min.x..=self.max.x).contains(&chunk.x) && (self.min.z..=self.max.z).contains(&chunk.z)
+    }
+
+    /// The chunks inside of both areas. Returns `None` if the areas do not overlap.
+    fn intersection(&self, other: &ChunkArea) -> Option<ChunkArea> {
+        let min = ChunkPos::new(self.min.x.max(other.min.x), self.min.z.max(other.min.z));
+        let max = ChunkPos::new(self.max.x.min(other.max.x), self.max.z.min(other.max.z));
+        (min.x <= max.x && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// The area covered by the regions containing the corners.
+    fn regions(&self) -> (RegionPos, RegionPos) {
+        (self.min.region(), self.max.region())
+    }
+
+    fn region_count(&self) -> usize {
+        let (min, max) = self.regions();
+        (max.x - min.x + 1) as usize * (max.z - min.z + 1) as usize
+    }
+
+    /// The area as a bounding box covering the full height of the world.
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_corners(self.min.min_block(i32::MIN), self.max.max_block(i32::MAX))
+    }
+}
+
+impl From<RegionPos> for ChunkArea {
+    fn from(region: RegionPos) -> Self {
+        Self::new(region.min_chunk(), region.max_chunk())
+    }
+}
+
+/// Number of chunks by generation.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Counts {
+    full: usize,
+    partial: usize,
+    missing: usize,
+}
+
+impl Counts {
+    fn of(coverage: &RegionCoverage) -> Self {
+        coverage
+            .iter()
+            .flatten()
+            .fold(Self::default(), |mut counts, generation| {
+                match generation {
+                    Generation::Full => counts.full += 1,
+                    Generation::Partial(_) => counts.partial += 1,
+                    Generation::Missing => counts.missing += 1,
+                }
+                counts
+            })
+    }
+
+    fn total(&self) -> usize {
+        self.full + self.partial + self.missing
+    }
+
+    fn add(&mut self, other: Counts) {
+        self.full += other.full;
+        self.partial += other.partial;
+        self.missing += other.missing;
+    }
+}
+
+pub fn main(world_dir: &Path, args: &Coverage, writer: &mut dyn Write) {
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let border = read_world_border(world_dir);
+    let border = dimension_border(&border, &args.dimension);
+    let (min, max) = border.chunk_corners();
+    let mut target = ChunkArea::new(min, max);
+    if let Some(area) = &args.area {
+        let (chunk1, chunk2) = area.chunk_corners();
+        let Some(area) = target.intersection(&ChunkArea::new(chunk1, chunk2)) else {
+            writeln!(writer, "The area is outside of the world border")
+                .expect("Could not write result");
+            return;
+        };
+        target = area;
+    }
+
+    let bounding_box = target.bounding_box();
+    // Both lists are sorted so existing region files can be looked up with a binary search
+    let (regions, existing) = if target.region_count() <= MAX_CHECKED_REGIONS {
+        let (min, max) = target.regions();
+        let existing = world
+            .region_positions(dimension, RegionKind::Chunks, Some(&bounding_box))
+            .expect("Could not read region directory");
+        (RegionPos::area(min, max).collect::<Vec<_>>(), existing)
+    } else {
+        log::warn!(
+            "The world border contains {} regions, only existing region files are checked",
+            target.region_count()
+        );
+        let existing = world
+            .region_positions(dimension, RegionKind::Chunks, None)
+            .expect("Could not read region directory")
+            .into_iter()
+            .filter(|region| target.intersection(&ChunkArea::from(*region)).is_some())
+            .collect::<Vec<_>>();
+        (existing.clone(), existing)
+    };
+    log::debug!("Checking {} regions", regions.len());
+
+    let coverage = regions
+        .into_iter()
+        .map(|region| {
+            let chunks = if existing.binary_search(&region).is_ok() {
+                world
+                    .load_chunk_region(dimension, region, Some(&bounding_box))
+                    .expect("Error reading file")
+                    .chunks
+            } else {
+                Vec::new()
+            };
+            (region, region_coverage(region, &chunks, &target))
+        })
+        .collect::<Vec<_>>();
+
+    write_report(&coverage, args.list_chunks, writer).expect("Could not write result");
+    if let Some(path) = &args.mask {
+        let Some(image) = mask(&coverage, &target) else {
+            log::info!("No chunks to draw a mask of");
+            return;
+        };
+        render::write_image(&image, path);
+        writeln!(writer, "Wrote mask to {}", path.display()).expect("Could not write result");
+    }
+}
+
+/// Read the world border from the level.dat file.
+fn read_world_border(world_dir: &Path) -> WorldBorder {
+    let data = std::fs::read(world_dir.join("level.dat")).expect("Could not read level.dat");
+    mc_map_reader::parse_world_border(&data)
+        .expect("Could not read the world border from level.dat")
+}
+
+/// The world border of a dimension. The nether scales the border of the overworld,
+/// all other dimensions including custom ones share it.
+fn dimension_border(border: &WorldBorder, dimension: &Dimension) -> WorldBorder {
+    match dimension {
+        Dimension::Nether => WorldBorder {
+            center_x: border.center_x / NETHER_COORDINATE_SCALE,
+            center_z: border.center_z / NETHER_COORDINATE_SCALE,
+            size: border.size / NETHER_COORDINATE_SCALE,
+        },
+        Dimension::Overworld | Dimension::End | Dimension::Custom(_) => border.clone(),
+    }
+}
+
+/// Find out how far every chunk of the region inside of the target area is generated.
+fn region_coverage(region: RegionPos, chunks: &[ChunkData], target: &ChunkArea) -> RegionCoverage {
+    let mut coverage = (0..CHUNKS_PER_REGION)
+        .map(|index| {
+            target
+                .contains(region.chunk_at_index(index))
+                .then_some(Generation::Missing)
+        })
+        .collect::<Vec<_>>();
+    for chunk in chunks {
+        let position = chunk.position();
+        if position.region() != region {
+            continue;
+        }
+        if let Some(generation) = &mut coverage[position.index_in_region()] {
+            *generation = match chunk.status {
+                ChunkStatus::Full => Generation::Full,
+                status => Generation::Partial(status),
+            };
+        }
+    }
+    coverage
+}
+
+fn write_report(
+    coverage: &[(RegionPos, RegionCoverage)],
+    list_chunks: bool,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let mut total = Counts::default();
+    for (region, region_coverage) in coverage {
+        let counts = Counts::of(region_coverage);
+        total.add(counts);
+        writeln!(
+            writer,
+            "{region}: {} full, {} partial, {} missing of {} chunks",
+            counts.full,
+            counts.partial,
+            counts.missing,
+            counts.total()
+        )?;
+        if !list_chunks {
+            continue;
+        }
+        for (index, generation) in region_coverage.iter().enumerate() {
+            let chunk = region.chunk_at_index(index);
+            match generation {
+                Some(Generation::Partial(status)) => {
+                    writeln!(writer, "Partial chunk {chunk} with status {}", status.id())?
+                }
+                Some(Generation::Missing) => writeln!(writer, "Missing chunk {chunk}")?,
+                Some(Generation::Full) | None => {}
+            }
+        }
+    }
+    let generated = match total.total() {
+        0 => 100.,
+        chunks => total.full as f64 * 100. / chunks as f64,
+    };
+    writeln!(
+        writer,
+        "Total: {} full, {} partial, {} missing of {} chunks ({generated:.2}% generated)",
+        total.full,
+        total.partial,
+        total.missing,
+        total.total()
+    )
+}
+
+/// Draw one pixel per chunk of the checked regions inside of the target area.
+/// Returns `None` if no region was checked.
+fn mask(coverage: &[(RegionPos, RegionCoverage)], target: &ChunkArea) -> Option<Image> {
+    let checked = coverage
+        .iter()
+        .map(|(region, _)| ChunkArea::from(*region))
+        .reduce(|a, b| {
+            ChunkArea::new(
+                ChunkPos::new(a.min.x.min(b.min.x), a.min.z.min(b.min.z)),
+                ChunkPos::new(a.max.x.max(b.max.x), a.max.z.max(b.max.z)),
+            )
+        })?;
+    let bounds = checked.intersection(target)?;
+    let mut image = Image::new(
+        (bounds.max.x - bounds.min.x + 1) as u32,
+        (bounds.max.z - bounds.min.z + 1) as u32,
+    );
+    for (region, region_coverage) in coverage {
+        for (index, generation) in region_coverage.iter().enumerate() {
+            let chunk = region.chunk_at_index(index);
+            let color = match generation {
+                Some(Generation::Full) => FULL_COLOR,
+                Some(Generation::Partial(_)) => PARTIAL_COLOR,
+                Some(Generation::Missing) => MISSING_COLOR,
+                None => continue,
+            };
+            let (Ok(x), Ok(z)) = (
+                u32::try_from(chunk.x - bounds.min.x),
+                u32::try_from(chunk.z - bounds.min.z),
+            ) else {
+                continue;
+            };
+            image.set(x, z, color);
+        }
+    }
+    Some(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+    use crate::test_util::empty_chunk;
+
+    fn chunk(x: i32, z: i32, status: ChunkStatus) -> ChunkData {
+        ChunkData {
+            status,
+            ..empty_chunk(x, z)
+        }
+    }
+
+    fn area(x1: i32, z1: i32, x2: i32, z2: i32) -> ChunkArea {
+        ChunkArea::new(ChunkPos::new(x1, z1), ChunkPos::new(x2, z2))
+    }
+
+    #[test_case(area(0, 0, 10, 10), area(5, -5, 20, 5) => Some(area(5, 0, 10, 5)); "Overlapping")]
+    #[test_case(area(0, 0, 10, 10), area(2, 2, 3, 3) => Some(area(2, 2, 3, 3)); "Inside")]
+    #[test_case(area(0, 0, 10, 10), area(11, 0, 20, 10) => None; "Next to each other")]
+    fn test_chunk_area_intersection(a: ChunkArea, b: ChunkArea) -> Option<ChunkArea> {
+        a.intersection(&b)
+    }
+
+    #[test_case(area(0, 0, 31, 31) => 1; "Single region")]
+    #[test_case(area(-1, 0, 32, 0) => 3; "Row")]
+    #[test_case(area(-33, -33, 0, 0) => 9; "Square")]
+    fn test_chunk_area_region_count(area: ChunkArea) -> usize {
+        area.region_count()
+    }
+
+    #[test_case(Dimension::Overworld => (ChunkPos::new(-4, -4), ChunkPos::new(3, 3)); "Overworld")]
+    #[test_case(Dimension::Nether => (ChunkPos::new(-1, -1), ChunkPos::new(0, 0)); "Nether")]
+    #[test_case(Dimension::End => (ChunkPos::new(-4, -4), ChunkPos::new(3, 3)); "End")]
+    #[test_case(Dimension::Custom("example:mining".to_string()) => (ChunkPos::new(-4, -4), ChunkPos::new(3, 3)); "Custom")]
+    fn test_dimension_border(dimension: Dimension) -> (ChunkPos, ChunkPos) {
+        let border = WorldBorder {
+            center_x: 0.,
+            center_z: 0.,
+            size: 128.,
+        };
+        dimension_border(&border, &dimension).chunk_corners()
+    }
+
+    fn test_coverage() -> Vec<(RegionPos, RegionCoverage)> {
+        let region = RegionPos::new(0, 0);
+        let chunks = [
+            chunk(0, 0, ChunkStatus::Full),
+            chunk(1, 0, ChunkStatus::Features),
+            chunk(0, 1, ChunkStatus::Full),
+            chunk(5, 5, ChunkStatus::Full),
+        ];
+        vec![(region, region_coverage(region, &chunks, &area(0, 0, 1, 1)))]
+    }
+
+    #[test]
+    fn test_region_coverage() {
+        let coverage = &test_coverage()[0].1;
+        assert_eq!(coverage.len(), CHUNKS_PER_REGION);
+        assert_eq!(coverage[0], Some(Generation::Full));
+        assert_eq!(
+            coverage[1],
+            Some(Generation::Partial(ChunkStatus::Features))
+        );
+        assert_eq!(coverage[32], Some(Generation::Full));
+        assert_eq!(coverage[33], Some(Generation::Missing));
+        assert_eq!(coverage[5 * 32 + 5], None);
+        assert_eq!(
+            Counts::of(coverage),
+            Counts {
+                full: 2,
+                partial: 1,
+                missing: 1
+            }
+        );
+    }
+
+    #[test_case(false => "r.0.0: 2 full, 1 partial, 1 missing of 4 chunks\n\
+        Total: 2 full, 1 partial, 1 missing of 4 chunks (50.00% generated)\n"; "Summary")]
+    #[test_case(true => "r.0.0: 2 full, 1 partial, 1 missing of 4 chunks\n\
+        Partial chunk c.1.0 with status features\n\
+        Missing chunk c.1.1\n\
+        Total: 2 full, 1 partial, 1 missing of 4 chunks (50.00% generated)\n"; "List chunks")]
+    fn test_write_report(list_chunks: bool) -> String {
+        let mut out = Vec::new();
+        write_report(&test_coverage(), list_chunks, &mut out).expect("Error writing report");
+        String::from_utf8(out).expect("Invalid utf-8")
+    }
+
+    #[test]
+    fn test_mask() {
+        let image = mask(&test_coverage(), &area(-10, 0, 1, 1)).expect("Missing mask");
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.get(0, 0), Some(FULL_COLOR));
+        assert_eq!(image.get(1, 0), Some(PARTIAL_COLOR));
+        assert_eq!(image.get(1, 1), Some(MISSING_COLOR));
+        assert!(mask(&[], &area(0, 0, 1, 1)).is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct FindBlocks {
+    /// Blocks to search for. Wildcards are supported in ids and property values
+    /// e.g. "minecraft:*_portal" or "minecraft:end_portal_frame[eye=true]"
+    #[arg(short, long = "block", value_parser=parse_block_pattern, required = true)]
+    pub blocks: Vec<BlockPattern>,
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// Lowest Y level to search in
+    #[arg(long, allow_negative_numbers = true)]
+    pub min_y: Option<i32>,
+    /// Highest Y level to search in
+    #[arg(long, allow_negative_numbers = true)]
+    pub max_y: Option<i32>,
+    /// Maximum number of blocks to list
+    #[arg(short, long)]
+    pub limit: Option<usize>,
+}
+
+/// A block id with optional block state properties e.g. `minecraft:end_portal_frame[eye=true]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockPattern {
+    pub id: String,
+    pub properties: Vec<(String, String)>,
+}
+
+pub(crate) fn parse_block_pattern(value: &str) -> Result<BlockPattern, String> {
+    let error = || {
+        format!("Can not parse block \"{value}\". Blocks must be given as followed: \"<id>\" or \"<id>[<property>=<value>,...]\".")
+    };
+    let Some((id, properties)) = value.split_once('[') else {
+        return Ok(BlockPattern {
+            id: value.to_string(),
+            properties: Vec::new(),
+        });
+    };
+    let properties = properties.strip_suffix(']').ok_or_else(error)?;
+    let properties = properties
+        .split(',')
+        .map(|property| {
+            property
+                .split_once('=')
+                .filter(|(name, value)| !name.is_empty() && !value.is_empty())
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .ok_or_else(error)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(BlockPattern {
+        id: id.to_string(),
+        properties,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn pattern(id: &str, properties: &[(&str, &str)]) -> BlockPattern {
+        BlockPattern {
+            id: id.to_string(),
+            properties: properties
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test_case("minecraft:*_portal" => Ok(pattern("minecraft:*_portal", &[])); "Id")]
+    #[test_case("minecraft:end_portal_frame[eye=true]" => Ok(pattern("minecraft:end_portal_frame", &[("eye", "true")])); "Property")]
+    #[test_case("minecraft:chest[facing=north,type=*]" => Ok(pattern("minecraft:chest", &[("facing", "north"), ("type", "*")])); "Multiple properties")]
+    #[test_case("minecraft:chest[facing=north" => Err(()); "Missing bracket")]
+    #[test_case("minecraft:chest[facing]" => Err(()); "Missing value")]
+    #[test_case("minecraft:chest[]" => Err(()); "Empty properties")]
+    fn test_parse_block_pattern(value: &str) -> Result<BlockPattern, ()> {
+        parse_block_pattern(value).map_err(|_| ())
+    }
+}
@@ -0,0 +1,286 @@
+use std::{io::Write, path::Path};
+
+use mc_map_reader::{
+    data::chunk::{BlockState, ChunkData, SECTION_SIZE},
+    files::RegionKind,
+    BlockPos, BoundingBox, SectionPos, World,
+};
+use wildmatch::WildMatch;
+
+use self::args::{BlockPattern, FindBlocks};
+
+pub mod args;
+
+/// A [`BlockPattern`] with compiled wildcards.
+struct BlockMatcher {
+    id: WildMatch,
+    properties: Vec<(String, WildMatch)>,
+}
+
+impl BlockMatcher {
+    fn new(pattern: &BlockPattern) -> Self {
+        Self {
+            id: WildMatch::new(&pattern.id),
+            properties: pattern
+                .properties
+                .iter()
+                .map(|(name, value)| (name.clone(), WildMatch::new(value)))
+                .collect(),
+        }
+    }
+
+    fn matches(&self, block: &BlockState) -> bool {
+        self.id.matches(&block.name)
+            && self.properties.iter().all(|(name, value)| {
+                block
+                    .property(name)
+                    .is_some_and(|property| value.matches(property))
+            })
+    }
+}
+
+pub fn main(world_dir: &Path, args: &FindBlocks, writer: &mut dyn Write) {
+    let matchers = args
+        .blocks
+        .iter()
+        .map(BlockMatcher::new)
+        .collect::<Vec<_>>();
+    let bounding_box = search_box(args);
+    // Without an area the box spans every coordinate and must not be used to list regions
+    let area_box = args.area.as_ref().map(|_| &bounding_box);
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, area_box)
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+
+    let mut remaining = args.limit.unwrap_or(usize::MAX);
+    for region in regions {
+        if remaining == 0 {
+            break;
+        }
+        let chunks = world
+            .load_chunk_region(dimension, region, area_box)
+            .expect("Error reading file")
+            .chunks;
+        for chunk in chunks.iter() {
+            for (position, block) in find_in_chunk(chunk, &matchers, &bounding_box) {
+                if remaining == 0 {
+                    return;
+                }
+                remaining -= 1;
+                write_block(position, block, writer).expect("Could not write result");
+            }
+        }
+    }
+}
+
+/// The box blocks are searched in. Without an area every X and Z coordinate is included.
+fn search_box(args: &FindBlocks) -> BoundingBox {
+    let (x1, z1, x2, z2) = match &args.area {
+        Some(area) => (area.x1, area.z1, area.x2, area.z2),
+        None => (i32::MIN, i32::MIN, i32::MAX, i32::MAX),
+    };
+    BoundingBox::from_corners(
+        BlockPos::new(x1, args.min_y.unwrap_or(i32::MIN), z1),
+        BlockPos::new(x2, args.max_y.unwrap_or(i32::MAX), z2),
+    )
+}
+
+/// Find all blocks in the chunk matching any of the matchers inside of the bounding box.
+fn find_in_chunk<'a>(
+    chunk: &'a ChunkData,
+    matchers: &'a [BlockMatcher],
+    bounding_box: &'a BoundingBox,
+) -> impl Iterator<Item = (BlockPos, &'a BlockState)> {
+    let size = SECTION_SIZE as i32;
+    chunk
+        .sections
+        .iter()
+        .filter_map(move |section| {
+            let min = SectionPos::new(chunk.x_pos, section.y as i32, chunk.z_pos).min_block();
+            if min.y + size - 1 < bounding_box.min_y || min.y > bounding_box.max_y {
+                return None;
+            }
+            // Only decode sections whose palette contains a matching block state
+            let matching = section
+                .block_states
+                .palette
+                .iter()
+                .filter(|block| matchers.iter().any(|matcher| matcher.matches(block)))
+                .collect::<Vec<_>>();
+            if matching.is_empty() {
+                return None;
+            }
+            Some(
+                section
+                    .block_states
+                    .iter()
+                    .filter(move |(_, block)| {
+                        matching
+                            .iter()
+                            .any(|matching| std::ptr::eq(*matching, *block))
+                    })
+                    .map(move |((x, y, z), block)| {
+                        (
+                            BlockPos::new(min.x + x as i32, min.y + y as i32, min.z + z as i32),
+                            block,
+                        )
+                    })
+                    .filter(|(position, _)| bounding_box.contains(*position)),
+            )
+        })
+        .flatten()
+}
+
+fn write_block(
+    position: BlockPos,
+    block: &BlockState,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let BlockPos { x, y, z } = position;
+    write!(writer, "Found {}", block.name)?;
+    let mut properties = block
+        .properties
+        .iter()
+        .flatten()
+        .filter_map(|(name, _)| block.property(name).map(|value| format!("{name}={value}")))
+        .collect::<Vec<_>>();
+    if !properties.is_empty() {
+        properties.sort();
+        write!(writer, "[{}]", properties.join(","))?;
+    }
+    writeln!(writer, " at x:{x} y:{y} z:{z}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::{
+        data::chunk::{BlockStates, BLOCKS_PER_SECTION},
+        nbt::{Array, List, Tag},
+    };
+    use test_case::test_case;
+
+    use super::{args::parse_block_pattern, *};
+    use crate::test_util::{chunk_with_sections, section};
+
+    fn block_state(name: &str, properties: &[(&str, &str)]) -> BlockState {
+        BlockState {
+            name: name.to_string(),
+            properties: (!properties.is_empty()).then(|| {
+                HashMap::from_iter(
+                    properties
+                        .iter()
+                        .map(|(name, value)| (name.to_string(), Tag::String(value.to_string()))),
+                )
+            }),
+        }
+    }
+
+    /// Stone. The first two blocks are end portal frames with and without an eye.
+    fn portal_frames() -> BlockStates {
+        let mut data = vec![0; BLOCKS_PER_SECTION / 16];
+        data[0] = 0x21;
+        BlockStates {
+            palette: List::from(vec![
+                block_state("minecraft:stone", &[]),
+                block_state(
+                    "minecraft:end_portal_frame",
+                    &[("eye", "true"), ("facing", "north")],
+                ),
+                block_state(
+                    "minecraft:end_portal_frame",
+                    &[("eye", "false"), ("facing", "north")],
+                ),
+            ]),
+            data: Some(Array::from(data)),
+        }
+    }
+
+    fn chunk() -> ChunkData {
+        chunk_with_sections(
+            -1,
+            2,
+            vec![section(-4, portal_frames()), section(0, portal_frames())],
+        )
+    }
+
+    fn unbounded() -> BoundingBox {
+        BoundingBox::from_corners(
+            BlockPos::new(i32::MIN, i32::MIN, i32::MIN),
+            BlockPos::new(i32::MAX, i32::MAX, i32::MAX),
+        )
+    }
+
+    fn find(patterns: &[&str], bounding_box: BoundingBox) -> Vec<BlockPos> {
+        let matchers = patterns
+            .iter()
+            .map(|pattern| {
+                BlockMatcher::new(&parse_block_pattern(pattern).expect("Invalid pattern"))
+            })
+            .collect::<Vec<_>>();
+        let chunk = chunk();
+        find_in_chunk(&chunk, &matchers, &bounding_box)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    #[test_case(&["minecraft:end_portal_frame"] => vec![
+        BlockPos::new(-16, -64, 32),
+        BlockPos::new(-15, -64, 32),
+        BlockPos::new(-16, 0, 32),
+        BlockPos::new(-15, 0, 32),
+    ]; "Id")]
+    #[test_case(&["minecraft:end_portal_frame[eye=true]"] => vec![
+        BlockPos::new(-16, -64, 32),
+        BlockPos::new(-16, 0, 32),
+    ]; "Property")]
+    #[test_case(&["minecraft:*_frame[eye=f*]"] => vec![
+        BlockPos::new(-15, -64, 32),
+        BlockPos::new(-15, 0, 32),
+    ]; "Wildcards")]
+    #[test_case(&["minecraft:end_portal_frame[power=1]"] => Vec::<BlockPos>::new(); "Missing property")]
+    #[test_case(&["minecraft:*_portal"] => Vec::<BlockPos>::new(); "No match")]
+    fn test_find_in_chunk(patterns: &[&str]) -> Vec<BlockPos> {
+        find(patterns, unbounded())
+    }
+
+    #[test]
+    fn test_find_in_chunk_in_box() {
+        assert_eq!(
+            find(
+                &["minecraft:end_portal_frame"],
+                BoundingBox::from_corners(BlockPos::new(-15, -1, 0), BlockPos::new(0, 300, 100))
+            ),
+            vec![BlockPos::new(-15, 0, 32)]
+        );
+    }
+
+    #[test]
+    fn test_write_block() {
+        let mut out = Vec::new();
+        write_block(
+            BlockPos::new(1, -2, 3),
+            &block_state(
+                "minecraft:end_portal_frame",
+                &[("facing", "north"), ("eye", "true")],
+            ),
+            &mut out,
+        )
+        .expect("Error writing result");
+        write_block(
+            BlockPos::new(0, 0, 0),
+            &block_state("minecraft:bedrock", &[]),
+            &mut out,
+        )
+        .expect("Error writing result");
+        assert_eq!(
+            String::from_utf8(out).expect("Invalid utf-8"),
+            "Found minecraft:end_portal_frame[eye=true,facing=north] at x:1 y:-2 z:3\n\
+             Found minecraft:bedrock at x:0 y:0 z:0\n"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct FindDuplicateItems {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// Do not check the inventories and ender chests of players
+    #[arg(short, long)]
+    pub skip_players: bool,
+    /// Compare every item with data instead of only items which should be unique
+    #[arg(long)]
+    pub all: bool,
+    /// Minimum number of containers an item has to be found in
+    #[arg(short, long, default_value_t = 2)]
+    pub min_containers: usize,
+}
@@ -0,0 +1,494 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+    io::Write,
+    path::Path,
+};
+
+use mc_map_reader::{
+    data::{
+        item::{ContentSlot, Item},
+        text_component::TextFormat,
+    },
+    nbt::hash_compound,
+    World,
+};
+
+use crate::world_items;
+
+use self::args::FindDuplicateItems;
+
+pub mod args;
+
+/// Maximum depth of items inside of items which are compared.
+const MAX_CONTENT_DEPTH: usize = 16;
+
+/// A place an item was found at.
+#[derive(Debug, Clone, PartialEq)]
+struct FoundItem {
+    /// Where the item was found e.g. `minecraft:chest at x:1 y:64 z:-3`.
+    location: String,
+    /// Items containing the item, outermost first.
+    path: Vec<ContentSlot>,
+    count: i32,
+}
+
+/// Items with the same id and data.
+#[derive(Debug, Clone, PartialEq)]
+struct Cluster {
+    fingerprint: u64,
+    /// The first item found with this fingerprint.
+    item: Item,
+    /// Text format of the file the first item was read from.
+    format: TextFormat,
+    found: Vec<FoundItem>,
+}
+
+impl Cluster {
+    /// Number of different containers the items were found in.
+    /// Items inside of different shulker boxes in the same chest count as different containers.
+    fn container_count(&self) -> usize {
+        self.found
+            .iter()
+            .map(|found| (&found.location, &found.path))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+pub fn main(world_dir: &Path, args: &FindDuplicateItems, writer: &mut dyn Write) {
+    let world = World::open(world_dir).expect("Could not open world");
+
+    let mut clusters = Clusters::new();
+    world_items::for_each_item(
+        &world,
+        args.dimension.id(),
+        args.area.as_ref(),
+        !args.skip_players,
+        &mut |location, item, format| add_item(location, item, format, args.all, &mut clusters),
+    );
+    log::debug!("Found {} fingerprints", clusters.len());
+
+    let clusters = duplicate_clusters(clusters, args.min_containers);
+    write_report(&clusters, writer).expect("Could not write result");
+}
+
+/// Clusters by their fingerprint. Items with different data can share a fingerprint,
+/// so the data of an item is compared with every cluster of its fingerprint.
+type Clusters = HashMap<u64, Vec<Cluster>>;
+
+/// Adds the item and all items inside of it to their clusters.
+fn add_item(location: &str, item: &Item, format: TextFormat, all: bool, clusters: &mut Clusters) {
+    let contents = item.walk_contents(MAX_CONTENT_DEPTH);
+    for (path, item) in std::iter::once((&[][..], item)).chain(contents.iter()) {
+        let compared = if all {
+            has_data(item)
+        } else {
+            should_be_unique(item, format)
+        };
+        if !compared {
+            continue;
+        }
+        let found = FoundItem {
+            location: location.to_string(),
+            path: path.to_vec(),
+            count: item.count,
+        };
+        let fingerprint = fingerprint(item);
+        let candidates = clusters.entry(fingerprint).or_default();
+        let index = match candidates
+            .iter()
+            .position(|cluster| same_data(&cluster.item, item))
+        {
+            Some(index) => index,
+            None => {
+                candidates.push(Cluster {
+                    fingerprint,
+                    item: item.clone(),
+                    format,
+                    found: Vec::new(),
+                });
+                candidates.len() - 1
+            }
+        };
+        candidates[index].found.push(found);
+    }
+}
+
+/// Returns true if both items have the same id and data. The count is ignored.
+fn same_data(a: &Item, b: &Item) -> bool {
+    a.id == b.id && a.tag == b.tag && a.components == b.components
+}
+
+/// Clusters found in at least `min_containers` containers, the largest first.
+fn duplicate_clusters(clusters: Clusters, min_containers: usize) -> Vec<Cluster> {
+    let mut clusters = clusters
+        .into_values()
+        .flatten()
+        .filter(|cluster| cluster.container_count() >= min_containers)
+        .collect::<Vec<_>>();
+    clusters.sort_by(|a, b| {
+        b.container_count()
+            .cmp(&a.container_count())
+            .then(a.fingerprint.cmp(&b.fingerprint))
+    });
+    clusters
+}
+
+/// Hash of the id and data of an item. The count is ignored.
+/// Compounds are hashed sorted by their keys, so the fingerprint is the same on every run and platform.
+fn fingerprint(item: &Item) -> u64 {
+    let mut hasher = FnvHasher::default();
+    item.id.hash(&mut hasher);
+    for data in [&item.tag, &item.components] {
+        match data {
+            Some(data) => {
+                true.hash(&mut hasher);
+                hash_compound(data, &mut hasher);
+            }
+            None => false.hash(&mut hasher),
+        }
+    }
+    hasher.finish()
+}
+
+/// 64 bit FNV-1a hash. Unlike the hasher of the standard library its output is fixed,
+/// numbers are written in little endian order independent of the platform.
+/// [Specification](http://www.isthe.com/chongo/tech/comp/fnv/index.html)
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64)
+    }
+}
+
+fn has_data(item: &Item) -> bool {
+    [&item.tag, &item.components]
+        .into_iter()
+        .flatten()
+        .any(|data| !data.is_empty())
+}
+
+/// Items which can only be created once like renamed gear, original written books and colored maps.
+/// Enchanted gear without a name is not unique because every player can enchant the same gear.
+/// Copies of books and maps are not unique because players can copy them as often as they like,
+/// only maps with display data like explorer maps are compared by their map id.
+fn should_be_unique(item: &Item, format: TextFormat) -> bool {
+    item.custom_name(format).is_some()
+        || !item.lore(format).is_empty()
+        || item
+            .written_book(format)
+            .is_some_and(|book| book.generation == 0)
+        || item.get("minecraft:map_id").is_some() && item.get("minecraft:map_color").is_some()
+}
+
+fn describe(item: &Item, format: TextFormat) -> String {
+    if let Some(book) = item.written_book(format) {
+        format!("{} {:?} by {}", item.id, book.title, book.author)
+    } else if let Some(name) = item.custom_name(format) {
+        format!("{} {:?}", item.id, name)
+    } else {
+        item.id.clone()
+    }
+}
+
+fn write_report(clusters: &[Cluster], writer: &mut dyn Write) -> std::io::Result<()> {
+    for cluster in clusters {
+        writeln!(
+            writer,
+            "{} (fingerprint {:016x}) found in {} containers",
+            describe(&cluster.item, cluster.format),
+            cluster.fingerprint,
+            cluster.container_count()
+        )?;
+        for found in &cluster.found {
+            write!(writer, "  {}", found.location)?;
+            for slot in &found.path {
+                write!(writer, " > {} slot {}", slot.container, slot.slot)?;
+            }
+            writeln!(writer, " x{}", found.count)?;
+        }
+    }
+    writeln!(writer, "Found {} duplicate clusters", clusters.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use mc_map_reader::nbt::{List, Tag};
+    use test_case::test_case;
+
+    use super::*;
+
+    fn string(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn named(id: &str, name: &str) -> Item {
+        Item {
+            id: id.to_string(),
+            count: 1,
+            tag: Some(HashMap::from_iter([(
+                "display".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "Name".to_string(),
+                    string(&format!(r#"{{"text":"{name}"}}"#)),
+                )])),
+            )])),
+            components: None,
+        }
+    }
+
+    fn book(generation: i32) -> Item {
+        Item {
+            id: "minecraft:written_book".to_string(),
+            count: 1,
+            tag: Some(HashMap::from_iter([
+                ("title".to_string(), string("Diary")),
+                ("author".to_string(), string("Steve")),
+                ("generation".to_string(), Tag::Int(generation)),
+                (
+                    "pages".to_string(),
+                    Tag::List(List::from(vec![string(r#"{"text":"Day 1"}"#)])),
+                ),
+            ])),
+            components: None,
+        }
+    }
+
+    fn enchanted(id: &str) -> Item {
+        Item {
+            id: id.to_string(),
+            count: 1,
+            tag: None,
+            components: Some(HashMap::from_iter([(
+                "minecraft:enchantments".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "minecraft:protection".to_string(),
+                    Tag::Int(4),
+                )])),
+            )])),
+        }
+    }
+
+    fn plain(id: &str) -> Item {
+        Item {
+            id: id.to_string(),
+            count: 64,
+            tag: None,
+            components: None,
+        }
+    }
+
+    fn shulker_box(items: Vec<(i8, Item)>) -> Item {
+        let items = items
+            .into_iter()
+            .map(|(slot, item)| {
+                let mut tag = HashMap::from_iter([
+                    ("id".to_string(), string(&item.id)),
+                    ("Count".to_string(), Tag::Byte(item.count as i8)),
+                    ("Slot".to_string(), Tag::Byte(slot)),
+                ]);
+                if let Some(data) = item.tag {
+                    tag.insert("tag".to_string(), Tag::Compound(data));
+                }
+                Tag::Compound(tag)
+            })
+            .collect::<Vec<_>>();
+        Item {
+            id: "minecraft:shulker_box".to_string(),
+            count: 1,
+            tag: Some(HashMap::from_iter([(
+                "BlockEntityTag".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "Items".to_string(),
+                    Tag::List(List::from(items)),
+                )])),
+            )])),
+            components: None,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_count() {
+        let mut stack = named("minecraft:diamond", "Shiny");
+        let single = stack.clone();
+        stack.count = 64;
+        assert_eq!(fingerprint(&stack), fingerprint(&single));
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        // FNV-1a of the id, the end of the string and the missing tag and components
+        assert_eq!(
+            fingerprint(&plain("minecraft:stone")),
+            0x7363_e59d_cc97_539c
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_collision() {
+        let sword = named("minecraft:diamond_sword", "Excalibur");
+        let other = named("minecraft:diamond_sword", "Durendal");
+        let fingerprint = fingerprint(&other);
+        // A different item which has the same fingerprint
+        let mut clusters = Clusters::from_iter([(
+            fingerprint,
+            vec![Cluster {
+                fingerprint,
+                item: sword,
+                format: TextFormat::Json,
+                found: Vec::new(),
+            }],
+        )]);
+        add_item("chest", &other, TextFormat::Json, false, &mut clusters);
+        let candidates = &clusters[&fingerprint];
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[1].item, other);
+    }
+
+    #[test_case(named("minecraft:diamond_sword", "Excalibur"), named("minecraft:diamond_sword", "Durendal") => false; "Different names")]
+    #[test_case(named("minecraft:diamond_sword", "Excalibur"), named("minecraft:iron_sword", "Excalibur") => false; "Different ids")]
+    #[test_case(book(0), book(1) => false; "Different generations")]
+    #[test_case(book(0), book(0) => true; "Same book")]
+    #[test_case(plain("minecraft:stone"), Item { components: Some(HashMap::new()), ..plain("minecraft:stone") } => false; "Different formats")]
+    fn test_fingerprint_equal(a: Item, b: Item) -> bool {
+        fingerprint(&a) == fingerprint(&b)
+    }
+
+    #[test_case(named("minecraft:diamond_sword", "Excalibur") => true; "Named")]
+    #[test_case(book(0) => true; "Original book")]
+    #[test_case(book(1) => false; "Copied book")]
+    #[test_case(plain("minecraft:stone") => false; "Plain")]
+    #[test_case(enchanted("minecraft:diamond_boots") => false; "Enchanted")]
+    #[test_case(Item {
+        tag: Some(HashMap::from_iter([("map".to_string(), Tag::Int(5))])),
+        ..plain("minecraft:filled_map")
+    } => false; "Map")]
+    #[test_case(Item {
+        tag: Some(HashMap::from_iter([
+            ("map".to_string(), Tag::Int(5)),
+            ("display".to_string(), Tag::Compound(HashMap::from_iter([("MapColor".to_string(), Tag::Int(3830373))]))),
+        ])),
+        ..plain("minecraft:filled_map")
+    } => true; "Legacy explorer map")]
+    #[test_case(Item {
+        components: Some(HashMap::from_iter([
+            ("minecraft:map_id".to_string(), Tag::Int(5)),
+            ("minecraft:map_color".to_string(), Tag::Int(3830373)),
+        ])),
+        ..plain("minecraft:filled_map")
+    } => true; "Component explorer map")]
+    fn test_should_be_unique(item: Item) -> bool {
+        should_be_unique(&item, TextFormat::Json)
+    }
+
+    fn clusters(items: &[(&str, Item)], all: bool) -> String {
+        let mut clusters = Clusters::new();
+        for (location, item) in items {
+            add_item(location, item, TextFormat::Json, all, &mut clusters);
+        }
+        let mut out = Vec::new();
+        write_report(&duplicate_clusters(clusters, 2), &mut out).expect("Error writing report");
+        let report = String::from_utf8(out).expect("Invalid utf-8");
+        // Fingerprints are tested separately
+        report
+            .lines()
+            .map(|line| match line.find(" (fingerprint ") {
+                Some(start) => format!("{}{}", &line[..start], &line[start + 31..]),
+                None => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_clusters() {
+        let items = [
+            ("minecraft:chest at x:0 y:64 z:0", book(0)),
+            ("minecraft:chest at x:0 y:64 z:0", plain("minecraft:stone")),
+            (
+                "minecraft:barrel at x:5 y:64 z:0",
+                shulker_box(vec![(3, book(0)), (4, plain("minecraft:stone"))]),
+            ),
+            (
+                "player 0000 inventory",
+                named("minecraft:diamond_sword", "Excalibur"),
+            ),
+            ("player 0000 inventory", book(1)),
+            ("minecraft:chest at x:9 y:64 z:0", book(1)),
+        ];
+        assert_eq!(
+            clusters(&items, false),
+            r#"minecraft:written_book "Diary" by Steve found in 2 containers
+  minecraft:chest at x:0 y:64 z:0 x1
+  minecraft:barrel at x:5 y:64 z:0 > minecraft:shulker_box slot 3 x1
+Found 1 duplicate clusters"#
+        );
+    }
+
+    #[test]
+    fn test_clusters_same_container() {
+        let items = [
+            (
+                "minecraft:chest at x:0 y:64 z:0",
+                named("minecraft:diamond_sword", "Excalibur"),
+            ),
+            (
+                "minecraft:chest at x:0 y:64 z:0",
+                named("minecraft:diamond_sword", "Excalibur"),
+            ),
+        ];
+        assert_eq!(clusters(&items, false), "Found 0 duplicate clusters");
+    }
+
+    #[test]
+    fn test_clusters_all() {
+        let items = [
+            ("player 0000 inventory", book(1)),
+            ("minecraft:chest at x:9 y:64 z:0", book(1)),
+            ("minecraft:chest at x:9 y:64 z:0", plain("minecraft:stone")),
+            ("player 0000 inventory", plain("minecraft:stone")),
+        ];
+        assert_eq!(
+            clusters(&items, true),
+            r#"minecraft:written_book "Diary" by Steve found in 2 containers
+  player 0000 inventory x1
+  minecraft:chest at x:9 y:64 z:0 x1
+Found 1 duplicate clusters"#
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct FindIllegalItems {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// JSON file with rules which are added to the built-in rules
+    #[arg(short, long)]
+    pub rules: Option<PathBuf>,
+    /// Do not check the inventories and ender chests of players
+    #[arg(short, long)]
+    pub skip_players: bool,
+}
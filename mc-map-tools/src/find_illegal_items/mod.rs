@@ -0,0 +1,224 @@
+use std::{io::Write, path::Path};
+
+use mc_map_reader::{
+    data::item::{ContentSlot, Item},
+    World,
+};
+
+use crate::world_items;
+
+use self::{
+    args::FindIllegalItems,
+    rules::{IllegalItemRules, Violation},
+};
+
+pub mod args;
+mod rules;
+
+/// Maximum depth of items inside of items which are checked.
+const MAX_CONTENT_DEPTH: usize = 16;
+
+/// An item which violates at least one rule.
+#[derive(Debug, Clone, PartialEq)]
+struct IllegalItem {
+    /// Where the item was found e.g. `minecraft:chest at x:1 y:64 z:-3`.
+    location: String,
+    /// Items containing the item, outermost first.
+    path: Vec<ContentSlot>,
+    item: Item,
+    violations: Vec<Violation>,
+}
+
+pub fn main(world_dir: &Path, args: &FindIllegalItems, writer: &mut dyn Write) {
+    let rules = match &args.rules {
+        Some(path) => {
+            IllegalItemRules::new(std::fs::File::open(path).expect("Could not open rules file"))
+                .expect("Invalid rules file")
+        }
+        None => IllegalItemRules::default(),
+    };
+    let world = World::open(world_dir).expect("Could not open world");
+
+    let mut illegal_items = Vec::new();
+    world_items::for_each_item(
+        &world,
+        args.dimension.id(),
+        args.area.as_ref(),
+        !args.skip_players,
+        &mut |location, item, _| check_item(&rules, location, item, &mut illegal_items),
+    );
+
+    write_report(&illegal_items, writer).expect("Could not write result");
+}
+
+/// Checks the item and all items inside of it.
+fn check_item(
+    rules: &IllegalItemRules,
+    location: &str,
+    item: &Item,
+    illegal_items: &mut Vec<IllegalItem>,
+) {
+    let contents = item.walk_contents(MAX_CONTENT_DEPTH);
+    for (path, item) in std::iter::once((&[][..], item)).chain(contents.iter()) {
+        let violations = rules.check(item);
+        if !violations.is_empty() {
+            illegal_items.push(IllegalItem {
+                location: location.to_string(),
+                path: path.to_vec(),
+                item: item.clone(),
+                violations,
+            });
+        }
+    }
+}
+
+fn write_report(illegal_items: &[IllegalItem], writer: &mut dyn Write) -> std::io::Result<()> {
+    for illegal_item in illegal_items {
+        write!(writer, "{}", illegal_item.location)?;
+        for slot in &illegal_item.path {
+            write!(writer, " > {} slot {}", slot.container, slot.slot)?;
+        }
+        writeln!(
+            writer,
+            ": {} x{}",
+            illegal_item.item.id, illegal_item.item.count
+        )?;
+        for violation in &illegal_item.violations {
+            writeln!(writer, "  {violation}")?;
+        }
+    }
+    writeln!(writer, "Found {} illegal items", illegal_items.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::nbt::{List, Tag};
+
+    use super::*;
+
+    fn string(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn item_tag(id: &str, count: i8) -> Tag {
+        Tag::Compound(HashMap::from_iter([
+            ("id".to_string(), string(id)),
+            ("Count".to_string(), Tag::Byte(count)),
+        ]))
+    }
+
+    fn shulker_box(items: Vec<Tag>) -> Item {
+        Item {
+            id: "minecraft:shulker_box".to_string(),
+            count: 1,
+            tag: Some(HashMap::from_iter([(
+                "BlockEntityTag".to_string(),
+                Tag::Compound(HashMap::from_iter([(
+                    "Items".to_string(),
+                    Tag::List(List::from(items)),
+                )])),
+            )])),
+            components: None,
+        }
+    }
+
+    #[test]
+    fn test_check_item_walks_contents() {
+        let slot = |id, count| match item_tag(id, count) {
+            Tag::Compound(mut item) => {
+                item.insert("Slot".to_string(), Tag::Byte(3));
+                Tag::Compound(item)
+            }
+            _ => unreachable!(),
+        };
+        let item = shulker_box(vec![
+            slot("minecraft:diamond", 64),
+            slot("minecraft:barrier", 1),
+        ]);
+        let mut illegal_items = Vec::new();
+        check_item(
+            &IllegalItemRules::default(),
+            "minecraft:chest at x:1 y:64 z:-3",
+            &item,
+            &mut illegal_items,
+        );
+        assert_eq!(illegal_items.len(), 1);
+        assert_eq!(illegal_items[0].item.id, "minecraft:barrier");
+        assert_eq!(
+            illegal_items[0].path,
+            vec![ContentSlot {
+                container: "minecraft:shulker_box".to_string(),
+                slot: 3,
+            }]
+        );
+        assert_eq!(illegal_items[0].violations, vec![Violation::Unobtainable]);
+    }
+
+    #[test]
+    fn test_write_report() {
+        let items = [
+            Item {
+                id: "minecraft:stone".to_string(),
+                count: 127,
+                tag: None,
+                components: None,
+            },
+            Item {
+                id: "minecraft:debug_stick".to_string(),
+                count: 1,
+                tag: None,
+                components: None,
+            },
+        ];
+        let mut illegal_items = Vec::new();
+        for item in &items {
+            check_item(
+                &IllegalItemRules::default(),
+                "minecraft:zombie at x:1 y:64 z:-3",
+                item,
+                &mut illegal_items,
+            );
+        }
+        let mut out = Vec::new();
+        write_report(&illegal_items, &mut out).expect("Error writing report");
+        assert_eq!(
+            String::from_utf8(out).expect("Invalid utf-8"),
+            "minecraft:zombie at x:1 y:64 z:-3: minecraft:stone x127
+  stack of 127 exceeds the maximum stack size of 64
+minecraft:zombie at x:1 y:64 z:-3: minecraft:debug_stick x1
+  unobtainable item
+Found 2 illegal items
+"
+        );
+    }
+
+    #[test]
+    fn test_write_report_nested() {
+        let illegal_items = [IllegalItem {
+            location: "player 0000 ender chest".to_string(),
+            path: vec![ContentSlot {
+                container: "minecraft:shulker_box".to_string(),
+                slot: 0,
+            }],
+            item: Item {
+                id: "minecraft:bedrock".to_string(),
+                count: -1,
+                tag: None,
+                components: None,
+            },
+            violations: vec![Violation::Unobtainable, Violation::InvalidCount(-1)],
+        }];
+        let mut out = Vec::new();
+        write_report(&illegal_items, &mut out).expect("Error writing report");
+        assert_eq!(
+            String::from_utf8(out).expect("Invalid utf-8"),
+            "player 0000 ender chest > minecraft:shulker_box slot 0: minecraft:bedrock x-1
+  unobtainable item
+  invalid count -1
+Found 1 illegal items
+"
+        );
+    }
+}
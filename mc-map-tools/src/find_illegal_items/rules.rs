@@ -0,0 +1,385 @@
+use std::{collections::HashMap, fmt::Display, io::Read};
+
+use mc_map_reader::{
+    data::item::{Enchantment, Item},
+    nbt::Tag,
+};
+use serde::Deserialize;
+
+use crate::wildcard::Wildcard;
+
+/// Rules describing items no survival player can have.
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct IllegalItemRules {
+    /// Items which can not be obtained in survival mode.
+    #[serde(default)]
+    pub unobtainable_items: Vec<Wildcard>,
+    #[serde(default = "default_max_stack_size")]
+    pub default_max_stack_size: i32,
+    /// Stack sizes of items which do not use the default. The first matching entry is used.
+    #[serde(default)]
+    pub max_stack_sizes: Vec<StackSize>,
+    /// Maximum levels of enchantments by id. Unknown enchantments are not checked.
+    #[serde(default)]
+    pub max_enchantment_levels: HashMap<String, i32>,
+    /// Groups of enchantments of which an item may only have one.
+    #[serde(default)]
+    pub incompatible_enchantments: Vec<Vec<String>>,
+    /// Maximum size of the encoded item data in bytes.
+    pub max_nbt_bytes: Option<usize>,
+}
+
+/// Rules read from a rules file which are added to the default rules.
+#[derive(Debug, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    unobtainable_items: Vec<Wildcard>,
+    default_max_stack_size: Option<i32>,
+    #[serde(default)]
+    max_stack_sizes: Vec<StackSize>,
+    #[serde(default)]
+    max_enchantment_levels: HashMap<String, i32>,
+    #[serde(default)]
+    incompatible_enchantments: Vec<Vec<String>>,
+    max_nbt_bytes: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct StackSize {
+    pub items: Vec<Wildcard>,
+    pub max: i32,
+}
+
+/// A reason for an item to be illegal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    Unobtainable,
+    InvalidCount(i32),
+    StackSize { count: i32, max: i32 },
+    EnchantmentLevel { id: String, level: i32, max: i32 },
+    IncompatibleEnchantments(Vec<String>),
+    OversizedNbt { size: usize, max: usize },
+}
+
+#[inline]
+const fn default_max_stack_size() -> i32 {
+    64
+}
+
+impl IllegalItemRules {
+    /// Reads a rules file and merges it over the default rules.
+    /// Items and enchantment groups are added to the defaults, stack sizes of the file take precedence over
+    /// the default stack sizes and the remaining values replace the defaults if they are set.
+    /// Default rules can not be removed.
+    pub fn new<R>(reader: R) -> Result<Self, serde_json::Error>
+    where
+        R: Read,
+    {
+        let file: RulesFile = serde_json::from_reader(reader)?;
+        Ok(Self::default().merge(file))
+    }
+
+    fn merge(mut self, file: RulesFile) -> Self {
+        self.unobtainable_items.extend(file.unobtainable_items);
+        if let Some(default_max_stack_size) = file.default_max_stack_size {
+            self.default_max_stack_size = default_max_stack_size;
+        }
+        self.max_stack_sizes.splice(0..0, file.max_stack_sizes);
+        self.max_enchantment_levels
+            .extend(file.max_enchantment_levels);
+        self.incompatible_enchantments
+            .extend(file.incompatible_enchantments);
+        if file.max_nbt_bytes.is_some() {
+            self.max_nbt_bytes = file.max_nbt_bytes;
+        }
+        self
+    }
+
+    /// All rules the item violates. Items inside of the item are not checked.
+    pub fn check(&self, item: &Item) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self
+            .unobtainable_items
+            .iter()
+            .any(|pattern| pattern.matches(&item.id))
+        {
+            violations.push(Violation::Unobtainable);
+        }
+
+        let max_stack_size = self.max_stack_size(&item.id);
+        if item.count < 1 {
+            violations.push(Violation::InvalidCount(item.count));
+        } else if item.count > max_stack_size {
+            violations.push(Violation::StackSize {
+                count: item.count,
+                max: max_stack_size,
+            });
+        }
+
+        for enchantments in [item.enchantments(), item.stored_enchantments()] {
+            self.check_enchantments(&enchantments, &mut violations);
+        }
+
+        if let Some(max) = self.max_nbt_bytes {
+            let size = item_nbt_size(item);
+            if size > max {
+                violations.push(Violation::OversizedNbt { size, max });
+            }
+        }
+        violations
+    }
+
+    fn max_stack_size(&self, id: &str) -> i32 {
+        self.max_stack_sizes
+            .iter()
+            .find(|stack_size| stack_size.items.iter().any(|pattern| pattern.matches(id)))
+            .map(|stack_size| stack_size.max)
+            .unwrap_or(self.default_max_stack_size)
+    }
+
+    fn check_enchantments(&self, enchantments: &[Enchantment], violations: &mut Vec<Violation>) {
+        let ids = enchantments
+            .iter()
+            .map(|enchantment| namespaced(&enchantment.id))
+            .collect::<Vec<_>>();
+        for (id, enchantment) in ids.iter().zip(enchantments) {
+            let Some(&max) = self.max_enchantment_levels.get(id) else {
+                continue;
+            };
+            if enchantment.level > max {
+                violations.push(Violation::EnchantmentLevel {
+                    id: id.clone(),
+                    level: enchantment.level,
+                    max,
+                });
+            }
+        }
+        for group in &self.incompatible_enchantments {
+            let found = group
+                .iter()
+                .filter(|id| ids.contains(id))
+                .cloned()
+                .collect::<Vec<_>>();
+            if found.len() > 1 {
+                violations.push(Violation::IncompatibleEnchantments(found));
+            }
+        }
+    }
+}
+
+impl Default for IllegalItemRules {
+    fn default() -> Self {
+        serde_json::from_str(include_str!("../../default-illegal-item-rules.json"))
+            .expect("Invalid default rules")
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unobtainable => write!(f, "unobtainable item"),
+            Self::InvalidCount(count) => write!(f, "invalid count {count}"),
+            Self::StackSize { count, max } => {
+                write!(
+                    f,
+                    "stack of {count} exceeds the maximum stack size of {max}"
+                )
+            }
+            Self::EnchantmentLevel { id, level, max } => {
+                write!(f, "{id} level {level} exceeds the maximum level {max}")
+            }
+            Self::IncompatibleEnchantments(ids) => {
+                write!(f, "incompatible enchantments {}", ids.join(", "))
+            }
+            Self::OversizedNbt { size, max } => {
+                write!(f, "{size} bytes of item data exceed the maximum of {max}")
+            }
+        }
+    }
+}
+
+/// Enchantment ids saved before 1.13 may lack the namespace.
+fn namespaced(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{id}")
+    }
+}
+
+/// Encoded size of the tag or components of an item in bytes.
+fn item_nbt_size(item: &Item) -> usize {
+    [&item.tag, &item.components]
+        .into_iter()
+        .flatten()
+        .map(compound_size)
+        .sum()
+}
+
+/// Encoded size of the payload of a tag in bytes.
+fn nbt_size(tag: &Tag) -> usize {
+    match tag {
+        Tag::End => 0,
+        Tag::Byte(_) => 1,
+        Tag::Short(_) => 2,
+        Tag::Int(_) | Tag::Float(_) => 4,
+        Tag::Long(_) | Tag::Double(_) => 8,
+        Tag::ByteArray(values) => 4 + values.len(),
+        Tag::IntArray(values) => 4 + 4 * values.len(),
+        Tag::LongArray(values) => 4 + 8 * values.len(),
+        Tag::String(value) => 2 + value.len(),
+        // Element type and length
+        Tag::List(values) => 5 + values.iter().map(nbt_size).sum::<usize>(),
+        Tag::Compound(values) => compound_size(values),
+    }
+}
+
+fn compound_size(values: &HashMap<String, Tag>) -> usize {
+    // Every entry starts with its type and name, the compound ends with an end tag
+    values
+        .iter()
+        .map(|(key, value)| 3 + key.len() + nbt_size(value))
+        .sum::<usize>()
+        + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use mc_map_reader::nbt::List;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn item(id: &str, count: i32, tag: Option<HashMap<String, Tag>>) -> Item {
+        Item {
+            id: id.to_string(),
+            tag,
+            components: None,
+            count,
+        }
+    }
+
+    fn enchantments(key: &str, enchantments: &[(&str, i16)]) -> Option<HashMap<String, Tag>> {
+        let enchantments = enchantments
+            .iter()
+            .map(|(id, level)| {
+                Tag::Compound(HashMap::from_iter([
+                    ("id".to_string(), Tag::String(id.to_string())),
+                    ("lvl".to_string(), Tag::Short(*level)),
+                ]))
+            })
+            .collect::<Vec<_>>();
+        Some(HashMap::from_iter([(
+            key.to_string(),
+            Tag::List(List::from(enchantments)),
+        )]))
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let rules = IllegalItemRules::default();
+        assert_eq!(rules.default_max_stack_size, 64);
+        assert_eq!(rules.max_stack_size("minecraft:diamond_sword"), 1);
+        assert_eq!(rules.max_stack_size("minecraft:diamond_pickaxe"), 1);
+        assert_eq!(rules.max_stack_size("minecraft:bucket"), 16);
+        assert_eq!(rules.max_stack_size("minecraft:water_bucket"), 1);
+        assert_eq!(rules.max_stack_size("minecraft:oak_hanging_sign"), 16);
+        assert_eq!(rules.max_stack_size("minecraft:turtle_egg"), 64);
+        assert_eq!(rules.max_stack_size("minecraft:diamond"), 64);
+    }
+
+    #[test_case(item("minecraft:diamond", 64, None) => Vec::<Violation>::new(); "Legal")]
+    #[test_case(item("minecraft:bedrock", 1, None) => vec![Violation::Unobtainable]; "Unobtainable")]
+    #[test_case(item("minecraft:zombie_spawn_egg", 1, None) => vec![Violation::Unobtainable]; "Spawn egg")]
+    #[test_case(item("minecraft:diamond", 127, None) => vec![Violation::StackSize { count: 127, max: 64 }]; "Oversized stack")]
+    #[test_case(item("minecraft:totem_of_undying", 2, None) => vec![Violation::StackSize { count: 2, max: 1 }]; "Stacked totems")]
+    #[test_case(item("minecraft:diamond", -5, None) => vec![Violation::InvalidCount(-5)]; "Negative count")]
+    #[test_case(item("minecraft:netherite_sword", 1, enchantments("Enchantments", &[
+        ("minecraft:sharpness", 5),
+        ("minecraft:unbreaking", 3),
+        ("minecraft:mending", 1),
+    ])) => Vec::<Violation>::new(); "Legal enchantments")]
+    #[test_case(item("minecraft:netherite_sword", 1, enchantments("Enchantments", &[
+        ("minecraft:sharpness", 32767),
+    ])) => vec![Violation::EnchantmentLevel {
+        id: "minecraft:sharpness".to_string(),
+        level: 32767,
+        max: 5,
+    }]; "Enchantment level")]
+    #[test_case(item("minecraft:netherite_sword", 1, enchantments("Enchantments", &[
+        ("sharpness", 5),
+        ("smite", 5),
+    ])) => vec![Violation::IncompatibleEnchantments(vec![
+        "minecraft:sharpness".to_string(),
+        "minecraft:smite".to_string(),
+    ])]; "Incompatible enchantments")]
+    #[test_case(item("minecraft:enchanted_book", 1, enchantments("StoredEnchantments", &[
+        ("minecraft:protection", 10),
+    ])) => vec![Violation::EnchantmentLevel {
+        id: "minecraft:protection".to_string(),
+        level: 10,
+        max: 4,
+    }]; "Stored enchantment level")]
+    #[test_case(item("minecraft:paper", 1, Some(HashMap::from_iter([(
+        "data".to_string(),
+        Tag::ByteArray(vec![0; 70000].into()),
+    )]))) => vec![Violation::OversizedNbt { size: 70012, max: 65536 }]; "Oversized NBT")]
+    fn test_check(item: Item) -> Vec<Violation> {
+        IllegalItemRules::default().check(&item)
+    }
+
+    #[test_case(Tag::Byte(1) => 1; "Byte")]
+    #[test_case(Tag::String("abc".to_string()) => 5; "String")]
+    #[test_case(Tag::List(List::from(vec![Tag::Int(1), Tag::Int(2)])) => 13; "List")]
+    #[test_case(Tag::Compound(HashMap::from_iter([("a".to_string(), Tag::Long(1))])) => 13; "Compound")]
+    #[test_case(Tag::IntArray(vec![1, 2, 3].into()) => 16; "Int array")]
+    fn test_nbt_size(tag: Tag) -> usize {
+        nbt_size(&tag)
+    }
+
+    #[test]
+    fn test_rules_file() {
+        let rules = IllegalItemRules::new(
+            r#"{"unobtainable_items": ["minecraft:dirt"], "max_stack_sizes": [{"items": ["minecraft:stone"], "max": 8}]}"#
+                .as_bytes(),
+        )
+        .expect("Invalid rules");
+        assert_eq!(
+            rules.check(&item("minecraft:dirt", 1, None)),
+            vec![Violation::Unobtainable]
+        );
+        assert_eq!(
+            rules.check(&item("minecraft:stone", 9, None)),
+            vec![Violation::StackSize { count: 9, max: 8 }]
+        );
+        assert_eq!(
+            rules.check(&item("minecraft:bedrock", 1, None)),
+            vec![Violation::Unobtainable]
+        );
+        assert_eq!(
+            rules.check(&item("minecraft:diamond_sword", 2, None)),
+            vec![Violation::StackSize { count: 2, max: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_rules_file_overrides_defaults() {
+        let rules = IllegalItemRules::new(
+            r#"{"default_max_stack_size": 99, "max_stack_sizes": [{"items": ["minecraft:*_sword"], "max": 4}], "max_enchantment_levels": {"minecraft:sharpness": 10}, "max_nbt_bytes": 16}"#
+                .as_bytes(),
+        )
+        .expect("Invalid rules");
+        assert_eq!(rules.max_stack_size("minecraft:diamond"), 99);
+        assert_eq!(rules.max_stack_size("minecraft:diamond_sword"), 4);
+        assert_eq!(rules.max_stack_size("minecraft:totem_of_undying"), 1);
+        assert_eq!(
+            rules.max_enchantment_levels.get("minecraft:sharpness"),
+            Some(&10)
+        );
+        assert_eq!(
+            rules.max_enchantment_levels.get("minecraft:smite"),
+            Some(&5)
+        );
+        assert_eq!(rules.max_nbt_bytes, Some(16));
+    }
+}
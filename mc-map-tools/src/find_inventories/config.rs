@@ -1,30 +1,104 @@
-use std::path::PathBuf;
+use std::fmt::Display;
 
-use clap::{Args, ValueEnum};
+use clap::Args;
+use mc_map_reader::world;
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Args, Debug)]
 pub struct SearchEntity {
     #[arg(short, long = "entity-id")]
     pub entity_ids: Option<Vec<String>>,
-    #[arg(short, long, value_enum, default_value_t = Dimension::Overworld)]
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
     pub dimension: Dimension,
     #[arg(short, long, default_value_t = false)]
     pub block_entity: bool,
 }
 
-impl From<Dimension> for Option<PathBuf> {
-    fn from(value: Dimension) -> Self {
-        match value {
-            Dimension::End => Some(PathBuf::from("DIM1")),
-            Dimension::Nether => Some(PathBuf::from("DIM")),
-            Dimension::Overworld => None,
-        }
-    }
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+/// A dimension given on the command line.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Dimension {
     Overworld,
     Nether,
     End,
+    /// A dimension added by a data pack or mod, given by its namespaced id e.g. `custom:mining`.
+    Custom(String),
+}
+
+impl Dimension {
+    /// The namespaced id used by [`mc_map_reader::World`] to find the dimension.
+    pub fn id(&self) -> &str {
+        match self {
+            Dimension::Overworld => world::Dimension::OVERWORLD,
+            Dimension::Nether => world::Dimension::NETHER,
+            Dimension::End => world::Dimension::END,
+            Dimension::Custom(id) => id,
+        }
+    }
+}
+
+impl From<&world::Dimension> for Dimension {
+    fn from(value: &world::Dimension) -> Self {
+        parse_dimension(value.id()).unwrap_or_else(|_| Dimension::Custom(value.id().to_string()))
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dimension::Overworld => write!(f, "overworld"),
+            Dimension::Nether => write!(f, "nether"),
+            Dimension::End => write!(f, "end"),
+            Dimension::Custom(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+/// Parses `overworld`, `nether`, `end` or the namespaced id of any dimension e.g. `minecraft:the_nether`.
+pub fn parse_dimension(value: &str) -> Result<Dimension, String> {
+    match value {
+        "overworld" | world::Dimension::OVERWORLD => Ok(Dimension::Overworld),
+        "nether" | world::Dimension::NETHER => Ok(Dimension::Nether),
+        "end" | world::Dimension::END => Ok(Dimension::End),
+        _ if value
+            .split_once(':')
+            .is_some_and(|(namespace, name)| !namespace.is_empty() && !name.is_empty()) =>
+        {
+            Ok(Dimension::Custom(value.to_string()))
+        }
+        _ => Err(format!(
+            "Unknown dimension \"{value}\". Use overworld, nether, end or the id of a dimension e.g. \"namespace:name\"."
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Dimension::Overworld => "minecraft:overworld"; "Overworld")]
+    #[test_case(Dimension::Nether => "minecraft:the_nether"; "Nether")]
+    #[test_case(Dimension::End => "minecraft:the_end"; "End")]
+    #[test_case(Dimension::Custom("custom:mining".to_string()) => "custom:mining"; "Custom")]
+    fn test_dimension_id(dimension: Dimension) -> String {
+        dimension.id().to_string()
+    }
+
+    #[test_case(Dimension::Overworld => "overworld"; "Overworld")]
+    #[test_case(Dimension::Nether => "nether"; "Nether")]
+    #[test_case(Dimension::End => "end"; "End")]
+    #[test_case(Dimension::Custom("custom:mining".to_string()) => "custom:mining"; "Custom")]
+    fn test_dimension_display(dimension: Dimension) -> String {
+        dimension.to_string()
+    }
+
+    #[test_case("overworld" => Ok(Dimension::Overworld); "Overworld")]
+    #[test_case("nether" => Ok(Dimension::Nether); "Nether")]
+    #[test_case("minecraft:the_nether" => Ok(Dimension::Nether); "Nether id")]
+    #[test_case("end" => Ok(Dimension::End); "End")]
+    #[test_case("custom:mining/deep" => Ok(Dimension::Custom("custom:mining/deep".to_string())); "Custom")]
+    #[test_case("mining" => Err("Unknown dimension \"mining\". Use overworld, nether, end or the id of a dimension e.g. \"namespace:name\".".to_string()); "Missing namespace")]
+    #[test_case("custom:" => Err("Unknown dimension \"custom:\". Use overworld, nether, end or the id of a dimension e.g. \"namespace:name\".".to_string()); "Missing name")]
+    fn test_parse_dimension(value: &str) -> Result<Dimension, String> {
+        parse_dimension(value)
+    }
 }
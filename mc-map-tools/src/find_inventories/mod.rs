@@ -1,10 +1,6 @@
-use std::{
-    fs::File,
-    ops::Deref,
-    path::{Path, PathBuf},
-};
+use std::{ops::Deref, path::Path};
 
-use mc_map_reader::data::chunk::ChunkData;
+use mc_map_reader::{data::chunk::ChunkData, files::RegionKind, World};
 use wildmatch::WildMatch;
 
 use self::config::SearchEntity;
@@ -14,9 +10,10 @@ pub mod config;
 pub fn main(world_dir: &Path, args: &SearchEntity) {
     let wildcards = args.entity_ids.as_ref();
     let wildcards = compile_wildcards(wildcards.unwrap_or(&vec![String::from("*")]).as_slice());
-    let dim: Option<PathBuf> = args.dimension.into();
-    let dim = dim.as_deref();
-    let regions = mc_map_reader::files::get_region_files(world_dir, dim)
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, None)
         .expect("Could not read region directory");
 
     let search_fn = if args.block_entity {
@@ -25,9 +22,10 @@ pub fn main(world_dir: &Path, args: &SearchEntity) {
         todo!()
     };
 
-    regions.into_iter().for_each(|r| {
-        let file = File::open(r).expect("Could not open file");
-        let region = mc_map_reader::load_region(file, None).expect("Error reading file");
+    regions.into_iter().for_each(|region| {
+        let region = world
+            .load_chunk_region(dimension, region, None)
+            .expect("Error reading file");
         region
             .chunks
             .iter()
@@ -46,7 +44,7 @@ fn search_block_entity(chunk_data: &ChunkData, wildcards: &[WildMatch]) {
         .for_each(|be| println!("Found {} at x:{} y:{} z:{}", be.id, be.x, be.y, be.z))
 }
 
-fn compile_wildcards(wildcards: &[String]) -> Vec<WildMatch> {
+pub(crate) fn compile_wildcards(wildcards: &[String]) -> Vec<WildMatch> {
     wildcards
         .iter()
         .map(Deref::deref)
@@ -0,0 +1,18 @@
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct FindPois {
+    /// POI types to search for. Wildcards are supported e.g. "minecraft:*"
+    #[arg(short, long = "poi-type", default_value = mc_map_reader::data::poi::NETHER_PORTAL)]
+    pub poi_types: Vec<String>,
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+}
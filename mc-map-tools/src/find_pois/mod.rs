@@ -0,0 +1,108 @@
+use std::{io::Write, path::Path};
+
+use mc_map_reader::{data::poi::PoiChunk, files::RegionKind, BlockPos, World};
+use wildmatch::WildMatch;
+
+use crate::{area::Area, find_inventories::compile_wildcards};
+
+use self::args::FindPois;
+
+pub mod args;
+
+pub fn main(world_dir: &Path, args: &FindPois, writer: &mut dyn Write) {
+    let wildcards = compile_wildcards(args.poi_types.as_slice());
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(Area::bounding_box);
+    let regions = world
+        .region_positions(dimension, RegionKind::Poi, bounding_box.as_ref())
+        .expect("Could not read poi directory");
+    log::debug!("Found {} poi region files", regions.len());
+
+    regions.into_iter().for_each(|region| {
+        let region = world
+            .load_poi_region(dimension, region)
+            .expect("Error reading file");
+        region.chunks.iter().for_each(|chunk| {
+            search_chunk(chunk, &wildcards, args.area.as_ref(), writer)
+                .expect("Could not write result")
+        })
+    })
+}
+
+fn search_chunk(
+    chunk: &PoiChunk,
+    wildcards: &[WildMatch],
+    area: Option<&Area>,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    chunk
+        .records()
+        .filter(|record| wildcards.iter().any(|w| w.matches(&record.poi_type)))
+        .filter_map(|record| record.position().map(|pos| (record, pos)))
+        .filter(|(_, pos)| match area {
+            Some(area) => area.contains(*pos),
+            None => true,
+        })
+        .try_for_each(|(record, BlockPos { x, y, z })| {
+            writeln!(writer, "Found {} at x:{x} y:{y} z:{z}", record.poi_type)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::{
+        data::poi::{PoiChunk, PoiRecord, PoiSection},
+        nbt::{Array, List},
+    };
+    use test_case::test_case;
+
+    use super::*;
+
+    fn record(poi_type: &str, pos: [i32; 3]) -> PoiRecord {
+        PoiRecord {
+            pos: Array::from(pos.to_vec()),
+            poi_type: poi_type.to_string(),
+            free_tickets: 0,
+        }
+    }
+
+    fn chunk(records: Vec<PoiRecord>) -> PoiChunk {
+        PoiChunk {
+            data_version: Some(3465),
+            sections: HashMap::from_iter([(
+                "4".to_string(),
+                PoiSection {
+                    valid: true,
+                    records: List::from(records),
+                },
+            )]),
+        }
+    }
+
+    const AREA: Area = Area {
+        x1: 10,
+        z1: -10,
+        x2: -10,
+        z2: 10,
+    };
+
+    #[test_case(&["minecraft:nether_portal"], None => "Found minecraft:nether_portal at x:1 y:64 z:2\nFound minecraft:nether_portal at x:100 y:70 z:100\n"; "Nether portals")]
+    #[test_case(&["minecraft:nether_portal"], Some(AREA) => "Found minecraft:nether_portal at x:1 y:64 z:2\n"; "Nether portals in area")]
+    #[test_case(&["minecraft:*"], Some(AREA) => "Found minecraft:nether_portal at x:1 y:64 z:2\nFound minecraft:home at x:3 y:65 z:4\n"; "Wildcard")]
+    #[test_case(&["minecraft:lodestone"], None => ""; "No match")]
+    fn test_search_chunk(types: &[&str], area: Option<Area>) -> String {
+        let chunk = chunk(vec![
+            record("minecraft:nether_portal", [1, 64, 2]),
+            record("minecraft:home", [3, 65, 4]),
+            record("minecraft:nether_portal", [100, 70, 100]),
+        ]);
+        let types = types.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        let mut out = Vec::new();
+        search_chunk(&chunk, &compile_wildcards(&types), area.as_ref(), &mut out)
+            .expect("Error writing result");
+        String::from_utf8(out).expect("Invalid utf-8")
+    }
+}
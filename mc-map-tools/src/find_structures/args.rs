@@ -0,0 +1,12 @@
+use clap::Args;
+
+use crate::find_inventories::config::{parse_dimension, Dimension};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct FindStructures {
+    /// Structure ids to search for. Wildcards are supported e.g. "minecraft:village_*"
+    #[arg(short, long = "structure", default_value = "*")]
+    pub structures: Vec<String>,
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+}
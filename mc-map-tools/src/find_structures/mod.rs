@@ -0,0 +1,144 @@
+use std::{io::Write, path::Path};
+
+use mc_map_reader::{
+    data::{chunk::ChunkData, structure::StructureStart},
+    files::RegionKind,
+    World,
+};
+use wildmatch::WildMatch;
+
+use crate::find_inventories::compile_wildcards;
+
+use self::args::FindStructures;
+
+pub mod args;
+
+pub fn main(world_dir: &Path, args: &FindStructures, writer: &mut dyn Write) {
+    let wildcards = compile_wildcards(args.structures.as_slice());
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, None)
+        .expect("Could not read region directory");
+
+    regions.into_iter().for_each(|region| {
+        let region = world
+            .load_chunk_region(dimension, region, None)
+            .expect("Error reading file");
+        region.chunks.iter().for_each(|chunk| {
+            search_chunk(chunk, &wildcards, writer).expect("Could not write result")
+        })
+    })
+}
+
+fn search_chunk(
+    chunk: &ChunkData,
+    wildcards: &[WildMatch],
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let Some(structures) = &chunk.structures else {
+        return Ok(());
+    };
+    let mut starts = structures
+        .valid_starts()
+        .filter(|start| wildcards.iter().any(|w| w.matches(&start.id)))
+        .collect::<Vec<_>>();
+    starts.sort_by(|a, b| a.id.cmp(&b.id));
+    starts
+        .into_iter()
+        .try_for_each(|start| write_structure(chunk, start, writer))
+}
+
+fn write_structure(
+    chunk: &ChunkData,
+    start: &StructureStart,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let position = start.chunk_pos().unwrap_or(chunk.position());
+    write!(
+        writer,
+        "Found {} in chunk x:{} z:{}",
+        start.id, position.x, position.z
+    )?;
+    if let Some(bb) = start.bounding_box() {
+        write!(
+            writer,
+            " from x:{} y:{} z:{} to x:{} y:{} z:{}",
+            bb.min_x, bb.min_y, bb.min_z, bb.max_x, bb.max_y, bb.max_z
+        )?;
+    }
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::{
+        data::{
+            chunk::ChunkData,
+            structure::{StructurePiece, StructureStart, Structures},
+        },
+        nbt::{Array, List},
+    };
+    use test_case::test_case;
+
+    use super::*;
+    use crate::test_util::empty_chunk;
+
+    fn start(id: &str, children: Option<Vec<StructurePiece>>) -> StructureStart {
+        StructureStart {
+            id: id.to_string(),
+            chunk_x: Some(3),
+            chunk_z: Some(-4),
+            references: Some(0),
+            children: children.map(List::from),
+        }
+    }
+
+    fn chunk(structures: Option<Structures>) -> ChunkData {
+        ChunkData {
+            structures,
+            ..empty_chunk(3, -4)
+        }
+    }
+
+    fn structures() -> Structures {
+        Structures {
+            starts: HashMap::from_iter([
+                (
+                    "minecraft:village_plains".to_string(),
+                    start(
+                        "minecraft:village_plains",
+                        Some(vec![StructurePiece {
+                            id: "minecraft:jigsaw".to_string(),
+                            bounding_box: Array::from(vec![48, 60, -64, 70, 75, -40]),
+                        }]),
+                    ),
+                ),
+                (
+                    "minecraft:mineshaft".to_string(),
+                    start("minecraft:mineshaft", None),
+                ),
+                ("minecraft:stronghold".to_string(), start("INVALID", None)),
+            ]),
+            references: HashMap::new(),
+        }
+    }
+
+    #[test_case(&["*"], Some(structures()) => "Found minecraft:mineshaft in chunk x:3 z:-4\nFound minecraft:village_plains in chunk x:3 z:-4 from x:48 y:60 z:-64 to x:70 y:75 z:-40\n"; "All structures")]
+    #[test_case(&["minecraft:village_*"], Some(structures()) => "Found minecraft:village_plains in chunk x:3 z:-4 from x:48 y:60 z:-64 to x:70 y:75 z:-40\n"; "Villages")]
+    #[test_case(&["minecraft:stronghold"], Some(structures()) => ""; "Not generated")]
+    #[test_case(&["*"], None => ""; "Without structures")]
+    fn test_search_chunk(structures: &[&str], chunk_structures: Option<Structures>) -> String {
+        let structures = structures.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut out = Vec::new();
+        search_chunk(
+            &chunk(chunk_structures),
+            &compile_wildcards(&structures),
+            &mut out,
+        )
+        .expect("Error writing result");
+        String::from_utf8(out).expect("Invalid utf-8")
+    }
+}
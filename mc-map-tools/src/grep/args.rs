@@ -0,0 +1,99 @@
+use clap::{Args, ValueEnum};
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct Grep {
+    /// Regular expression to search for e.g. "diamonds?" or "-?\d{3,} -?\d{3,}"
+    #[arg(value_parser = parse_regex)]
+    pub pattern: String,
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// Ignore upper and lower case
+    #[arg(short, long)]
+    pub ignore_case: bool,
+    /// Only search these kinds of text. Every kind is searched by default
+    #[arg(short, long = "source", value_enum)]
+    pub sources: Vec<TextSource>,
+    /// Maximum number of matches to list
+    #[arg(short, long)]
+    pub limit: Option<usize>,
+    /// Do not search the inventories and ender chests of players
+    #[arg(long)]
+    pub skip_players: bool,
+}
+
+impl Grep {
+    /// Whether texts of the source should be searched.
+    pub fn includes(&self, source: TextSource) -> bool {
+        self.sources.is_empty() || self.sources.contains(&source)
+    }
+}
+
+/// The kinds of text which can be searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TextSource {
+    /// Lines on both sides of signs and hanging signs
+    Signs,
+    /// Title, author and pages of books in containers and lecterns
+    Books,
+    /// Custom names and lore of items
+    Items,
+    /// Custom names of containers and other blocks
+    BlockNames,
+    /// Custom names of entities
+    Entities,
+    /// Commands and names of command blocks
+    CommandBlocks,
+}
+
+fn parse_regex(value: &str) -> Result<String, String> {
+    regex::Regex::new(value)
+        .map(|_| value.to_string())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[command(flatten)]
+        grep: Grep,
+    }
+
+    #[test_case(&["grep", "diamonds?"] => Ok((String::from("diamonds?"), vec![])); "Pattern")]
+    #[test_case(&["grep", "base", "-s", "signs", "-s", "books"] => Ok((String::from("base"), vec![TextSource::Signs, TextSource::Books])); "Sources")]
+    #[test_case(&["grep", "(unclosed"] => Err(()); "Invalid regex")]
+    #[test_case(&["grep"] => Err(()); "Missing pattern")]
+    fn test_parse(args: &[&str]) -> Result<(String, Vec<TextSource>), ()> {
+        Command::try_parse_from(args)
+            .map(|command| (command.grep.pattern, command.grep.sources))
+            .map_err(|_| ())
+    }
+
+    #[test_case(&[] => true; "Default")]
+    #[test_case(&[TextSource::Signs] => true; "Included")]
+    #[test_case(&[TextSource::Books] => false; "Excluded")]
+    fn test_includes(sources: &[TextSource]) -> bool {
+        let grep = Grep {
+            pattern: String::new(),
+            dimension: Dimension::Overworld,
+            area: None,
+            ignore_case: false,
+            sources: sources.to_vec(),
+            limit: None,
+            skip_players: false,
+        };
+        grep.includes(TextSource::Signs)
+    }
+}
@@ -0,0 +1,829 @@
+use std::{collections::HashMap, io::Write, path::Path};
+
+use mc_map_reader::{
+    data::{
+        block_entity::{BlockEntity, BlockEntityType, Sign, SignText},
+        item::Item,
+        text_component::{plain_text_from_tag, TextFormat},
+    },
+    files::RegionKind,
+    nbt::Tag,
+    BlockPos, World,
+};
+use regex::{Regex, RegexBuilder};
+
+use crate::world_items;
+
+use self::args::{Grep, TextSource};
+
+pub mod args;
+
+/// A piece of text found in the world.
+#[derive(Debug, Clone, PartialEq)]
+struct Text {
+    source: TextSource,
+    /// Where the text was found e.g. `minecraft:chest > minecraft:written_book page 2`.
+    context: String,
+    text: String,
+}
+
+impl Text {
+    fn new(source: TextSource, context: String, text: String) -> Self {
+        Self {
+            source,
+            context,
+            text,
+        }
+    }
+}
+
+pub fn main(world_dir: &Path, args: &Grep, writer: &mut dyn Write) {
+    let regex = RegexBuilder::new(&args.pattern)
+        .case_insensitive(args.ignore_case)
+        .build()
+        .expect("Invalid regular expression");
+    let world = World::open(world_dir).expect("Could not open world");
+
+    let search_blocks = [
+        TextSource::Signs,
+        TextSource::Books,
+        TextSource::Items,
+        TextSource::BlockNames,
+        TextSource::CommandBlocks,
+    ]
+    .into_iter()
+    .any(|source| args.includes(source));
+    let block_texts = search_blocks
+        .then(|| block_texts(&world, args))
+        .into_iter()
+        .flatten();
+    let search_items = args.includes(TextSource::Items) || args.includes(TextSource::Books);
+    let entity_texts = (search_items || args.includes(TextSource::Entities))
+        .then(|| entity_texts(&world, args))
+        .into_iter()
+        .flatten();
+    let player_texts = (search_items && !args.skip_players)
+        .then(|| player_texts(&world))
+        .into_iter()
+        .flatten();
+
+    block_texts
+        .chain(entity_texts)
+        .chain(player_texts)
+        .filter(|(_, text)| is_match(&regex, args, text))
+        .take(args.limit.unwrap_or(usize::MAX))
+        .for_each(|(position, text)| {
+            write_text(position, &text, writer).expect("Could not write result")
+        });
+}
+
+fn is_match(regex: &Regex, args: &Grep, text: &Text) -> bool {
+    !text.text.is_empty() && args.includes(text.source) && regex.is_match(&text.text)
+}
+
+/// Texts of all block entities in the searched regions. Regions are read one at a time.
+fn block_texts<'a>(
+    world: &'a World,
+    args: &'a Grep,
+) -> impl Iterator<Item = (BlockPos, Text)> + 'a {
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, bounding_box.as_ref())
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+    regions.into_iter().flat_map(move |region| {
+        let chunks = world
+            .load_chunk_region(dimension, region, bounding_box.as_ref())
+            .expect("Error reading file")
+            .chunks;
+        chunks
+            .into_iter()
+            .flat_map(|chunk| {
+                let format = TextFormat::from_data_version(chunk.data_version);
+                chunk
+                    .block_entities
+                    .into_iter()
+                    .flatten()
+                    .map(move |block_entity| (format, block_entity))
+            })
+            .filter(|(_, block_entity)| in_area(args, block_entity.position()))
+            .flat_map(|(format, block_entity)| {
+                let position = block_entity.position();
+                block_entity_texts(&block_entity, format)
+                    .into_iter()
+                    .map(move |text| (position, text))
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Texts of all entities and the items they carry in the searched regions. Regions are read one at a time.
+fn entity_texts<'a>(
+    world: &'a World,
+    args: &'a Grep,
+) -> impl Iterator<Item = (BlockPos, Text)> + 'a {
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let regions = world
+        .region_positions(dimension, RegionKind::Entities, bounding_box.as_ref())
+        .unwrap_or_else(|e| {
+            log::warn!("Could not read entities directory: {e}");
+            Vec::new()
+        });
+    log::debug!("Found {} entity region files", regions.len());
+    regions.into_iter().flat_map(move |region| {
+        let chunks = world
+            .load_entity_region_tags(dimension, region)
+            .expect("Error reading file")
+            .chunks;
+        chunks
+            .into_iter()
+            .filter_map(|chunk| chunk.get_as_map().ok())
+            .flat_map(|mut chunk| {
+                let format = match chunk.get("DataVersion") {
+                    Some(Tag::Int(data_version)) => TextFormat::from_data_version(*data_version),
+                    _ => TextFormat::Json,
+                };
+                let entities = match chunk.remove("Entities") {
+                    Some(Tag::List(entities)) => entities.take(),
+                    _ => Vec::new(),
+                };
+                entities.into_iter().map(move |entity| (format, entity))
+            })
+            .filter_map(|(format, entity)| match entity {
+                Tag::Compound(entity) => {
+                    Some((world_items::entity_position(&entity)?, format, entity))
+                }
+                _ => None,
+            })
+            .filter(|(position, _, _)| in_area(args, *position))
+            .flat_map(|(position, format, entity)| {
+                let mut texts = Vec::new();
+                add_entity_texts(&entity, format, &mut texts);
+                texts.into_iter().map(move |text| (position, text))
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Texts of the items in the inventories and ender chests of all players at their position.
+fn player_texts(world: &World) -> impl Iterator<Item = (BlockPos, Text)> {
+    world_items::read_players(world)
+        .into_iter()
+        .filter_map(|(uuid, player)| Some((player.mob.entity.block_pos()?, uuid, player)))
+        .flat_map(|(position, uuid, player)| {
+            let format = TextFormat::from_data_version(player.data_version);
+            let mut texts = Vec::new();
+            for (inventory, items) in world_items::player_items(&player) {
+                add_slot_item_texts(
+                    &items,
+                    &format!("player {uuid} {inventory}"),
+                    format,
+                    &mut texts,
+                );
+            }
+            texts.into_iter().map(move |text| (position, text))
+        })
+}
+
+fn in_area(args: &Grep, position: BlockPos) -> bool {
+    match &args.area {
+        Some(area) => area.contains(position),
+        None => true,
+    }
+}
+
+fn write_text(position: BlockPos, text: &Text, writer: &mut dyn Write) -> std::io::Result<()> {
+    writeln!(
+        writer,
+        "{} at x:{} y:{} z:{}: {:?}",
+        text.context, position.x, position.y, position.z, text.text
+    )
+}
+
+/// All texts stored in a block entity including the items it contains.
+fn block_entity_texts(block_entity: &BlockEntity, format: TextFormat) -> Vec<Text> {
+    let id = block_entity.id.as_str();
+    let mut texts = Vec::new();
+    let custom_name = match &block_entity.entity_type {
+        BlockEntityType::Sign(sign) | BlockEntityType::HangingSign(sign) => {
+            add_sign_texts(sign, id, format, &mut texts);
+            None
+        }
+        BlockEntityType::CommandBlock(command_block) => {
+            texts.push(Text::new(
+                TextSource::CommandBlocks,
+                format!("{id} command"),
+                command_block.command.clone(),
+            ));
+            if let Some(name) = &command_block.custom_name {
+                texts.push(Text::new(
+                    TextSource::CommandBlocks,
+                    format!("{id} name"),
+                    format.plain_text(name),
+                ));
+            }
+            None
+        }
+        BlockEntityType::Banner(block) => block.custom_name.as_ref(),
+        BlockEntityType::Beacon(block) => block.custom_name.as_ref(),
+        BlockEntityType::EnchantingTable(block) => block.custom_name.as_ref(),
+        BlockEntityType::BrewingStand(block) => block.custom_name.as_ref(),
+        _ => world_items::inventory_block(block_entity)
+            .and_then(|block| block.custom_name())
+            .or_else(|| {
+                world_items::cooking_block(block_entity).and_then(|block| block.custom_name())
+            }),
+    };
+    if let Some(name) = custom_name {
+        texts.push(Text::new(
+            TextSource::BlockNames,
+            format!("{id} name"),
+            format.plain_text(name),
+        ));
+    }
+    add_slot_item_texts(
+        &world_items::block_entity_items(block_entity),
+        id,
+        format,
+        &mut texts,
+    );
+    texts
+}
+
+/// Add the texts of items stored in a block entity or a player inventory.
+/// Items with a slot are listed with their slot.
+fn add_slot_item_texts(
+    items: &[(Option<i8>, &Item)],
+    context: &str,
+    format: TextFormat,
+    texts: &mut Vec<Text>,
+) {
+    for (slot, item) in items {
+        match slot {
+            Some(slot) => add_item_texts(item, &format!("{context} slot {slot}"), format, texts),
+            None => add_item_texts(item, context, format, texts),
+        }
+    }
+}
+
+fn add_sign_texts(sign: &Sign, id: &str, format: TextFormat, texts: &mut Vec<Text>) {
+    let mut add_side = |side: &str, text: &SignText| {
+        for (line, message) in text.plain_messages(format).enumerate() {
+            texts.push(Text::new(
+                TextSource::Signs,
+                format!("{id} {side} line {}", line + 1),
+                message,
+            ));
+        }
+    };
+    add_side("front", &sign.front_text);
+    add_side("back", &sign.back_text);
+}
+
+/// Add the texts of an item and of all items inside of it.
+fn add_item_texts(item: &Item, context: &str, format: TextFormat, texts: &mut Vec<Text>) {
+    let context = format!("{context} > {}", item.id);
+    add_item_data_texts(item, &context, format, texts);
+    for (path, content) in item.walk_contents(usize::MAX).iter() {
+        // The first slot is inside of the item itself which is already part of the context
+        let context = path
+            .iter()
+            .skip(1)
+            .map(|slot| slot.container.as_str())
+            .chain([content.id.as_str()])
+            .fold(context.clone(), |context, id| format!("{context} > {id}"));
+        add_item_data_texts(content, &context, format, texts);
+    }
+}
+
+/// Add the name, lore and book contents of an item.
+fn add_item_data_texts(item: &Item, context: &str, format: TextFormat, texts: &mut Vec<Text>) {
+    if let Some(name) = item.custom_name(format) {
+        texts.push(Text::new(
+            TextSource::Items,
+            format!("{context} name"),
+            name,
+        ));
+    }
+    for (line, text) in item.lore(format).into_iter().enumerate() {
+        texts.push(Text::new(
+            TextSource::Items,
+            format!("{context} lore line {}", line + 1),
+            text,
+        ));
+    }
+    let pages = match item.written_book(format) {
+        Some(book) => {
+            texts.push(Text::new(
+                TextSource::Books,
+                format!("{context} title"),
+                book.title,
+            ));
+            texts.push(Text::new(
+                TextSource::Books,
+                format!("{context} author"),
+                book.author,
+            ));
+            book.pages
+        }
+        None => writable_book_pages(item),
+    };
+    for (page, text) in pages.into_iter().enumerate() {
+        texts.push(Text::new(
+            TextSource::Books,
+            format!("{context} page {}", page + 1),
+            text,
+        ));
+    }
+}
+
+/// Pages of a book and quill. Pages are plain text and may be filtered since 1.20.5.
+fn writable_book_pages(item: &Item) -> Vec<String> {
+    let pages = match (&item.tag, &item.components) {
+        (Some(tag), _) => tag.get("pages"),
+        (None, Some(components)) => match components.get("minecraft:writable_book_content") {
+            Some(Tag::Compound(content)) => content.get("pages"),
+            _ => None,
+        },
+        (None, None) => None,
+    };
+    let Some(Tag::List(pages)) = pages else {
+        return Vec::new();
+    };
+    pages
+        .iter()
+        .filter_map(|page| match page {
+            Tag::String(page) => Some(page.clone()),
+            Tag::Compound(page) => match page.get("raw") {
+                Some(Tag::String(page)) => Some(page.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Add the custom name and the texts of the items of an entity and of all its passengers.
+fn add_entity_texts(entity: &HashMap<String, Tag>, format: TextFormat, texts: &mut Vec<Text>) {
+    let id = match entity.get("id") {
+        Some(Tag::String(id)) => id.as_str(),
+        _ => "entity",
+    };
+    if let Some(name) = entity.get("CustomName") {
+        texts.push(Text::new(
+            TextSource::Entities,
+            format!("{id} name"),
+            plain_text_from_tag(name, format),
+        ));
+    }
+    for item in world_items::entity_items(entity) {
+        add_item_texts(&item, id, format, texts);
+    }
+    if let Some(Tag::List(passengers)) = entity.get("Passengers") {
+        for passenger in passengers.iter() {
+            if let Tag::Compound(passenger) = passenger {
+                add_entity_texts(passenger, format, texts);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mc_map_reader::{
+        data::{
+            block_entity::{Chest, CommandBlock, Lectern},
+            item::ItemWithSlot,
+        },
+        nbt::List,
+    };
+    use test_case::test_case;
+
+    use super::*;
+
+    fn block_entity(id: &str, entity_type: BlockEntityType) -> BlockEntity {
+        BlockEntity {
+            id: id.to_string(),
+            keep_packed: false,
+            x: 1,
+            y: 64,
+            z: -3,
+            entity_type,
+        }
+    }
+
+    fn compound(values: Vec<(&str, Tag)>) -> HashMap<String, Tag> {
+        values
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect()
+    }
+
+    fn string(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn book() -> Item {
+        Item {
+            id: "minecraft:written_book".to_string(),
+            tag: Some(compound(vec![
+                ("title", string("Coords")),
+                ("author", string("Steve")),
+                (
+                    "pages",
+                    Tag::List(List::from(vec![
+                        string(r#"{"text":"Base at 100 -200"}"#),
+                        string(r#""Bring diamonds""#),
+                    ])),
+                ),
+            ])),
+            components: None,
+            count: 1,
+        }
+    }
+
+    fn texts(texts: Vec<Text>) -> Vec<(TextSource, String, String)> {
+        texts
+            .into_iter()
+            .map(|text| (text.source, text.context, text.text))
+            .collect()
+    }
+
+    fn text(source: TextSource, context: &str, text: &str) -> (TextSource, String, String) {
+        (source, context.to_string(), text.to_string())
+    }
+
+    #[test]
+    fn test_sign_texts() {
+        let sign = Sign {
+            back_text: SignText::default(),
+            front_text: SignText {
+                messages: List::from(vec![
+                    string("Hello"),
+                    string(""),
+                    string("[100, 64, -200]"),
+                    string("World"),
+                ]),
+                ..SignText::default()
+            },
+            is_waxed: false,
+        };
+        let found = texts(block_entity_texts(
+            &block_entity("minecraft:sign", BlockEntityType::Sign(sign)),
+            TextFormat::Nbt,
+        ));
+        assert_eq!(found.len(), 8);
+        assert_eq!(
+            found[0],
+            text(TextSource::Signs, "minecraft:sign front line 1", "Hello")
+        );
+        assert_eq!(
+            found[2],
+            text(
+                TextSource::Signs,
+                "minecraft:sign front line 3",
+                "[100, 64, -200]"
+            )
+        );
+        assert_eq!(
+            found[3],
+            text(TextSource::Signs, "minecraft:sign front line 4", "World")
+        );
+    }
+
+    #[test]
+    fn test_container_texts() {
+        let shulker_box = Item {
+            id: "minecraft:shulker_box".to_string(),
+            tag: Some(compound(vec![
+                (
+                    "display",
+                    Tag::Compound(compound(vec![
+                        ("Name", string(r#"{"text":"Loot"}"#)),
+                        (
+                            "Lore",
+                            Tag::List(List::from(vec![string(r#""Do not open""#)])),
+                        ),
+                    ])),
+                ),
+                (
+                    "BlockEntityTag",
+                    Tag::Compound(compound(vec![(
+                        "Items",
+                        Tag::List(List::from(vec![Tag::Compound(compound(vec![
+                            ("id", string("minecraft:writable_book")),
+                            ("Slot", Tag::Byte(0)),
+                            ("Count", Tag::Byte(1)),
+                            (
+                                "tag",
+                                Tag::Compound(compound(vec![(
+                                    "pages",
+                                    Tag::List(List::from(vec![string("secret")])),
+                                )])),
+                            ),
+                        ]))])),
+                    )])),
+                ),
+            ])),
+            components: None,
+            count: 1,
+        };
+        let chest = Chest {
+            custom_name: Some(r#"{"text":"Stash"}"#.to_string()),
+            items: Some(List::from(vec![ItemWithSlot {
+                slot: 4,
+                item: shulker_box,
+            }])),
+            lock: None,
+            loot_table: None,
+            loot_table_seed: None,
+        };
+        let found = texts(block_entity_texts(
+            &block_entity("minecraft:chest", BlockEntityType::Chest(chest)),
+            TextFormat::Json,
+        ));
+        assert_eq!(
+            found,
+            vec![
+                text(TextSource::BlockNames, "minecraft:chest name", "Stash"),
+                text(
+                    TextSource::Items,
+                    "minecraft:chest slot 4 > minecraft:shulker_box name",
+                    "Loot"
+                ),
+                text(
+                    TextSource::Items,
+                    "minecraft:chest slot 4 > minecraft:shulker_box lore line 1",
+                    "Do not open"
+                ),
+                text(
+                    TextSource::Books,
+                    "minecraft:chest slot 4 > minecraft:shulker_box > minecraft:writable_book page 1",
+                    "secret"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lectern_texts() {
+        let lectern = Lectern {
+            book: Some(book()),
+            page: Some(0),
+        };
+        let found = texts(block_entity_texts(
+            &block_entity("minecraft:lectern", BlockEntityType::Lectern(lectern)),
+            TextFormat::Json,
+        ));
+        assert_eq!(
+            found,
+            vec![
+                text(
+                    TextSource::Books,
+                    "minecraft:lectern > minecraft:written_book title",
+                    "Coords"
+                ),
+                text(
+                    TextSource::Books,
+                    "minecraft:lectern > minecraft:written_book author",
+                    "Steve"
+                ),
+                text(
+                    TextSource::Books,
+                    "minecraft:lectern > minecraft:written_book page 1",
+                    "Base at 100 -200"
+                ),
+                text(
+                    TextSource::Books,
+                    "minecraft:lectern > minecraft:written_book page 2",
+                    "Bring diamonds"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_component_item_texts() {
+        let book = Item {
+            id: "minecraft:writable_book".to_string(),
+            tag: None,
+            components: Some(compound(vec![(
+                "minecraft:writable_book_content",
+                Tag::Compound(compound(vec![(
+                    "pages",
+                    Tag::List(List::from(vec![Tag::Compound(compound(vec![(
+                        "raw",
+                        string("secret"),
+                    )]))])),
+                )])),
+            )])),
+            count: 1,
+        };
+        let shulker_box = Item {
+            id: "minecraft:shulker_box".to_string(),
+            tag: None,
+            components: Some(compound(vec![
+                ("minecraft:custom_name", string(r#"{"text":"Loot"}"#)),
+                (
+                    "minecraft:container",
+                    Tag::List(List::from(vec![Tag::Compound(compound(vec![
+                        ("slot", Tag::Int(0)),
+                        (
+                            "item",
+                            Tag::Compound(compound(vec![
+                                ("id", string(&book.id)),
+                                ("count", Tag::Int(1)),
+                                (
+                                    "components",
+                                    Tag::Compound(book.components.clone().unwrap_or_default()),
+                                ),
+                            ])),
+                        ),
+                    ]))])),
+                ),
+            ])),
+            count: 1,
+        };
+        let mut found = Vec::new();
+        add_item_texts(
+            &shulker_box,
+            "minecraft:chest slot 0",
+            TextFormat::Json,
+            &mut found,
+        );
+        assert_eq!(
+            texts(found),
+            vec![
+                text(
+                    TextSource::Items,
+                    "minecraft:chest slot 0 > minecraft:shulker_box name",
+                    "Loot"
+                ),
+                text(
+                    TextSource::Books,
+                    "minecraft:chest slot 0 > minecraft:shulker_box > minecraft:writable_book page 1",
+                    "secret"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_block_texts() {
+        let command_block = CommandBlock {
+            auto: false,
+            command: "tp @a 0 64 0".to_string(),
+            condition_met: false,
+            custom_name: Some(r#"{"text":"Spawn"}"#.to_string()),
+            last_execution: 0,
+            last_output: String::new(),
+            powered: false,
+            success_count: 0,
+            track_output: false,
+            update_last_execution: false,
+        };
+        let found = texts(block_entity_texts(
+            &block_entity(
+                "minecraft:command_block",
+                BlockEntityType::CommandBlock(command_block),
+            ),
+            TextFormat::Json,
+        ));
+        assert_eq!(
+            found,
+            vec![
+                text(
+                    TextSource::CommandBlocks,
+                    "minecraft:command_block command",
+                    "tp @a 0 64 0"
+                ),
+                text(
+                    TextSource::CommandBlocks,
+                    "minecraft:command_block name",
+                    "Spawn"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entity_texts() {
+        let entity = compound(vec![
+            ("id", string("minecraft:boat")),
+            (
+                "Passengers",
+                Tag::List(List::from(vec![Tag::Compound(compound(vec![
+                    ("id", string("minecraft:villager")),
+                    ("CustomName", string(r#"{"text":"Bob"}"#)),
+                ]))])),
+            ),
+        ]);
+        let mut found = Vec::new();
+        add_entity_texts(&entity, TextFormat::Json, &mut found);
+        assert_eq!(
+            texts(found),
+            vec![text(TextSource::Entities, "minecraft:villager name", "Bob")]
+        );
+    }
+
+    #[test]
+    fn test_entity_item_texts() {
+        let named_item = |id: &str, name: &str| {
+            Tag::Compound(compound(vec![
+                ("id", string(id)),
+                ("count", Tag::Int(1)),
+                (
+                    "components",
+                    Tag::Compound(compound(vec![("minecraft:custom_name", string(name))])),
+                ),
+            ]))
+        };
+        let minecart = compound(vec![
+            ("id", string("minecraft:chest_minecart")),
+            (
+                "Items",
+                Tag::List(List::from(vec![named_item(
+                    "minecraft:diamond",
+                    "[100, 64, -200]",
+                )])),
+            ),
+        ]);
+        let armor_stand = compound(vec![
+            ("id", string("minecraft:armor_stand")),
+            (
+                "equipment",
+                Tag::Compound(compound(vec![(
+                    "mainhand",
+                    named_item("minecraft:diamond_sword", "Excalibur"),
+                )])),
+            ),
+        ]);
+        let mut found = Vec::new();
+        add_entity_texts(&minecart, TextFormat::Nbt, &mut found);
+        add_entity_texts(&armor_stand, TextFormat::Nbt, &mut found);
+        assert_eq!(
+            texts(found),
+            vec![
+                text(
+                    TextSource::Items,
+                    "minecraft:chest_minecart > minecraft:diamond name",
+                    "[100, 64, -200]"
+                ),
+                text(
+                    TextSource::Items,
+                    "minecraft:armor_stand > minecraft:diamond_sword name",
+                    "Excalibur"
+                ),
+            ]
+        );
+    }
+
+    #[test_case("diamond", false, TextSource::Books, "Bring diamonds" => true; "Match")]
+    #[test_case("DIAMOND", false, TextSource::Books, "Bring diamonds" => false; "Case sensitive")]
+    #[test_case("DIAMOND", true, TextSource::Books, "Bring diamonds" => true; "Ignore case")]
+    #[test_case("diamond", false, TextSource::Signs, "Bring diamonds" => false; "Excluded source")]
+    #[test_case(".*", false, TextSource::Books, "" => false; "Empty text")]
+    fn test_is_match(pattern: &str, ignore_case: bool, source: TextSource, value: &str) -> bool {
+        let args = Grep {
+            pattern: pattern.to_string(),
+            dimension: crate::find_inventories::config::Dimension::Overworld,
+            area: None,
+            ignore_case,
+            sources: vec![TextSource::Books],
+            limit: None,
+            skip_players: false,
+        };
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(ignore_case)
+            .build()
+            .expect("Invalid regex");
+        is_match(
+            &regex,
+            &args,
+            &Text::new(source, String::new(), value.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_write_text() {
+        let mut out = Vec::new();
+        write_text(
+            BlockPos::new(1, 64, -3),
+            &Text::new(
+                TextSource::Signs,
+                "minecraft:sign front line 1".to_string(),
+                "Base \"here\"".to_string(),
+            ),
+            &mut out,
+        )
+        .expect("Error writing text");
+        assert_eq!(
+            String::from_utf8(out).expect("Invalid utf-8"),
+            "minecraft:sign front line 1 at x:1 y:64 z:-3: \"Base \\\"here\\\"\"\n"
+        );
+    }
+}
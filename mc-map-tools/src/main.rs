@@ -9,20 +9,60 @@
 //! Search for stashes of duplicate items.
 //! ### FindInventories (experimental)
 //! Find inventories of a specific type.
+//! ### FindPois
+//! Find points of interest like nether portals, beds or job site blocks.
+//! ### FindStructures
+//! Find generated structures like villages, strongholds or ancient cities.
+//! ### TickReport
+//! Rank chunks by their number of scheduled block and fluid ticks.
+//! ### BlockStats
+//! Count blocks by block id for every dimension, optionally by Y level.
+//! ### FindBlocks
+//! Find blocks by id and block state.
+//! ### Render
+//! Render a top-down map of a dimension or area to PNG images.
+//! ### Coverage
+//! Report how many chunks inside of the world border are fully generated.
+//! ### Grep
+//! Search texts like signs, books and custom names with a regular expression.
+//! ### AuditCommandBlocks
+//! List command blocks and flag risky commands using a configurable rule set.
+//! ### SpawnerReport
+//! List spawners with their mobs and flag non-vanilla configurations.
+//! ### FindIllegalItems
+//! Find items in containers, entities and player inventories which break a configurable rule set.
+//! ### FindDuplicateItems
+//! Find identical copies of items which should be unique in different containers or players.
 //! ### ReadLevelDat (experimental)
 //! Read the level.dat file. This feature is currently pretty useless.
 
+mod area;
 mod arguments;
+mod audit_command_blocks;
+mod block_stats;
 mod config;
+mod coverage;
 mod file;
+mod find_blocks;
+mod find_duplicate_items;
+mod find_illegal_items;
 mod find_inventories;
+mod find_pois;
+mod find_structures;
+mod grep;
 mod paths;
 #[cfg(feature = "experimental")]
 mod read_level_dat;
+mod render;
 mod search_dupe_stashes;
+mod spawner_report;
+#[cfg(test)]
+mod test_util;
+mod tick_report;
 mod tmp_dir;
+mod wildcard;
+mod world_items;
 
-use async_std::io::ReadExt;
 use std::{fs::File, path::PathBuf};
 
 use arguments::Action;
@@ -66,17 +106,71 @@ async fn main() {
         Action::FindInventories(sub_args) => {
             find_inventories::main(args.save_directory.as_path(), &sub_args)
         }
+        Action::FindPois(sub_args) => find_pois::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::FindStructures(sub_args) => find_structures::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::TickReport(sub_args) => tick_report::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::BlockStats(sub_args) => block_stats::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::FindBlocks(sub_args) => find_blocks::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::Render(sub_args) => render::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::Coverage(sub_args) => coverage::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::Grep(sub_args) => grep::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::AuditCommandBlocks(sub_args) => audit_command_blocks::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::SpawnerReport(sub_args) => spawner_report::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::FindIllegalItems(sub_args) => find_illegal_items::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
+        Action::FindDuplicateItems(sub_args) => find_duplicate_items::main(
+            args.save_directory.as_path(),
+            &sub_args,
+            &mut std::io::stdout().lock(),
+        ),
         #[cfg(feature = "experimental")]
         Action::ReadLevelDat => read_level_dat::main(args.save_directory.as_path()),
     }
 }
 
-async fn read_file(mut region_file: async_std::fs::File) -> std::io::Result<Vec<u8>> {
-    let mut buf = Vec::default();
-    region_file.read_to_end(&mut buf).await?;
-    Ok(buf)
-}
-
 fn setup_logger(level: log::LevelFilter) {
     use simplelog::*;
     let mut logger: Vec<Box<dyn SharedLogger>> = Vec::new();
@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::{
+    area::{parse_area, Area},
+    find_inventories::config::{parse_dimension, Dimension},
+};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct Render {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// An area of blocks
+    #[arg(short, long, value_parser=parse_area)]
+    pub area: Option<Area>,
+    /// Directory the images are written to
+    #[arg(short, long, default_value = "render")]
+    pub output: PathBuf,
+    /// Also write a single image of the whole dimension or area
+    #[arg(long)]
+    pub overview: bool,
+    /// Number of blocks per pixel in the overview
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub scale: u32,
+    /// Highest Y level to render. Useful to look below the roof of the nether
+    #[arg(long, allow_negative_numbers = true)]
+    pub max_y: Option<i32>,
+}
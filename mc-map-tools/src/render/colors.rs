@@ -0,0 +1,135 @@
+//! Colors used to draw blocks. The values are based on the colors of vanilla maps.
+
+/// An RGB color.
+pub type Color = [u8; 3];
+
+/// Color of blocks without an entry in the color table.
+pub const DEFAULT_COLOR: Color = [128, 128, 128];
+
+const GRASS: Color = [127, 178, 56];
+const PLANT: Color = [0, 124, 0];
+const WOOD: Color = [143, 119, 72];
+const STONE: Color = [112, 112, 112];
+const DIRT: Color = [151, 109, 77];
+const SAND: Color = [247, 233, 163];
+const SNOW: Color = [255, 255, 255];
+const ICE: Color = [160, 160, 255];
+pub const WATER: Color = [64, 64, 255];
+
+/// Colors of blocks prefixed with a dye color e.g. `minecraft:red_wool`.
+const DYE_COLORS: [(&str, Color); 16] = [
+    ("white_", [255, 255, 255]),
+    ("orange_", [216, 127, 51]),
+    ("magenta_", [178, 76, 216]),
+    ("light_blue_", [102, 153, 216]),
+    ("yellow_", [229, 229, 51]),
+    ("lime_", [127, 204, 25]),
+    ("pink_", [242, 127, 165]),
+    ("gray_", [76, 76, 76]),
+    ("light_gray_", [153, 153, 153]),
+    ("cyan_", [76, 127, 153]),
+    ("purple_", [127, 63, 178]),
+    ("blue_", [51, 76, 178]),
+    ("brown_", [102, 76, 51]),
+    ("green_", [102, 127, 51]),
+    ("red_", [153, 51, 51]),
+    ("black_", [25, 25, 25]),
+];
+
+/// Colors of blocks by the end of their id e.g. all kinds of leaves.
+const SUFFIX_COLORS: [(&str, Color); 12] = [
+    ("_leaves", PLANT),
+    ("_log", WOOD),
+    ("_wood", WOOD),
+    ("_planks", WOOD),
+    ("_stem", [92, 25, 29]),
+    ("_hyphae", [92, 25, 29]),
+    ("_ore", STONE),
+    ("_sapling", PLANT),
+    ("_tulip", PLANT),
+    ("_mushroom", [102, 76, 51]),
+    ("_coral_block", [51, 76, 178]),
+    ("_wart_block", [153, 51, 51]),
+];
+
+/// Get the color of a block by its id. Blocks without an entry use [`DEFAULT_COLOR`].
+pub fn block_color(id: &str) -> Color {
+    let name = id.strip_prefix("minecraft:").unwrap_or(id);
+    if let Some(color) = exact_color(name) {
+        return color;
+    }
+    DYE_COLORS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .or_else(|| {
+            SUFFIX_COLORS
+                .iter()
+                .find(|(suffix, _)| name.ends_with(suffix))
+        })
+        .map_or(DEFAULT_COLOR, |(_, color)| *color)
+}
+
+fn exact_color(name: &str) -> Option<Color> {
+    let color = match name {
+        "grass_block" | "moss_block" | "moss_carpet" | "slime_block" => GRASS,
+        "short_grass" | "grass" | "tall_grass" | "fern" | "large_fern" | "vine" | "lily_pad"
+        | "sugar_cane" | "cactus" | "kelp" | "kelp_plant" | "seagrass" | "tall_seagrass"
+        | "sweet_berry_bush" | "bamboo" | "dandelion" | "poppy" | "wheat" | "carrots"
+        | "potatoes" | "beetroots" => PLANT,
+        "stone" | "cobblestone" | "mossy_cobblestone" | "andesite" | "diorite" | "granite"
+        | "stone_bricks" | "gravel" | "bedrock" | "tuff" | "calcite" | "dripstone_block"
+        | "furnace" | "smooth_stone" => STONE,
+        "deepslate" | "cobbled_deepslate" => [100, 100, 100],
+        "dirt" | "coarse_dirt" | "rooted_dirt" | "farmland" | "dirt_path" | "packed_mud" => DIRT,
+        "podzol" => [129, 86, 49],
+        "mycelium" => [111, 99, 105],
+        "mud" => [87, 92, 92],
+        "sand" | "sandstone" | "smooth_sandstone" | "end_stone" | "end_stone_bricks"
+        | "birch_planks" => SAND,
+        "red_sand" | "red_sandstone" | "terracotta" | "acacia_planks" => [216, 127, 51],
+        "clay" => [164, 168, 184],
+        "water" | "bubble_column" => WATER,
+        "lava" | "magma_block" => [255, 0, 0],
+        "snow" | "snow_block" | "powder_snow" | "quartz_block" => SNOW,
+        "ice" | "packed_ice" | "blue_ice" | "frosted_ice" => ICE,
+        "netherrack" | "nether_bricks" | "nether_wart" => [112, 2, 0],
+        "soul_sand" | "soul_soil" => [102, 76, 51],
+        "crimson_nylium" => [189, 48, 49],
+        "warped_nylium" => [22, 126, 134],
+        "basalt" | "polished_basalt" | "blackstone" | "obsidian" | "crying_obsidian"
+        | "coal_block" => [25, 25, 25],
+        "glowstone" | "shroomlight" | "gold_block" => [250, 238, 77],
+        "iron_block" => [167, 167, 167],
+        "diamond_block" => [92, 219, 213],
+        "emerald_block" => [0, 217, 58],
+        "lapis_block" => [74, 128, 255],
+        "redstone_block" | "tnt" => [255, 0, 0],
+        "purpur_block" | "purpur_pillar" | "chorus_plant" | "chorus_flower" => [178, 76, 216],
+        "oak_planks" | "chest" | "crafting_table" | "bookshelf" => WOOD,
+        "spruce_planks" => [129, 86, 49],
+        "dark_oak_planks" => [102, 76, 51],
+        "mangrove_planks" => [153, 51, 51],
+        "jungle_planks" => [151, 109, 77],
+        "pumpkin" | "carved_pumpkin" | "jack_o_lantern" => [216, 127, 51],
+        "melon" => [127, 204, 25],
+        _ => return None,
+    };
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("minecraft:grass_block" => GRASS; "Exact")]
+    #[test_case("minecraft:red_wool" => [153, 51, 51]; "Dye color")]
+    #[test_case("minecraft:light_blue_concrete" => [102, 153, 216]; "Dye color with underscore")]
+    #[test_case("minecraft:blue_ice" => ICE; "Exact before dye color")]
+    #[test_case("minecraft:jungle_leaves" => PLANT; "Suffix")]
+    #[test_case("minecraft:unknown_block" => DEFAULT_COLOR; "Unknown")]
+    #[test_case("water" => WATER; "Without namespace")]
+    fn test_block_color(id: &str) -> Color {
+        block_color(id)
+    }
+}
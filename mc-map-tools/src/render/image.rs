@@ -0,0 +1,133 @@
+use std::io::Write;
+
+/// An RGBA pixel.
+pub type Pixel = [u8; 4];
+
+/// Pixel of areas without any data.
+pub const TRANSPARENT: Pixel = [0, 0, 0, 0];
+
+/// A simple RGBA image. Pixels are stored row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<Pixel>,
+}
+
+impl Image {
+    /// Create a transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![TRANSPARENT; width as usize * height as usize],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Get a pixel. Returns `None` if the position is outside of the image.
+    pub fn get(&self, x: u32, y: u32) -> Option<Pixel> {
+        self.index(x, y).map(|index| self.pixels[index])
+    }
+
+    /// Set a pixel. Positions outside of the image are ignored.
+    pub fn set(&mut self, x: u32, y: u32, pixel: Pixel) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = pixel;
+        }
+    }
+
+    /// Draw a pixel over the existing one using its alpha channel. Positions outside of the image are ignored.
+    pub fn blend(&mut self, x: u32, y: u32, pixel: Pixel) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = blend(self.pixels[index], pixel);
+        }
+    }
+
+    /// Returns true if every pixel is transparent.
+    pub fn is_empty(&self) -> bool {
+        self.pixels.iter().all(|pixel| pixel[3] == 0)
+    }
+
+    /// Encode the image as PNG.
+    pub fn write_png(&self, writer: impl Write) -> Result<(), png::EncodingError> {
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())
+    }
+
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y as usize * self.width as usize + x as usize)
+    }
+}
+
+/// Composite `above` over `below`.
+fn blend(below: Pixel, above: Pixel) -> Pixel {
+    let alpha_above = above[3] as u32;
+    let alpha_below = below[3] as u32 * (255 - alpha_above) / 255;
+    let alpha = alpha_above + alpha_below;
+    if alpha == 0 {
+        return TRANSPARENT;
+    }
+    let mix = |above: u8, below: u8| {
+        ((above as u32 * alpha_above + below as u32 * alpha_below) / alpha) as u8
+    };
+    [
+        mix(above[0], below[0]),
+        mix(above[1], below[1]),
+        mix(above[2], below[2]),
+        alpha as u8,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn test_set_get() {
+        let mut image = Image::new(2, 3);
+        assert!(image.is_empty());
+        image.set(1, 2, [1, 2, 3, 255]);
+        image.set(2, 0, [1, 2, 3, 255]);
+        assert_eq!(image.get(1, 2), Some([1, 2, 3, 255]));
+        assert_eq!(image.get(0, 0), Some(TRANSPARENT));
+        assert_eq!(image.get(2, 0), None);
+        assert!(!image.is_empty());
+    }
+
+    #[test_case([10, 20, 30, 255], [200, 100, 0, 255] => [200, 100, 0, 255]; "Opaque")]
+    #[test_case([10, 20, 30, 255], [200, 100, 0, 0] => [10, 20, 30, 255]; "Invisible")]
+    #[test_case([0, 0, 0, 255], [255, 255, 255, 51] => [51, 51, 51, 255]; "Translucent over opaque")]
+    #[test_case(TRANSPARENT, [200, 100, 0, 128] => [200, 100, 0, 128]; "Translucent over transparent")]
+    #[test_case(TRANSPARENT, TRANSPARENT => TRANSPARENT; "Transparent")]
+    fn test_blend(below: Pixel, above: Pixel) -> Pixel {
+        blend(below, above)
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut image = Image::new(2, 1);
+        image.set(0, 0, [255, 0, 0, 255]);
+        let mut out = Vec::new();
+        image.write_png(&mut out).expect("Error encoding image");
+
+        let mut reader = png::Decoder::new(out.as_slice())
+            .read_info()
+            .expect("Error decoding image");
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).expect("Error decoding image");
+        assert_eq!((info.width, info.height), (2, 1));
+        assert_eq!(data, vec![255, 0, 0, 255, 0, 0, 0, 0]);
+    }
+}
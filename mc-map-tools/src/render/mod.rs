@@ -0,0 +1,504 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use mc_map_reader::{
+    coordinates::{BLOCKS_PER_CHUNK_AXIS, CHUNKS_PER_REGION_AXIS},
+    data::chunk::{BlockState, ChunkData, HeightmapKind, SECTION_SIZE},
+    files::RegionKind,
+    BlockPos, ChunkPos, RegionPos, World,
+};
+
+use crate::area::Area;
+
+use self::{
+    args::Render,
+    colors::Color,
+    image::{Image, Pixel},
+};
+
+pub mod args;
+pub mod colors;
+pub mod image;
+
+/// Width and height of a region in blocks. Every region is rendered to an image of this size.
+pub const REGION_SIZE: u32 = (BLOCKS_PER_CHUNK_AXIS * CHUNKS_PER_REGION_AXIS) as u32;
+/// Water deeper than this is drawn in the darkest shade.
+const MAX_WATER_DEPTH: i32 = 16;
+
+/// Brightness of blocks higher than the block north of them.
+const BRIGHTNESS_HIGHER: u16 = 255;
+/// Brightness of blocks at the same height as the block north of them.
+const BRIGHTNESS_LEVEL: u16 = 220;
+/// Brightness of blocks lower than the block north of them.
+const BRIGHTNESS_LOWER: u16 = 180;
+
+pub fn main(world_dir: &Path, args: &Render, writer: &mut dyn Write) {
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(Area::bounding_box);
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, bounding_box.as_ref())
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+
+    let tile_dir = args.output.join(args.dimension.to_string());
+    fs::create_dir_all(&tile_dir).expect("Could not create output directory");
+    let mut overview = args
+        .overview
+        .then(|| Overview::covering(args.area.as_ref(), regions.iter().copied(), args.scale))
+        .flatten();
+
+    for region in regions {
+        let tile = render_region_file(&world, dimension, region, args.area.as_ref(), args.max_y);
+        if tile.is_empty() {
+            continue;
+        }
+        let path = tile_dir.join(format!("{region}.png"));
+        write_image(&tile, &path);
+        writeln!(writer, "Rendered {region} to {}", path.display())
+            .expect("Could not write result");
+        if let Some(overview) = &mut overview {
+            overview.draw(region, &tile);
+        }
+    }
+
+    if let Some(overview) = overview {
+        let path = args.output.join(format!("{}.png", args.dimension));
+        write_image(&overview.image, &path);
+        writeln!(writer, "Rendered overview to {}", path.display())
+            .expect("Could not write result");
+    }
+}
+
+/// Load a region file and render it. Only the chunks overlapping the area are decoded.
+pub(crate) fn render_region_file(
+    world: &World,
+    dimension: &str,
+    region: RegionPos,
+    area: Option<&Area>,
+    max_y: Option<i32>,
+) -> Image {
+    let bounding_box = area.map(Area::bounding_box);
+    let chunks = world
+        .load_chunk_region(dimension, region, bounding_box.as_ref())
+        .expect("Error reading file")
+        .chunks;
+    render_region(region, &chunks, area, max_y)
+}
+
+pub(crate) fn write_image(image: &Image, path: &Path) {
+    let file = File::create(path).expect("Could not create image file");
+    image
+        .write_png(BufWriter::new(file))
+        .expect("Could not write image");
+}
+
+/// The topmost visible block of a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Column {
+    /// Y coordinate of the block.
+    height: i32,
+    color: Color,
+}
+
+/// Render the chunks of a region. Every pixel is one block column, north is at the top.
+/// Columns outside of the area and missing chunks are transparent.
+fn render_region(
+    region: RegionPos,
+    chunks: &[ChunkData],
+    area: Option<&Area>,
+    max_y: Option<i32>,
+) -> Image {
+    let origin = region.min_block(0);
+    let size = REGION_SIZE as usize;
+    let mut columns = vec![None; size * size];
+    for chunk in chunks {
+        let heightmap = chunk.heightmap(HeightmapKind::WorldSurface);
+        let chunk_origin = chunk.position().min_block(0);
+        for z in 0..SECTION_SIZE {
+            for x in 0..SECTION_SIZE {
+                let column = BlockPos::new(chunk_origin.x + x as i32, 0, chunk_origin.z + z as i32);
+                let (Ok(tile_x), Ok(tile_z)) = (
+                    usize::try_from(column.x - origin.x),
+                    usize::try_from(column.z - origin.z),
+                ) else {
+                    continue;
+                };
+                if tile_x >= size
+                    || tile_z >= size
+                    || area.is_some_and(|area| !area.contains(column))
+                {
+                    continue;
+                }
+                let top = heightmap.map(|heightmap| heightmap[z][x] - 1);
+                columns[tile_z * size + tile_x] = surface(chunk, column.x, column.z, top, max_y);
+            }
+        }
+    }
+
+    let mut image = Image::new(REGION_SIZE, REGION_SIZE);
+    for z in 0..size {
+        for x in 0..size {
+            let Some(column) = columns[z * size + x] else {
+                continue;
+            };
+            let north = z
+                .checked_sub(1)
+                .and_then(|north| columns[north * size + x])
+                .map(|north| north.height);
+            let pixel = shade(column.color, brightness(column.height, north));
+            image.set(x as u32, z as u32, pixel);
+        }
+    }
+    image
+}
+
+/// Find the topmost visible block of a column starting at `top`.
+/// Without a heightmap the search starts at the top of the highest section.
+/// With `max_y` the search starts at the first air block below it, so solid ceilings are skipped.
+/// Water is drawn darker the deeper it is.
+fn surface(
+    chunk: &ChunkData,
+    x: i32,
+    z: i32,
+    top: Option<i32>,
+    max_y: Option<i32>,
+) -> Option<Column> {
+    let section_size = SECTION_SIZE as i32;
+    let min_y = chunk.y_pos * section_size;
+    let top = top
+        .or_else(|| {
+            let section = chunk.sections.iter().map(|section| section.y).max()?;
+            Some(section as i32 * section_size + section_size - 1)
+        })?
+        .min(max_y.unwrap_or(i32::MAX));
+
+    let block_at = |y: i32| chunk.block_at(BlockPos::new(x, y, z));
+    let top = match max_y {
+        // Look through the ceiling the column is cut at, e.g. the roof of the nether
+        Some(_) => (min_y..=top)
+            .rev()
+            .find(|y| block_at(*y).is_some_and(BlockState::is_air))?,
+        None => top,
+    };
+    let (height, block) = (min_y..=top).rev().find_map(|y| {
+        block_at(y)
+            .filter(|block| !block.is_air())
+            .map(|block| (y, block))
+    })?;
+    if !is_water(block) {
+        return Some(Column {
+            height,
+            color: colors::block_color(&block.name),
+        });
+    }
+
+    let depth = (height - MAX_WATER_DEPTH + 1..height)
+        .rev()
+        .take_while(|y| *y >= min_y && block_at(*y).is_some_and(is_water))
+        .count() as i32
+        + 1;
+    let brightness = 255 - (depth * 100 / MAX_WATER_DEPTH) as u16;
+    let [r, g, b, _] = shade(colors::WATER, brightness);
+    Some(Column {
+        height,
+        color: [r, g, b],
+    })
+}
+
+fn is_water(block: &BlockState) -> bool {
+    block.name == "minecraft:water" || block.name == "minecraft:bubble_column"
+}
+
+/// Brightness of a column compared to the column north of it. This makes slopes visible like on vanilla maps.
+fn brightness(height: i32, north: Option<i32>) -> u16 {
+    match north {
+        Some(north) if height > north => BRIGHTNESS_HIGHER,
+        Some(north) if height < north => BRIGHTNESS_LOWER,
+        _ => BRIGHTNESS_LEVEL,
+    }
+}
+
+/// Scale a color by `brightness / 255`.
+fn shade(color: Color, brightness: u16) -> Pixel {
+    let [r, g, b] = color.map(|channel| (channel as u16 * brightness / 255) as u8);
+    [r, g, b, 255]
+}
+
+/// A single image of all rendered regions.
+pub(crate) struct Overview {
+    /// Block shown in the top left pixel.
+    min: BlockPos,
+    /// Number of blocks per pixel along each axis.
+    scale: u32,
+    image: Image,
+}
+
+impl Overview {
+    /// Create an overview of the area or, without an area, of all regions.
+    /// Returns `None` if there are no regions.
+    pub(crate) fn covering(
+        area: Option<&Area>,
+        regions: impl Iterator<Item = RegionPos>,
+        scale: u32,
+    ) -> Option<Self> {
+        let (min, max) = match area {
+            Some(area) => (
+                BlockPos::new(area.x1.min(area.x2), 0, area.z1.min(area.z2)),
+                BlockPos::new(area.x1.max(area.x2), 0, area.z1.max(area.z2)),
+            ),
+            None => regions.fold(None, |bounds: Option<(BlockPos, BlockPos)>, region| {
+                let (region_min, region_max) = (region.min_block(0), region.max_block(0));
+                Some(match bounds {
+                    Some((min, max)) => (
+                        BlockPos::new(min.x.min(region_min.x), 0, min.z.min(region_min.z)),
+                        BlockPos::new(max.x.max(region_max.x), 0, max.z.max(region_max.z)),
+                    ),
+                    None => (region_min, region_max),
+                })
+            })?,
+        };
+        let width = (max.x - min.x) as u32 / scale + 1;
+        let height = (max.z - min.z) as u32 / scale + 1;
+        Some(Self {
+            min,
+            scale,
+            image: Image::new(width, height),
+        })
+    }
+
+    pub(crate) fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// The pixel showing the block column at `x` and `z`. Every pixel of the overview shows the
+    /// top left block of the blocks it covers, so `None` is returned for all other blocks.
+    fn pixel_of(&self, x: i32, z: i32) -> Option<(u32, u32)> {
+        let scale = self.scale as i32;
+        let (offset_x, offset_z) = (x - self.min.x, z - self.min.z);
+        if offset_x < 0 || offset_z < 0 || offset_x % scale != 0 || offset_z % scale != 0 {
+            return None;
+        }
+        Some(((offset_x / scale) as u32, (offset_z / scale) as u32))
+    }
+
+    /// Copy a rendered region into the overview.
+    pub(crate) fn draw(&mut self, region: RegionPos, tile: &Image) {
+        let origin = region.min_block(0);
+        for z in 0..tile.height() {
+            for x in 0..tile.width() {
+                let (Some((overview_x, overview_z)), Some(pixel)) = (
+                    self.pixel_of(origin.x + x as i32, origin.z + z as i32),
+                    tile.get(x, z),
+                ) else {
+                    continue;
+                };
+                self.image.set(overview_x, overview_z, pixel);
+            }
+        }
+    }
+
+    /// Draw a translucent pixel over every block column of a chunk.
+    pub(crate) fn fill_chunk(&mut self, chunk: ChunkPos, pixel: Pixel) {
+        let origin = chunk.min_block(0);
+        for z in origin.z..origin.z + BLOCKS_PER_CHUNK_AXIS {
+            for x in origin.x..origin.x + BLOCKS_PER_CHUNK_AXIS {
+                if let Some((overview_x, overview_z)) = self.pixel_of(x, z) {
+                    self.image.blend(overview_x, overview_z, pixel);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mc_map_reader::{
+        data::chunk::{Heightmaps, Section, BLOCKS_PER_SECTION},
+        nbt::Array,
+    };
+    use test_case::test_case;
+
+    use super::{colors::DEFAULT_COLOR, image::TRANSPARENT, *};
+    use crate::test_util::{block_states, chunk_with_sections, floor, section};
+
+    /// Heightmap in which every column has the same height above the bottom of the chunk.
+    fn heightmaps(height: i64) -> Heightmaps {
+        let long = (0..7).fold(0, |long, i| long | height << (9 * i));
+        Heightmaps {
+            motion_blocking: None,
+            motion_blocking_no_leaves: None,
+            ocean_floor: None,
+            ocean_floor_wg: None,
+            world_surface: Some(Array::from(vec![long; 37])),
+            world_surface_wg: None,
+        }
+    }
+
+    fn chunk(sections: Vec<Section>, height_maps: Option<Heightmaps>) -> ChunkData {
+        ChunkData {
+            height_maps,
+            ..chunk_with_sections(-1, 2, sections)
+        }
+    }
+
+    fn stone_floor() -> ChunkData {
+        chunk(
+            vec![
+                section(-4, floor(&["minecraft:air", "minecraft:stone"])),
+                section(-3, block_states(&["minecraft:air"], None)),
+            ],
+            None,
+        )
+    }
+
+    fn column(height: i32, color: Color) -> Option<Column> {
+        Some(Column { height, color })
+    }
+
+    #[test_case(stone_floor(), None, None => column(-64, [112, 112, 112]); "Without heightmap")]
+    #[test_case(stone_floor(), Some(-64), None => column(-64, [112, 112, 112]); "From heightmap")]
+    #[test_case(stone_floor(), Some(-65), None => None; "Below the chunk")]
+    #[test_case(stone_floor(), None, Some(-60) => column(-64, [112, 112, 112]); "Max y above surface")]
+    #[test_case(chunk(vec![section(-4, block_states(&["minecraft:bedrock"], None))], None), None, Some(-60) => None; "No cave below max y")]
+    #[test_case(chunk(
+        vec![
+            section(-4, floor(&["minecraft:air", "minecraft:stone"])),
+            section(-3, block_states(&["minecraft:netherrack"], None)),
+        ],
+        None,
+    ), None, Some(-40) => column(-64, [112, 112, 112]); "Roof above cave")]
+    #[test_case(chunk(vec![section(-4, block_states(&["minecraft:air"], None))], None), None, None => None; "Only air")]
+    #[test_case(chunk(vec![section(-4, floor(&["minecraft:water", "minecraft:sand"]))], None), None, None => column(-49, [40, 40, 162]); "Deep water")]
+    #[test_case(chunk(vec![section(-4, floor(&["minecraft:air", "minecraft:water"]))], None), None, None => column(-64, [62, 62, 249]); "Shallow water")]
+    #[test_case(chunk(vec![section(-4, floor(&["minecraft:air", "minecraft:unknown"]))], None), None, None => column(-64, DEFAULT_COLOR); "Unknown block")]
+    fn test_surface(chunk: ChunkData, top: Option<i32>, max_y: Option<i32>) -> Option<Column> {
+        surface(&chunk, -10, 40, top, max_y)
+    }
+
+    #[test_case(10, None => BRIGHTNESS_LEVEL; "No neighbour")]
+    #[test_case(10, Some(10) => BRIGHTNESS_LEVEL; "Level")]
+    #[test_case(11, Some(10) => BRIGHTNESS_HIGHER; "Higher")]
+    #[test_case(9, Some(10) => BRIGHTNESS_LOWER; "Lower")]
+    fn test_brightness(height: i32, north: Option<i32>) -> u16 {
+        brightness(height, north)
+    }
+
+    #[test_case([255, 100, 0], 255 => [255, 100, 0, 255]; "Full brightness")]
+    #[test_case([255, 100, 0], 180 => [180, 70, 0, 255]; "Darker")]
+    fn test_shade(color: Color, brightness: u16) -> Pixel {
+        shade(color, brightness)
+    }
+
+    #[test]
+    fn test_render_region() {
+        let chunks = [chunk(
+            vec![section(-4, floor(&["minecraft:air", "minecraft:stone"]))],
+            Some(heightmaps(1)),
+        )];
+        let image = render_region(RegionPos::new(-1, 0), &chunks, None, None);
+        let stone = shade([112, 112, 112], BRIGHTNESS_LEVEL);
+        assert_eq!(image.get(496, 32), Some(stone));
+        assert_eq!(image.get(511, 47), Some(stone));
+        assert_eq!(image.get(495, 32), Some(TRANSPARENT));
+        assert_eq!(image.get(496, 48), Some(TRANSPARENT));
+    }
+
+    #[test]
+    fn test_render_region_slope() {
+        // The southern half of the chunk is one block higher
+        let mut data = vec![0; BLOCKS_PER_SECTION / 16];
+        data[..16].fill(0x1111_1111_1111_1111);
+        data[24..32].fill(0x1111_1111_1111_1111);
+        let chunks = [chunk(
+            vec![section(
+                -4,
+                block_states(&["minecraft:air", "minecraft:stone"], Some(data)),
+            )],
+            None,
+        )];
+        let image = render_region(RegionPos::new(-1, 0), &chunks, None, None);
+        assert_eq!(
+            image.get(500, 39),
+            Some(shade([112, 112, 112], BRIGHTNESS_LEVEL))
+        );
+        assert_eq!(
+            image.get(500, 40),
+            Some(shade([112, 112, 112], BRIGHTNESS_HIGHER))
+        );
+        assert_eq!(
+            image.get(500, 41),
+            Some(shade([112, 112, 112], BRIGHTNESS_LEVEL))
+        );
+    }
+
+    #[test]
+    fn test_render_region_in_area() {
+        let area = Area {
+            x1: -16,
+            z1: 32,
+            x2: -15,
+            z2: 100,
+        };
+        let image = render_region(RegionPos::new(-1, 0), &[stone_floor()], Some(&area), None);
+        assert_ne!(image.get(497, 32), Some(TRANSPARENT));
+        assert_eq!(image.get(498, 32), Some(TRANSPARENT));
+    }
+
+    #[test]
+    fn test_overview_covering_regions() {
+        let overview = Overview::covering(
+            None,
+            [RegionPos::new(-1, 0), RegionPos::new(1, 2)].into_iter(),
+            4,
+        )
+        .expect("Missing overview");
+        assert_eq!(overview.min, BlockPos::new(-512, 0, 0));
+        assert_eq!(
+            (overview.image.width(), overview.image.height()),
+            (384, 384)
+        );
+        assert!(Overview::covering(None, std::iter::empty(), 1).is_none());
+    }
+
+    #[test]
+    fn test_overview_draw() {
+        let area = Area {
+            x1: 10,
+            z1: 10,
+            x2: -10,
+            z2: -10,
+        };
+        let mut overview =
+            Overview::covering(Some(&area), std::iter::empty(), 2).expect("Missing overview");
+        assert_eq!((overview.image.width(), overview.image.height()), (11, 11));
+        let mut tile = Image::new(REGION_SIZE, REGION_SIZE);
+        tile.set(0, 0, [1, 1, 1, 255]);
+        tile.set(1, 0, [2, 2, 2, 255]);
+        tile.set(2, 0, [3, 3, 3, 255]);
+        overview.draw(RegionPos::new(0, 0), &tile);
+        assert_eq!(overview.image.get(5, 5), Some([1, 1, 1, 255]));
+        assert_eq!(overview.image.get(6, 5), Some([3, 3, 3, 255]));
+        assert_eq!(overview.image.get(4, 5), Some(TRANSPARENT));
+    }
+
+    #[test]
+    fn test_overview_fill_chunk() {
+        let area = Area {
+            x1: -16,
+            z1: 0,
+            x2: 31,
+            z2: 15,
+        };
+        let mut overview =
+            Overview::covering(Some(&area), std::iter::empty(), 4).expect("Missing overview");
+        overview.fill_chunk(ChunkPos::new(0, 0), [255, 0, 0, 255]);
+        assert_eq!(overview.image.get(3, 0), Some(TRANSPARENT));
+        assert_eq!(overview.image.get(4, 0), Some([255, 0, 0, 255]));
+        assert_eq!(overview.image.get(7, 3), Some([255, 0, 0, 255]));
+        assert_eq!(overview.image.get(8, 0), Some(TRANSPARENT));
+    }
+}
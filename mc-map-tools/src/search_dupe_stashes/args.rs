@@ -1,13 +1,24 @@
 use std::path::PathBuf;
 
+use crate::area::{parse_area, Area};
+
 #[derive(Debug, clap::Parser)]
 pub struct SearchDupeStashes {
-    /// An area of chunks
+    /// An area of blocks
     #[arg(short, long, value_parser=parse_area)]
     pub area: Option<Area>,
     /// The radius of chunks to be searched
     #[arg(default_value = "1")]
     pub radius: u32,
+    /// Write a PNG heatmap of the items of every group per chunk to this file
+    #[arg(long)]
+    pub heatmap: Option<PathBuf>,
+    /// Number of blocks per pixel in the heatmap
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub heatmap_scale: u32,
+    /// Draw the heatmap on top of a top-down render of the map
+    #[arg(long, requires = "heatmap")]
+    pub overlay: bool,
     #[command(subcommand)]
     pub mode: Option<SearchDupeStashesMode>,
 }
@@ -33,37 +44,8 @@ pub struct GrothRate {
     file_location: Option<PathBuf>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Area {
-    /// X value of first point
-    pub x1: i32,
-    /// Z value of first point
-    pub z1: i32,
-    /// X value of second point
-    pub x2: i32,
-    /// Z value of second point
-    pub z2: i32,
-}
-
-fn parse_area(value: &str) -> Result<Area, String> {
-    let Some(((x1, z1), (x2, z2))) = value
-        .split_once(';')
-        .and_then(|(pos1, pos2)| parse_point(pos1).zip(parse_point(pos2)))
-    else {
-        return Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers."));
-    };
-    Ok(Area { x1, z1, x2, z2 })
-}
-
-fn parse_point(value: &str) -> Option<(i32, i32)> {
-    value
-        .split_once(',')
-        .and_then(|(x, z)| x.parse().ok().zip(z.parse().ok()))
-}
-
 #[cfg(test)]
 mod tests {
-
     use super::*;
     use test_case::test_case;
 
@@ -75,23 +57,12 @@ mod tests {
         );
     }
 
-    #[test_case("1,2" => Some((1, 2)); "Success")]
-    #[test_case("1,2,3" => None; "Too many values")]
-    #[test_case("1" => None; "Too few values")]
-    #[test_case("a,2" => None; "First value is not a number")]
-    #[test_case("1,b" => None; "Second value is not a number")]
-    #[test_case("1," => None; "Second value is missing")]
-    #[test_case(",2" => None; "First value is missing")]
-    #[test_case("-1,2" => Some((-1, 2)); "Negative values")]
-    fn test_parse_point(v: &str) -> Option<(i32, i32)> {
-        parse_point(v)
-    }
-
-    #[test_case("1,2;3,4" => Ok(Area { x1: 1, z1: 2, x2: 3, z2: 4 }); "Success")]
-    #[test_case("1,2;3,4,5" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "Too many values")]
-    #[test_case("1,2" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "Too few values")]
-    #[test_case("a,2;3,4" => Err(String::from("Can not parse provided area. Area must be give as followed: \"<x1>,<z1>;<x2>,<z2>\". Make sure that you have no spaces and all numbers are valid integers.")); "First value of first point is not a number")]
-    fn test_parse_area(v: &str) -> Result<Area, String> {
-        parse_area(v)
+    #[test_case(&["--heatmap", "heatmap.png", "--overlay"] => true; "Overlay with heatmap")]
+    #[test_case(&["--overlay"] => false; "Overlay without heatmap")]
+    #[test_case(&["--heatmap", "heatmap.png", "--heatmap-scale", "0"] => false; "Zero scale")]
+    fn test_parse_heatmap_args(args: &[&str]) -> bool {
+        use clap::Parser;
+        SearchDupeStashes::try_parse_from(std::iter::once("search").chain(args.iter().copied()))
+            .is_ok()
     }
 }
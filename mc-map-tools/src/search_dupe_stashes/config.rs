@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
+use crate::wildcard::Wildcard;
+
 type Nbt = serde_json::value::Map<String, serde_json::Value>;
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -28,9 +30,6 @@ const fn default_multiplier() -> usize {
     1
 }
 
-#[derive(Debug, PartialEq)]
-pub struct Wildcard(wildmatch::WildMatch);
-
 impl Default for SearchDupeStashesConfig {
     fn default() -> Self {
         serde_json::from_str(include_str!(
@@ -40,22 +39,6 @@ impl Default for SearchDupeStashesConfig {
     }
 }
 
-impl From<&str> for Wildcard {
-    fn from(value: &str) -> Self {
-        Self(wildmatch::WildMatch::new(value))
-    }
-}
-
-impl<'de> Deserialize<'de> for Wildcard {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        let value = String::deserialize(deserializer)?;
-        Ok(value.as_str().into())
-    }
-}
-
 impl Group {
     pub fn matches(&self, item: &mc_map_reader::data::item::Item) -> bool {
         self.items.iter().any(|entry| entry.matches(item))
@@ -69,14 +52,15 @@ impl GroupEntry {
 
     fn matches_id(&self, item: &mc_map_reader::data::item::Item) -> bool {
         let Some(id) = &self.id else { return true };
-        id.0.matches(&item.id)
+        id.matches(&item.id)
     }
 
     fn matches_nbt(&self, item: &mc_map_reader::data::item::Item) -> bool {
         let Some(required_nbt) = &self.nbt else {
             return true;
         };
-        let item_nbt = if let Some(item_nbt) = &item.tag {
+        // Items saved since 1.20.5 are matched against their components
+        let item_nbt = if let Some(item_nbt) = item.tag.as_ref().or(item.components.as_ref()) {
             item_nbt
         } else {
             return required_nbt.is_empty();
@@ -158,7 +142,8 @@ mod tests {
 
     use crate::search_dupe_stashes::config::default_multiplier;
 
-    use super::{Group, GroupEntry, Wildcard};
+    use super::{Group, GroupEntry};
+    use crate::wildcard::Wildcard;
     use mc_map_reader::{
         data::item::Item as McItem,
         nbt::{Array, List, Tag},
@@ -177,12 +162,6 @@ mod tests {
         super::SearchDupeStashesConfig::default();
     }
 
-    #[test]
-    fn test_wildcard() {
-        let wildcard = Wildcard::from("fo*ar");
-        assert_eq!(wildcard.0, wildmatch::WildMatch::new("fo*ar"));
-    }
-
     #[test_case(Some("foo*") => true; "Does match")]
     #[test_case(Some("foo") => false; "Does not match")]
     #[test_case(None => true; "No pattern")]
@@ -196,6 +175,7 @@ mod tests {
             id: "foobar".to_string(),
             count: 1,
             tag: None,
+            components: None,
         };
         entry.matches_id(&item)
     }
@@ -205,27 +185,27 @@ mod tests {
             GroupEntry { id: Some(Wildcard::from("item")), nbt: None, multiplier: 1 }
         ],
         threshold: 1
-    }, McItem { id: String::from("item"), tag: None, count: 1 } => true; "Is Equals single")]
+    }, McItem { id: String::from("item"), tag: None, components: None, count: 1 } => true; "Is Equals single")]
     #[test_case(Group {
         items: vec![
             GroupEntry { id: Some(Wildcard::from("test")), nbt: None, multiplier: 1 },
             GroupEntry { id: Some(Wildcard::from("item")), nbt: None, multiplier: 1 }
         ],
         threshold: 1
-    }, McItem { id: String::from("item"), tag: None, count: 1 } => true; "Is Equals multiple")]
+    }, McItem { id: String::from("item"), tag: None, components: None, count: 1 } => true; "Is Equals multiple")]
     #[test_case(Group {
         items: vec![
             GroupEntry { id: Some(Wildcard::from("item2")), nbt: None, multiplier: 1 }
         ],
         threshold: 1
-    }, McItem { id: String::from("item"), tag: None, count: 1 } => false; "Is Not Equals single")]
+    }, McItem { id: String::from("item"), tag: None, components: None, count: 1 } => false; "Is Not Equals single")]
     #[test_case(Group {
         items: vec![
             GroupEntry { id: Some(Wildcard::from("test")), nbt: None, multiplier: 1 },
             GroupEntry { id: Some(Wildcard::from("item2")), nbt: None, multiplier: 1 }
         ],
         threshold: 1
-    }, McItem { id: String::from("item"), tag: None, count: 1 } => false; "Is not equals multiple")]
+    }, McItem { id: String::from("item"), tag: None, components: None, count: 1 } => false; "Is not equals multiple")]
     fn test_group_matches(group: Group, item: McItem) -> bool {
         group.matches(&item)
     }
@@ -253,6 +233,7 @@ mod tests {
             id: "foobar".to_string(),
             count: 1,
             tag: item_nbt,
+            components: None,
         };
         entry.matches_nbt(&item)
     }
@@ -277,6 +258,7 @@ mod tests {
             id: item_id.to_string(),
             count: 1,
             tag: item_nbt,
+            components: None,
         };
         entry.matches(&item)
     }
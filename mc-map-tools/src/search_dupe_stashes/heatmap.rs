@@ -0,0 +1,160 @@
+use std::{collections::HashMap, io::Write, path::Path};
+
+use mc_map_reader::{world::Dimension, BlockPos, ChunkPos, RegionPos, World};
+
+use crate::{
+    file::region_inventories::RegionInventories,
+    render::{self, image::Pixel, Overview},
+};
+
+use super::{args::SearchDupeStashes, data::RegionInventoryCache};
+
+/// Number of items of every group by chunk.
+type ChunkItems = HashMap<ChunkPos, HashMap<u64, u64>>;
+
+/// Draw the density of the item groups per chunk and write it to `path`.
+/// `thresholds` contains the threshold of every group by the hash of its name.
+pub async fn write_heatmap(
+    world: &World,
+    args: &SearchDupeStashes,
+    path: &Path,
+    regions: &[RegionPos],
+    region_cache: &RegionInventoryCache<'_>,
+    thresholds: &HashMap<u64, usize>,
+    writer: &mut dyn Write,
+) {
+    let mut chunk_items = ChunkItems::new();
+    for region in regions {
+        match region_cache.get(region.x, region.z).await {
+            Ok(inventories) => add_inventories(&mut chunk_items, &inventories),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => log::error!("Error reading region inventory file {e}"),
+        }
+    }
+
+    let Some(mut overview) = Overview::covering(
+        args.area.as_ref(),
+        regions.iter().copied(),
+        args.heatmap_scale,
+    ) else {
+        log::info!("No regions to draw a heatmap of");
+        return;
+    };
+    if args.overlay {
+        for region in regions {
+            let tile = render::render_region_file(
+                world,
+                Dimension::OVERWORLD,
+                *region,
+                args.area.as_ref(),
+                None,
+            );
+            overview.draw(*region, &tile);
+        }
+    }
+    for (chunk, items) in chunk_items.iter() {
+        let density = density(items, thresholds);
+        if density > 0.0 {
+            overview.fill_chunk(*chunk, heat_color(density));
+        }
+    }
+    render::write_image(overview.image(), path);
+    writeln!(writer, "Wrote heatmap to {}", path.display()).expect("Error writing message");
+}
+
+/// Add the items of all inventories of a region to the chunks containing them.
+fn add_inventories(chunk_items: &mut ChunkItems, inventories: &RegionInventories) {
+    for inventory in inventories.inventories.iter() {
+        let chunk = BlockPos::new(inventory.x, inventory.y, inventory.z).chunk();
+        let items = chunk_items.entry(chunk).or_default();
+        for item in inventory.items.iter() {
+            *items.entry(item.group_id).or_default() += item.count;
+        }
+    }
+}
+
+/// The highest number of items of a group in relation to the threshold of the group.
+/// A value of 1 or more means that the chunk alone reaches the threshold.
+fn density(items: &HashMap<u64, u64>, thresholds: &HashMap<u64, usize>) -> f64 {
+    items
+        .iter()
+        .filter_map(|(group, count)| {
+            let threshold = thresholds.get(group)?;
+            Some(*count as f64 / (*threshold).max(1) as f64)
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Color of a density going from a translucent green over yellow to an opaque red at the threshold.
+fn heat_color(density: f64) -> Pixel {
+    let density = density.clamp(0.0, 1.0);
+    let (red, green) = if density < 0.5 {
+        ((density * 2.0 * 255.0) as u8, 255)
+    } else {
+        (255, ((1.0 - density) * 2.0 * 255.0) as u8)
+    };
+    let alpha = (96.0 + density * 159.0) as u8;
+    [red, green, 0, alpha]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file::region_inventories::{Inventory, Item};
+    use test_case::test_case;
+
+    use super::*;
+
+    fn inventory(x: i32, z: i32, items: &[(u64, u64)]) -> Inventory {
+        Inventory {
+            x,
+            y: 64,
+            z,
+            items: items
+                .iter()
+                .map(|(group_id, count)| Item {
+                    group_id: *group_id,
+                    count: *count,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_add_inventories() {
+        let mut chunk_items = ChunkItems::new();
+        add_inventories(
+            &mut chunk_items,
+            &RegionInventories {
+                inventories: vec![
+                    inventory(0, 0, &[(1, 10), (2, 5)]),
+                    inventory(15, 15, &[(1, 20)]),
+                    inventory(-1, 0, &[(1, 1)]),
+                ],
+            },
+        );
+        assert_eq!(
+            chunk_items,
+            HashMap::from_iter([
+                (ChunkPos::new(0, 0), HashMap::from_iter([(1, 30), (2, 5)])),
+                (ChunkPos::new(-1, 0), HashMap::from_iter([(1, 1)])),
+            ])
+        );
+    }
+
+    #[test_case(&[(1, 50)] => 0.5; "Below threshold")]
+    #[test_case(&[(1, 50), (2, 30)] => 1.5; "Highest group")]
+    #[test_case(&[(3, 1000)] => 0.0; "Unknown group")]
+    #[test_case(&[] => 0.0; "No items")]
+    fn test_density(items: &[(u64, u64)]) -> f64 {
+        let thresholds = HashMap::from_iter([(1, 100), (2, 20)]);
+        density(&HashMap::from_iter(items.iter().copied()), &thresholds)
+    }
+
+    #[test_case(0.0 => [0, 255, 0, 96]; "Empty")]
+    #[test_case(0.5 => [255, 255, 0, 175]; "Half of the threshold")]
+    #[test_case(1.0 => [255, 0, 0, 255]; "Threshold")]
+    #[test_case(3.0 => [255, 0, 0, 255]; "Above threshold")]
+    fn test_heat_color(density: f64) -> Pixel {
+        heat_color(density)
+    }
+}
@@ -2,6 +2,7 @@ pub mod args;
 pub mod config;
 mod data;
 mod detection_method;
+mod heatmap;
 
 use async_std::fs::OpenOptions;
 use data::*;
@@ -13,24 +14,24 @@ use std::sync::Arc;
 use std::{collections::HashMap, path::Path};
 
 use mc_map_reader::{
-    data::{
-        block_entity::{BlockEntity, BlockEntityType, InventoryBlock, ShulkerBox},
-        chunk::ChunkData,
-        item::Item,
-    },
-    RegionLoadError,
+    data::{block_entity::BlockEntity, chunk::ChunkData, item::Item},
+    files::RegionKind,
+    world::{Dimension, WorldError},
+    BoundingBox, RegionPos, World,
 };
 
+use crate::config::Config;
 use crate::file::region_inventories::Inventory;
 use crate::file::FileItemWrite;
 use crate::search_dupe_stashes::detection_method::DetectionMethod;
 use crate::tmp_dir::TmpDir;
-use crate::{config::Config, read_file};
+use crate::world_items;
 
 use self::config::SearchDupeStashesConfig;
 
-const BLOCKS_IN_CHUNK: i32 = 16;
-const CHUNKS_IN_REGION_FILE: i32 = 32;
+/// How deep items inside of shulker boxes and bundles are counted.
+const MAX_CONTENT_DEPTH: usize = 4;
+
 type QuadTree<'a> = qutee::QuadTree<i32, &'a Inventory, ConstCap<32>>;
 
 #[derive(Debug, thiserror::Error)]
@@ -38,7 +39,7 @@ enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
-    RegionLoadError(#[from] RegionLoadError),
+    World(#[from] WorldError),
 }
 
 pub async fn main(
@@ -50,13 +51,13 @@ pub async fn main(
     let detection_method = Box::new(detection_method::Absolute::new(
         &config.search_dupe_stashes.groups,
     ));
-    let region_files = if let Some(area) = data.area {
-        mc_map_reader::files::get_regions_in_area(
-            world_dir, None, area.x1, area.z1, area.x2, area.z2,
-        )
-    } else {
-        mc_map_reader::files::get_regions(world_dir, None).expect("Could not read region directory")
-    };
+    let bounding_box = data.area.as_ref().map(crate::area::Area::bounding_box);
+    let bounding_box = bounding_box.as_ref();
+    let world = World::open(world_dir).expect("Could not open world");
+    let world = &world;
+    let region_files = world
+        .region_positions(Dimension::OVERWORLD, RegionKind::Chunks, bounding_box)
+        .expect("Could not read region directory");
     log::debug!(
         "Found {} region files {region_files:#?}",
         region_files.len()
@@ -72,7 +73,7 @@ pub async fn main(
     }
     let inventories_dir = inventories_dir.as_path();
     let regions_future = region_files.into_iter().map(|region| async move {
-        let inventories = search_inventories_in_region(region.as_path(), config).await;
+        let inventories = search_inventories_in_region(world, region, bounding_box, config);
         let inventories = match inventories {
             Ok(inventories) => inventories,
             Err(err) => {
@@ -80,18 +81,21 @@ pub async fn main(
                 return Err(err);
             }
         };
-        save_region_inventories(inventories_dir, region.x(), region.z(), inventories).await?;
-        Ok((region.x(), region.z()))
+        save_region_inventories(inventories_dir, region.x, region.z, inventories).await?;
+        Ok((region.x, region.z))
     });
     let results = futures::future::join_all(regions_future).await;
 
-    let regions = results.into_iter().filter_map(|e| match e {
-        Ok((x, z)) => Some((x, z)),
-        Err(e) => {
-            log::error!("Error while reading region file {}", e);
-            None
-        }
-    });
+    let regions = results
+        .into_iter()
+        .filter_map(|e| match e {
+            Ok((x, z)) => Some(RegionPos::new(x, z)),
+            Err(e) => {
+                log::error!("Error while reading region file {}", e);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
 
     let group_hash_lookup_table = HashMap::from_iter(config.groups.keys().map(|key| {
         let mut hasher = std::collections::hash_map::DefaultHasher::default();
@@ -102,13 +106,12 @@ pub async fn main(
     let detection_method_ref = detection_method.as_ref();
     let group_hash_lookup_table_ref = &group_hash_lookup_table;
     let region_cache_ref = &region_cache;
-    let potential_stash_locations = regions.map(|(x, z)| async move {
-        let top = z - 1;
-        let bottom = z + 1;
-        let left = x - 1;
-        let right = x + 1;
-        let regions =
-            (left..right).flat_map(|x| (top..bottom).map(move |z| region_cache_ref.get(x, z)));
+    let potential_stash_locations = regions.iter().map(|&RegionPos { x, z }| async move {
+        // The center region and its eight neighbours. The center is at index 4.
+        let top_left = RegionPos::new(x - 1, z - 1);
+        let bottom_right = RegionPos::new(x + 1, z + 1);
+        let regions = RegionPos::area(top_left, bottom_right)
+            .map(|region| region_cache_ref.get(region.x, region.z));
         let regions = futures::future::join_all(regions).await;
 
         let Some(Ok(center_region)) = regions.get(4) else {
@@ -116,11 +119,11 @@ pub async fn main(
         };
         let center_region = Arc::clone(center_region);
 
-        let top_left_coords = min_corner_block_in_chunk(left, top);
-        let bottom_right_coords = max_corner_block_in_chunk(right, bottom);
+        let min = top_left.min_block(0);
+        let max = bottom_right.max_block(0);
         let mut tree = QuadTree::new_with_const_cap(Boundary::between_points(
-            top_left_coords,
-            bottom_right_coords,
+            (min.x, min.z),
+            (max.x + 1, max.z + 1),
         ));
         regions
             .iter()
@@ -166,6 +169,24 @@ pub async fn main(
             })
         });
 
+    if let Some(path) = &data.heatmap {
+        let thresholds = HashMap::from_iter(
+            group_hash_lookup_table
+                .iter()
+                .map(|(hash, group)| (*hash, config.groups[*group].threshold)),
+        );
+        heatmap::write_heatmap(
+            world,
+            &data,
+            path,
+            &regions,
+            &region_cache,
+            &thresholds,
+            writer,
+        )
+        .await;
+    }
+
     if let Err(err) = async_std::fs::remove_dir_all(temp_dir.as_ref()).await {
         log::error!(
             "Could not remove temporary directory \"{}\": {err}",
@@ -174,22 +195,6 @@ pub async fn main(
     }
 }
 
-fn min_corner_block_in_chunk(region_x: i32, region_z: i32) -> (i32, i32) {
-    let chunk_x = region_x << 5;
-    let chunk_z = region_z << 5;
-    let block_x = chunk_x * BLOCKS_IN_CHUNK;
-    let block_z = chunk_z * BLOCKS_IN_CHUNK;
-    (block_x, block_z)
-}
-
-fn max_corner_block_in_chunk(region_x: i32, region_z: i32) -> (i32, i32) {
-    let (min_block_x, min_block_z) = min_corner_block_in_chunk(region_x, region_z);
-    (
-        min_block_x + CHUNKS_IN_REGION_FILE * BLOCKS_IN_CHUNK,
-        min_block_z + CHUNKS_IN_REGION_FILE * BLOCKS_IN_CHUNK,
-    )
-}
-
 fn collect_items_in_area(
     radius: i32,
     inventory: &Inventory,
@@ -228,23 +233,27 @@ fn collect_items_in_area(
     )
 }
 
-async fn search_inventories_in_region<'a>(
-    region: &Path,
+/// Search all inventories in a region file of the overworld.
+/// If a bounding box is given only the chunks overlapping it are decoded
+/// and only inventories inside of it are returned.
+fn search_inventories_in_region<'a>(
+    world: &World,
+    position: RegionPos,
+    bounding_box: Option<&'a BoundingBox>,
     config: &'a SearchDupeStashesConfig,
 ) -> Result<impl Iterator<Item = FoundInventory<'a>>, Error> {
-    let region = OpenOptions::new().read(true).open(region).await?;
-    let region = read_file(region).await?;
-    let region = mc_map_reader::load_region(region.as_slice(), None)?;
+    let region = world.load_chunk_region(Dimension::OVERWORLD, position, bounding_box)?;
     let inv = region
         .chunks
         .into_iter()
-        .filter_map(|c| search_inventories_in_chunk(c, config))
+        .filter_map(move |c| search_inventories_in_chunk(c, bounding_box, config))
         .flatten();
     Ok(inv)
 }
 
 fn search_inventories_in_chunk<'inventory, 'config, 'chunk>(
     chunk: ChunkData,
+    bounding_box: Option<&'config BoundingBox>,
     config: &'config SearchDupeStashesConfig,
 ) -> Option<impl Iterator<Item = FoundInventory<'inventory>>>
 where
@@ -254,47 +263,44 @@ where
     let Some(block_entities) = chunk.block_entities else {
         return None;
     };
-    let inventories = block_entities.into_iter().filter_map(|block_entity| {
-        let inventory: &dyn InventoryBlock = match &block_entity.entity_type {
-            BlockEntityType::Barrel(block) => block,
-            BlockEntityType::Chest(block) => block,
-            BlockEntityType::Dispenser(block) => block,
-            BlockEntityType::Dropper(block) => block,
-            BlockEntityType::Hopper(block) => block,
-            BlockEntityType::ShulkerBox(block) => block,
-            BlockEntityType::TrappedChest(block) => block,
-            _ => return None,
-        };
-        search_inventory_block(inventory, &block_entity, config)
-    });
+    let block_entities =
+        block_entities
+            .into_iter()
+            .filter(move |block_entity| match bounding_box {
+                Some(bounding_box) => bounding_box.contains(block_entity.position()),
+                None => true,
+            });
+    let inventories =
+        block_entities.filter_map(|block_entity| search_block_entity(&block_entity, config));
     Some(inventories)
 }
 
-fn search_inventory_block<'a, 'b>(
-    inventory: &dyn InventoryBlock,
+fn search_block_entity<'a, 'b>(
     base_entity: &BlockEntity,
     config: &'b SearchDupeStashesConfig,
 ) -> Option<FoundInventory<'a>>
 where
     'b: 'a,
 {
+    // Only containers are searched. Items in furnaces, lecterns and similar blocks are not stashed.
+    let inventory = world_items::inventory_block(base_entity)?;
     if inventory.loot_table().is_some() || inventory.loot_table_seed().is_some() {
         return None;
     }
     let x = base_entity.x;
     let z = base_entity.z;
     let y = base_entity.y;
-    let items = if let Some(items) = inventory.items() {
-        items.iter().fold(HashMap::default(), |mut item_map, item| {
-            add_item_to_map(item, &mut item_map, config);
-            if item_is_shulker_box(&item.item.id) {
-                search_subinventory(&item.item, &mut item_map, config)
-            }
+    let items = inventory
+        .items()?
+        .iter()
+        .fold(HashMap::default(), |mut item_map, item| {
+            add_item_to_map(&item.item, &mut item_map, config);
+            item.item
+                .walk_contents(MAX_CONTENT_DEPTH)
+                .iter()
+                .for_each(|(_, content)| add_item_to_map(content, &mut item_map, config));
             item_map
-        })
-    } else {
-        return None;
-    };
+        });
     log::debug!(
         "Found inventory at ({x}, {y}, {z}) with {items_len} items",
         items_len = items.len()
@@ -306,42 +312,13 @@ where
     })
 }
 
-#[inline]
-fn item_is_shulker_box(id: &str) -> bool {
-    id.starts_with("minecraft:") && id.ends_with("shulker_box")
-}
-
-fn search_subinventory<'a, 'b>(
-    item: &Item,
-    item_map: &mut HashMap<&'a str, FoundItem>,
-    config: &'b SearchDupeStashesConfig,
-) where
-    'b: 'a,
-{
-    let Some(tag) = &item.tag else {
-        return;
-    };
-    let Some(block_entity_tag) = tag.get("BlockEntityTag").cloned() else {
-        return;
-    };
-    let Ok(inventory) = ShulkerBox::try_from(block_entity_tag) else {
-        return;
-    };
-    if let Some(items) = inventory.items() {
-        items
-            .iter()
-            .for_each(|item| add_item_to_map(item, item_map, config))
-    }
-}
-
 fn add_item_to_map<'a, 'b>(
-    item: &mc_map_reader::data::item::ItemWithSlot,
+    item: &Item,
     item_map: &mut HashMap<&'a str, FoundItem>,
     config: &'b SearchDupeStashesConfig,
 ) where
     'b: 'a,
 {
-    let item = &item.item;
     config
         .groups
         .iter()
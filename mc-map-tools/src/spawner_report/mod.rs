@@ -0,0 +1,385 @@
+use std::{collections::HashMap, fmt::Display, io::Write, path::Path};
+
+use mc_map_reader::{
+    data::{
+        block_entity::{BlockEntityType, Spawner},
+        chunk::ChunkData,
+    },
+    files::RegionKind,
+    nbt::Tag,
+    BlockPos, World,
+};
+
+use self::args::SpawnerReport;
+
+pub mod args;
+
+/// Mobs spawners are generated with in dungeons, mineshafts, fortresses, strongholds and bastions.
+const NATURAL_MOBS: [&str; 7] = [
+    "minecraft:blaze",
+    "minecraft:cave_spider",
+    "minecraft:magma_cube",
+    "minecraft:silverfish",
+    "minecraft:skeleton",
+    "minecraft:spider",
+    "minecraft:zombie",
+];
+/// Dungeon floors are made of cobblestone and mossy cobblestone.
+const DUNGEON_FLOOR: [&str; 2] = ["minecraft:cobblestone", "minecraft:mossy_cobblestone"];
+/// Keys of an entity or spawn data which give a spawned mob items.
+const EQUIPMENT_KEYS: [&str; 4] = ["ArmorItems", "HandItems", "body_armor_item", "equipment"];
+
+/// Settings of generated spawners as `(name, value)` pairs.
+const VANILLA_SETTINGS: [(&str, i16); 6] = [
+    ("MaxNearbyEntities", 6),
+    ("SpawnCount", 4),
+    ("SpawnRange", 4),
+    ("RequiredPlayerRange", 16),
+    ("MinSpawnDelay", 200),
+    ("MaxSpawnDelay", 800),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+struct FoundSpawner {
+    position: BlockPos,
+    mobs: Vec<String>,
+    anomalies: Vec<Anomaly>,
+}
+
+/// A sign that a spawner was not generated with the world.
+#[derive(Debug, Clone, PartialEq)]
+enum Anomaly {
+    ModifiedSetting {
+        name: &'static str,
+        value: i16,
+        vanilla: i16,
+    },
+    NoMob,
+    UnnaturalMob(String),
+    /// Entity data besides the id. Contains the keys of the data.
+    CustomEntityData(Vec<String>),
+    Equipment,
+    OutsideStructure,
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ModifiedSetting {
+                name,
+                value,
+                vanilla,
+            } => write!(f, "modified {name}: {value} (vanilla {vanilla})"),
+            Self::NoMob => write!(
+                f,
+                "no mob: spawners without a mob are only placed in creative mode"
+            ),
+            Self::UnnaturalMob(id) => write!(
+                f,
+                "unnatural mob: {id} never spawns from generated spawners"
+            ),
+            Self::CustomEntityData(keys) => write!(f, "custom entity data: {}", keys.join(", ")),
+            Self::Equipment => write!(f, "equipment: spawned mobs carry items"),
+            Self::OutsideStructure => write!(f, "outside of dungeons and structures"),
+        }
+    }
+}
+
+pub fn main(world_dir: &Path, args: &SpawnerReport, writer: &mut dyn Write) {
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let bounding_box = args.area.as_ref().map(|area| area.bounding_box());
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, bounding_box.as_ref())
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+
+    let spawners = regions
+        .into_iter()
+        .flat_map(|region| {
+            let chunks = world
+                .load_chunk_region(dimension, region, bounding_box.as_ref())
+                .expect("Error reading file")
+                .chunks;
+            chunks
+                .iter()
+                .flat_map(chunk_spawners)
+                .filter(|spawner| match &args.area {
+                    Some(area) => area.contains(spawner.position),
+                    None => true,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    write_report(&spawners, args.flagged_only, writer).expect("Could not write result");
+}
+
+fn chunk_spawners(chunk: &ChunkData) -> Vec<FoundSpawner> {
+    let in_structure = in_structure(chunk);
+    chunk
+        .block_entities
+        .iter()
+        .flat_map(|block_entities| block_entities.iter())
+        .filter_map(|block_entity| {
+            let BlockEntityType::MobSpawner(mob_spawner) = &block_entity.entity_type else {
+                return None;
+            };
+            let position = block_entity.position();
+            let below = BlockPos::new(position.x, position.y - 1, position.z);
+            let in_dungeon = chunk
+                .block_at(below)
+                .is_some_and(|block| DUNGEON_FLOOR.contains(&block.name.as_str()));
+            Some(FoundSpawner {
+                position,
+                mobs: mobs(&mob_spawner.spawner),
+                anomalies: anomalies(&mob_spawner.spawner, in_structure || in_dungeon),
+            })
+        })
+        .collect()
+}
+
+/// Whether a structure reaches into the chunk.
+/// Bounding boxes of structures starting in other chunks are not loaded,
+/// so a spawner next to a structure counts as part of it.
+fn in_structure(chunk: &ChunkData) -> bool {
+    match &chunk.structures {
+        Some(structures) => {
+            structures.valid_starts().next().is_some()
+                || structures
+                    .references
+                    .values()
+                    .any(|references| references.iter().next().is_some())
+        }
+        None => false,
+    }
+}
+
+/// The distinct mob ids of a spawner in the order they are spawned.
+fn mobs(spawner: &Spawner) -> Vec<String> {
+    let mut mobs = Vec::new();
+    for entity in spawner.entities() {
+        if let Some(Tag::String(id)) = entity.get("id") {
+            if !mobs.contains(id) {
+                mobs.push(id.clone());
+            }
+        }
+    }
+    mobs
+}
+
+fn anomalies(spawner: &Spawner, in_structure: bool) -> Vec<Anomaly> {
+    let values = [
+        spawner.max_nearby_entities,
+        spawner.spawn_count,
+        spawner.spawn_range,
+        spawner.required_player_range,
+        spawner.min_spawn_delay,
+        spawner.max_spawn_delay,
+    ];
+    let mut anomalies = VANILLA_SETTINGS
+        .iter()
+        .zip(values)
+        .filter(|((_, vanilla), value)| vanilla != value)
+        .map(|(&(name, vanilla), value)| Anomaly::ModifiedSetting {
+            name,
+            value,
+            vanilla,
+        })
+        .collect::<Vec<_>>();
+
+    let mobs = mobs(spawner);
+    if mobs.is_empty() {
+        anomalies.push(Anomaly::NoMob);
+    }
+    anomalies.extend(
+        mobs.into_iter()
+            .filter(|id| !NATURAL_MOBS.contains(&id.as_str()))
+            .map(Anomaly::UnnaturalMob),
+    );
+
+    let mut custom_keys = spawner
+        .entities()
+        .flat_map(HashMap::keys)
+        .filter(|key| *key != "id")
+        .cloned()
+        .collect::<Vec<_>>();
+    custom_keys.sort();
+    custom_keys.dedup();
+    let has_equipment = spawner.spawn_data.contains_key("equipment")
+        || custom_keys
+            .iter()
+            .any(|key| EQUIPMENT_KEYS.contains(&key.as_str()));
+    if !custom_keys.is_empty() {
+        anomalies.push(Anomaly::CustomEntityData(custom_keys));
+    }
+    if has_equipment {
+        anomalies.push(Anomaly::Equipment);
+    }
+
+    if !in_structure {
+        anomalies.push(Anomaly::OutsideStructure);
+    }
+    anomalies
+}
+
+fn write_report(
+    spawners: &[FoundSpawner],
+    flagged_only: bool,
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    let mut flagged_count = 0;
+    for spawner in spawners {
+        if !spawner.anomalies.is_empty() {
+            flagged_count += 1;
+        } else if flagged_only {
+            continue;
+        }
+        let mobs = if spawner.mobs.is_empty() {
+            "no mob".to_string()
+        } else {
+            spawner.mobs.join(", ")
+        };
+        writeln!(
+            writer,
+            "Spawner at x:{} y:{} z:{}: {mobs}",
+            spawner.position.x, spawner.position.y, spawner.position.z
+        )?;
+        for anomaly in &spawner.anomalies {
+            writeln!(writer, "  {anomaly}")?;
+        }
+    }
+    writeln!(
+        writer,
+        "Found {} spawners, {} flagged",
+        spawners.len(),
+        flagged_count
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use mc_map_reader::{data::block_entity::PotentialSpawn, nbt::List};
+    use test_case::test_case;
+
+    use super::*;
+
+    fn entity(id: &str, extra: &[(&str, Tag)]) -> HashMap<String, Tag> {
+        let mut entity = HashMap::from_iter([("id".to_string(), Tag::String(id.to_string()))]);
+        entity.extend(
+            extra
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone())),
+        );
+        entity
+    }
+
+    fn spawn_data(entity: HashMap<String, Tag>) -> HashMap<String, Tag> {
+        HashMap::from_iter([("entity".to_string(), Tag::Compound(entity))])
+    }
+
+    fn spawner(spawn_data: HashMap<String, Tag>) -> Spawner {
+        Spawner {
+            delay: 20,
+            max_nearby_entities: 6,
+            max_spawn_delay: 800,
+            min_spawn_delay: 200,
+            required_player_range: 16,
+            spawn_count: 4,
+            spawn_data,
+            spawn_potentials: None,
+            spawn_range: 4,
+        }
+    }
+
+    #[test]
+    fn test_dungeon_spawner() {
+        let spawner = spawner(spawn_data(entity("minecraft:zombie", &[])));
+        assert_eq!(anomalies(&spawner, true), vec![]);
+        assert_eq!(anomalies(&spawner, false), vec![Anomaly::OutsideStructure]);
+    }
+
+    #[test]
+    fn test_modified_settings() {
+        let mut spawner = spawner(spawn_data(entity("minecraft:skeleton", &[])));
+        spawner.max_nearby_entities = 50;
+        spawner.spawn_count = 20;
+        assert_eq!(
+            anomalies(&spawner, true),
+            vec![
+                Anomaly::ModifiedSetting {
+                    name: "MaxNearbyEntities",
+                    value: 50,
+                    vanilla: 6
+                },
+                Anomaly::ModifiedSetting {
+                    name: "SpawnCount",
+                    value: 20,
+                    vanilla: 4
+                },
+            ]
+        );
+    }
+
+    #[test_case(HashMap::new() => vec![Anomaly::NoMob]; "No mob")]
+    #[test_case(spawn_data(entity("minecraft:creeper", &[])) => vec![Anomaly::UnnaturalMob("minecraft:creeper".to_string())]; "Unnatural mob")]
+    #[test_case(spawn_data(entity("minecraft:zombie", &[
+        ("HandItems", Tag::List(List::from(Vec::<Tag>::new()))),
+        ("Health", Tag::Float(100.0)),
+    ])) => vec![
+        Anomaly::CustomEntityData(vec!["HandItems".to_string(), "Health".to_string()]),
+        Anomaly::Equipment,
+    ]; "Custom entity data")]
+    #[test_case(HashMap::from_iter([
+        ("entity".to_string(), Tag::Compound(entity("minecraft:zombie", &[]))),
+        ("equipment".to_string(), Tag::Compound(HashMap::new())),
+    ]) => vec![Anomaly::Equipment]; "Spawn data equipment")]
+    fn test_anomalies(spawn_data: HashMap<String, Tag>) -> Vec<Anomaly> {
+        anomalies(&spawner(spawn_data), true)
+    }
+
+    #[test]
+    fn test_mobs() {
+        let mut spawner = spawner(spawn_data(entity("minecraft:zombie", &[])));
+        spawner.spawn_potentials = Some(List::from(vec![
+            PotentialSpawn {
+                weight: 1,
+                data: spawn_data(entity("minecraft:zombie", &[])),
+            },
+            PotentialSpawn {
+                weight: 1,
+                data: spawn_data(entity("minecraft:spider", &[])),
+            },
+        ]));
+        assert_eq!(mobs(&spawner), vec!["minecraft:zombie", "minecraft:spider"]);
+    }
+
+    #[test_case(false => "Spawner at x:1 y:20 z:-3: minecraft:zombie
+Spawner at x:5 y:70 z:8: no mob
+  no mob: spawners without a mob are only placed in creative mode
+  outside of dungeons and structures
+Found 2 spawners, 1 flagged
+"; "All")]
+    #[test_case(true => "Spawner at x:5 y:70 z:8: no mob
+  no mob: spawners without a mob are only placed in creative mode
+  outside of dungeons and structures
+Found 2 spawners, 1 flagged
+"; "Flagged only")]
+    fn test_write_report(flagged_only: bool) -> String {
+        let spawners = [
+            FoundSpawner {
+                position: BlockPos::new(1, 20, -3),
+                mobs: vec!["minecraft:zombie".to_string()],
+                anomalies: vec![],
+            },
+            FoundSpawner {
+                position: BlockPos::new(5, 70, 8),
+                mobs: vec![],
+                anomalies: vec![Anomaly::NoMob, Anomaly::OutsideStructure],
+            },
+        ];
+        let mut out = Vec::new();
+        write_report(&spawners, flagged_only, &mut out).expect("Error writing report");
+        String::from_utf8(out).expect("Invalid utf-8")
+    }
+}
@@ -0,0 +1,72 @@
+//! Fixtures shared by the tests of the commands.
+
+use mc_map_reader::{
+    data::chunk::{
+        Biomes, BlockState, BlockStates, ChunkData, ChunkStatus, Section, BLOCKS_PER_SECTION,
+    },
+    nbt::{Array, List},
+};
+
+/// A fully generated chunk of 1.20.1 without sections, heightmaps, structures, ticks or block entities.
+/// Chunks with specific data can be built from it using struct update syntax.
+pub fn empty_chunk(x_pos: i32, z_pos: i32) -> ChunkData {
+    ChunkData {
+        data_version: 3465,
+        x_pos,
+        y_pos: -4,
+        z_pos,
+        status: ChunkStatus::Full,
+        last_update: 0,
+        sections: List::from(vec![]),
+        height_maps: None,
+        structures: None,
+        block_ticks: None,
+        fluid_ticks: None,
+        block_entities: None,
+    }
+}
+
+/// An empty chunk containing the sections.
+pub fn chunk_with_sections(x_pos: i32, z_pos: i32, sections: Vec<Section>) -> ChunkData {
+    ChunkData {
+        sections: List::from(sections),
+        ..empty_chunk(x_pos, z_pos)
+    }
+}
+
+/// A section of plains with the block states and without light data.
+pub fn section(y: i8, block_states: BlockStates) -> Section {
+    Section {
+        y,
+        block_states,
+        biomes: Biomes {
+            palette: List::from(vec!["minecraft:plains".to_string()]),
+            data: None,
+        },
+        block_light: None,
+        sky_light: None,
+    }
+}
+
+/// Block states of blocks without properties. `data` uses four bits per block.
+pub fn block_states(palette: &[&str], data: Option<Vec<i64>>) -> BlockStates {
+    BlockStates {
+        palette: List::from(
+            palette
+                .iter()
+                .map(|name| BlockState {
+                    name: name.to_string(),
+                    properties: None,
+                })
+                .collect::<Vec<_>>(),
+        ),
+        data: data.map(Array::from),
+    }
+}
+
+/// Every block in the lowest layer is the second block of the palette, the rest is the first.
+pub fn floor(palette: &[&str]) -> BlockStates {
+    let mut data = vec![0; BLOCKS_PER_SECTION / 16];
+    data[..16].fill(0x1111_1111_1111_1111);
+    block_states(palette, Some(data))
+}
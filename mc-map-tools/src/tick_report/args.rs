@@ -0,0 +1,12 @@
+use clap::Args;
+
+use crate::find_inventories::config::{parse_dimension, Dimension};
+
+#[derive(Debug, Clone, PartialEq, Args)]
+pub struct TickReport {
+    #[arg(short, long, value_parser = parse_dimension, default_value_t = Dimension::Overworld)]
+    pub dimension: Dimension,
+    /// Maximum number of chunks in the report
+    #[arg(short, long, default_value_t = 20)]
+    pub limit: usize,
+}
@@ -0,0 +1,193 @@
+use std::{collections::HashMap, fmt::Display, io::Write, path::Path};
+
+use mc_map_reader::{data::chunk::ChunkData, files::RegionKind, World};
+
+use self::args::TickReport;
+
+pub mod args;
+
+/// Scheduled ticks of a single chunk.
+#[derive(Debug, PartialEq)]
+struct ChunkTicks {
+    x: i32,
+    z: i32,
+    block_ticks: usize,
+    fluid_ticks: usize,
+    most_common: Option<(String, usize)>,
+}
+
+pub fn main(world_dir: &Path, args: &TickReport, writer: &mut dyn Write) {
+    let world = World::open(world_dir).expect("Could not open world");
+    let dimension = args.dimension.id();
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, None)
+        .expect("Could not read region directory");
+
+    let chunks = regions
+        .into_iter()
+        .flat_map(|region| {
+            let region = world
+                .load_chunk_region(dimension, region, None)
+                .expect("Error reading file");
+            region
+                .chunks
+                .iter()
+                .filter_map(ChunkTicks::new)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    rank(chunks, args.limit)
+        .iter()
+        .try_for_each(|chunk| writeln!(writer, "{chunk}"))
+        .expect("Could not write result")
+}
+
+fn rank(mut chunks: Vec<ChunkTicks>, limit: usize) -> Vec<ChunkTicks> {
+    chunks.sort_by(|a, b| b.total().cmp(&a.total()).then((a.x, a.z).cmp(&(b.x, b.z))));
+    chunks.truncate(limit);
+    chunks
+}
+
+impl ChunkTicks {
+    /// Returns `None` if the chunk has no pending ticks.
+    fn new(chunk: &ChunkData) -> Option<Self> {
+        if chunk.pending_ticks() == 0 {
+            return None;
+        }
+        let ticks = chunk
+            .block_ticks
+            .iter()
+            .chain(chunk.fluid_ticks.iter())
+            .flat_map(|ticks| ticks.iter());
+        let counts = ticks.fold(HashMap::<&str, usize>::new(), |mut counts, tick| {
+            *counts.entry(tick.id.as_str()).or_default() += 1;
+            counts
+        });
+        let most_common = counts
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(a.0)))
+            .map(|(id, count)| (id.to_string(), count));
+        Some(Self {
+            x: chunk.x_pos,
+            z: chunk.z_pos,
+            block_ticks: chunk.block_ticks.as_ref().map_or(0, |ticks| ticks.len()),
+            fluid_ticks: chunk.fluid_ticks.as_ref().map_or(0, |ticks| ticks.len()),
+            most_common,
+        })
+    }
+
+    fn total(&self) -> usize {
+        self.block_ticks + self.fluid_ticks
+    }
+}
+
+impl Display for ChunkTicks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Chunk x:{} z:{} has {} pending ticks ({} block, {} fluid)",
+            self.x,
+            self.z,
+            self.total(),
+            self.block_ticks,
+            self.fluid_ticks
+        )?;
+        if let Some((id, count)) = &self.most_common {
+            write!(f, " most common: {id} ({count})")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mc_map_reader::{data::chunk::ScheduledTick, nbt::List};
+    use test_case::test_case;
+
+    use super::*;
+    use crate::test_util::empty_chunk;
+
+    fn ticks(ids: &[&str]) -> Option<List<ScheduledTick>> {
+        Some(List::from(
+            ids.iter()
+                .map(|id| ScheduledTick {
+                    id: id.to_string(),
+                    x: 0,
+                    y: 0,
+                    z: 0,
+                    delay: 1,
+                    priority: 0,
+                })
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    fn chunk(
+        block_ticks: Option<List<ScheduledTick>>,
+        fluid_ticks: Option<List<ScheduledTick>>,
+    ) -> ChunkData {
+        ChunkData {
+            block_ticks,
+            fluid_ticks,
+            ..empty_chunk(1, -2)
+        }
+    }
+
+    fn chunk_ticks(x: i32, z: i32, block_ticks: usize) -> ChunkTicks {
+        ChunkTicks {
+            x,
+            z,
+            block_ticks,
+            fluid_ticks: 0,
+            most_common: None,
+        }
+    }
+
+    #[test_case(chunk(None, None) => None; "No ticks")]
+    #[test_case(chunk(ticks(&[]), ticks(&[])) => None; "Empty ticks")]
+    #[test_case(chunk(ticks(&["minecraft:repeater", "minecraft:observer", "minecraft:observer"]), ticks(&["minecraft:water"])) => Some(ChunkTicks {
+        x: 1,
+        z: -2,
+        block_ticks: 3,
+        fluid_ticks: 1,
+        most_common: Some(("minecraft:observer".to_string(), 2)),
+    }); "Block and fluid ticks")]
+    fn test_chunk_ticks_new(chunk: ChunkData) -> Option<ChunkTicks> {
+        ChunkTicks::new(&chunk)
+    }
+
+    #[test]
+    fn test_rank() {
+        let ranked = rank(
+            vec![
+                chunk_ticks(0, 0, 5),
+                chunk_ticks(1, 0, 500),
+                chunk_ticks(2, 0, 50),
+                chunk_ticks(-1, 0, 50),
+            ],
+            3,
+        );
+        assert_eq!(
+            ranked,
+            vec![
+                chunk_ticks(1, 0, 500),
+                chunk_ticks(-1, 0, 50),
+                chunk_ticks(2, 0, 50)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let chunk_ticks = ChunkTicks {
+            most_common: Some(("minecraft:water".to_string(), 4000)),
+            fluid_ticks: 4000,
+            ..chunk_ticks(3, -7, 12)
+        };
+        assert_eq!(
+            chunk_ticks.to_string(),
+            "Chunk x:3 z:-7 has 4012 pending ticks (12 block, 4000 fluid) most common: minecraft:water (4000)"
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Patterns with `*` and `?` wildcards used in config and rule files.
+
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq)]
+pub struct Wildcard(wildmatch::WildMatch);
+
+impl From<&str> for Wildcard {
+    fn from(value: &str) -> Self {
+        Self(wildmatch::WildMatch::new(value))
+    }
+}
+
+impl Wildcard {
+    pub fn matches(&self, value: &str) -> bool {
+        self.0.matches(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wildcard {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(value.as_str().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wildcard;
+
+    #[test]
+    fn test_wildcard() {
+        let wildcard = Wildcard::from("fo*ar");
+        assert_eq!(wildcard.0, wildmatch::WildMatch::new("fo*ar"));
+    }
+}
@@ -0,0 +1,403 @@
+//! Items stored in containers, entities and player files of a world.
+
+use std::collections::HashMap;
+
+use mc_map_reader::{
+    data::{
+        block_entity::{BlockEntity, BlockEntityType, CookingBlockEntity, InventoryBlock},
+        file_format::player_dat::Player,
+        item::{Item, ItemWithSlot},
+        text_component::TextFormat,
+    },
+    files::RegionKind,
+    nbt::{List, Tag},
+    BlockPos, World,
+};
+
+use crate::area::Area;
+
+/// Items of a block entity or inventory with their slot if the items are stored in slots.
+pub type SlotItems<'a> = Vec<(Option<i8>, &'a Item)>;
+
+/// Keys of entities containing a single item.
+const ENTITY_ITEM_KEYS: [&str; 5] = [
+    "Item",
+    "SaddleItem",
+    "DecorItem",
+    "ArmorItem",
+    "body_armor_item",
+];
+/// Keys of entities containing a list of items.
+const ENTITY_ITEM_LIST_KEYS: [&str; 4] = ["Items", "HandItems", "ArmorItems", "Inventory"];
+
+/// Calls `visit` with the location and every item stored in the block entities and entities of a dimension
+/// together with the text format of the file the item was read from.
+/// Items of players are visited as well if `players` is set. Items inside of other items are not visited.
+pub fn for_each_item(
+    world: &World,
+    dimension: &str,
+    area: Option<&Area>,
+    players: bool,
+    visit: &mut dyn FnMut(&str, &Item, TextFormat),
+) {
+    visit_block_entities(world, dimension, area, visit);
+    visit_entities(world, dimension, area, visit);
+    if players {
+        visit_players(world, visit);
+    }
+}
+
+fn visit_block_entities(
+    world: &World,
+    dimension: &str,
+    area: Option<&Area>,
+    visit: &mut dyn FnMut(&str, &Item, TextFormat),
+) {
+    let bounding_box = area.map(Area::bounding_box);
+    let regions = world
+        .region_positions(dimension, RegionKind::Chunks, bounding_box.as_ref())
+        .expect("Could not read region directory");
+    log::debug!("Found {} region files", regions.len());
+    for region in regions {
+        let chunks = world
+            .load_chunk_region(dimension, region, bounding_box.as_ref())
+            .expect("Error reading file")
+            .chunks;
+        for chunk in &chunks {
+            let format = TextFormat::from_data_version(chunk.data_version);
+            for block_entity in chunk
+                .block_entities
+                .iter()
+                .flat_map(|block_entities| block_entities.iter())
+                .filter(|block_entity| in_area(area, block_entity.position()))
+            {
+                let location = location(&block_entity.id, block_entity.position());
+                for (_, item) in block_entity_items(block_entity) {
+                    visit(&location, item, format);
+                }
+            }
+        }
+    }
+}
+
+/// Items stored in a block entity with their slot. Items of block entities without slots like lecterns have no slot.
+pub fn block_entity_items(block_entity: &BlockEntity) -> SlotItems<'_> {
+    if let Some(inventory) = inventory_block(block_entity) {
+        return slot_items(inventory.items());
+    }
+    if let Some(cooking_block) = cooking_block(block_entity) {
+        return slot_items(cooking_block.items());
+    }
+    match &block_entity.entity_type {
+        BlockEntityType::BrewingStand(block) => slot_items(block.items.as_ref()),
+        BlockEntityType::Campfire(block) => slot_items(block.items.as_ref()),
+        BlockEntityType::SoulCampfire(block) => slot_items(block.items.as_ref()),
+        BlockEntityType::ChiseledBookshelf(block) => slot_items(block.items.as_ref()),
+        BlockEntityType::BrushableBlock(block) => {
+            block.item.iter().map(|item| (None, item)).collect()
+        }
+        BlockEntityType::Lectern(block) => block.book.iter().map(|item| (None, item)).collect(),
+        BlockEntityType::Jukebox(block) => vec![(None, &block.record_item)],
+        _ => Vec::new(),
+    }
+}
+
+/// The block entity as a container like a chest or a hopper.
+pub fn inventory_block(block_entity: &BlockEntity) -> Option<&dyn InventoryBlock> {
+    match &block_entity.entity_type {
+        BlockEntityType::Barrel(block) => Some(block),
+        BlockEntityType::Chest(block) => Some(block),
+        BlockEntityType::Crafter(block) => Some(block),
+        BlockEntityType::DecoratedPot(block) => Some(block),
+        BlockEntityType::Dispenser(block) => Some(block),
+        BlockEntityType::Dropper(block) => Some(block),
+        BlockEntityType::Hopper(block) => Some(block),
+        BlockEntityType::ShulkerBox(block) => Some(block),
+        BlockEntityType::TrappedChest(block) => Some(block),
+        _ => None,
+    }
+}
+
+/// The block entity as a furnace, blast furnace or smoker.
+pub fn cooking_block(block_entity: &BlockEntity) -> Option<&dyn CookingBlockEntity> {
+    match &block_entity.entity_type {
+        BlockEntityType::BlastFurnace(block) => Some(block),
+        BlockEntityType::Furnace(block) => Some(block),
+        BlockEntityType::Smoker(block) => Some(block),
+        _ => None,
+    }
+}
+
+fn slot_items(items: Option<&List<ItemWithSlot>>) -> SlotItems<'_> {
+    items
+        .iter()
+        .flat_map(|items| items.iter())
+        .map(|item| (Some(item.slot), &item.item))
+        .collect()
+}
+
+fn visit_entities(
+    world: &World,
+    dimension: &str,
+    area: Option<&Area>,
+    visit: &mut dyn FnMut(&str, &Item, TextFormat),
+) {
+    let bounding_box = area.map(Area::bounding_box);
+    let regions = world
+        .region_positions(dimension, RegionKind::Entities, bounding_box.as_ref())
+        .unwrap_or_else(|e| {
+            log::warn!("Could not read entities directory: {e}");
+            Vec::new()
+        });
+    log::debug!("Found {} entity region files", regions.len());
+    for region in regions {
+        for chunk in world
+            .load_entity_region_tags(dimension, region)
+            .expect("Error reading file")
+            .chunks
+        {
+            let Ok(mut chunk) = chunk.get_as_map() else {
+                continue;
+            };
+            let Some(Tag::List(entities)) = chunk.remove("Entities") else {
+                continue;
+            };
+            let format = match chunk.get("DataVersion") {
+                Some(Tag::Int(data_version)) => TextFormat::from_data_version(*data_version),
+                _ => TextFormat::Json,
+            };
+            for entity in entities.iter() {
+                if let Tag::Compound(entity) = entity {
+                    visit_entity(entity, area, format, visit);
+                }
+            }
+        }
+    }
+}
+
+/// Visits the items of an entity and its passengers. Entities without a position are skipped.
+fn visit_entity(
+    entity: &HashMap<String, Tag>,
+    area: Option<&Area>,
+    format: TextFormat,
+    visit: &mut dyn FnMut(&str, &Item, TextFormat),
+) {
+    if let Some(Tag::List(passengers)) = entity.get("Passengers") {
+        for passenger in passengers.iter() {
+            if let Tag::Compound(passenger) = passenger {
+                visit_entity(passenger, area, format, visit);
+            }
+        }
+    }
+    let (Some(Tag::String(id)), Some(position)) = (entity.get("id"), entity_position(entity))
+    else {
+        return;
+    };
+    if !in_area(area, position) {
+        return;
+    }
+    let location = location(id, position);
+    for item in entity_items(entity) {
+        visit(&location, &item, format);
+    }
+}
+
+/// The block containing an entity read from an entity chunk.
+pub fn entity_position(entity: &HashMap<String, Tag>) -> Option<BlockPos> {
+    let Some(Tag::List(position)) = entity.get("Pos") else {
+        return None;
+    };
+    match position.as_slice() {
+        [Tag::Double(x), Tag::Double(y), Tag::Double(z)] => Some(BlockPos::containing(*x, *y, *z)),
+        _ => None,
+    }
+}
+
+/// Items held, worn or carried by an entity. Items of passengers and empty slots are skipped.
+pub fn entity_items(entity: &HashMap<String, Tag>) -> Vec<Item> {
+    let mut items = Vec::new();
+    for key in ENTITY_ITEM_KEYS {
+        items.extend(entity.get(key).and_then(parse_item));
+    }
+    for key in ENTITY_ITEM_LIST_KEYS {
+        if let Some(Tag::List(list)) = entity.get(key) {
+            items.extend(list.iter().filter_map(parse_item));
+        }
+    }
+    // Since 1.21.5 held and worn items are stored by slot name
+    if let Some(Tag::Compound(equipment)) = entity.get("equipment") {
+        items.extend(equipment.values().filter_map(parse_item));
+    }
+    items
+}
+
+fn parse_item(tag: &Tag) -> Option<Item> {
+    let Tag::Compound(item) = tag else {
+        return None;
+    };
+    if !item.contains_key("id") {
+        return None;
+    }
+    match Item::try_from(item.clone()) {
+        Ok(item) => Some(item),
+        Err(e) => {
+            log::warn!("Could not parse item: {e:?}");
+            None
+        }
+    }
+}
+
+/// Visits the inventories and ender chests of all players. Unreadable player files are skipped.
+fn visit_players(world: &World, visit: &mut dyn FnMut(&str, &Item, TextFormat)) {
+    for (uuid, player) in read_players(world) {
+        let format = TextFormat::from_data_version(player.data_version);
+        for (inventory, items) in player_items(&player) {
+            let location = format!("player {uuid} {inventory}");
+            for (_, item) in items {
+                visit(&location, item, format);
+            }
+        }
+    }
+}
+
+/// The players of a world with their UUID. Unreadable player files are skipped.
+pub fn read_players(world: &World) -> Vec<(String, Player)> {
+    let uuids = world.player_uuids().unwrap_or_else(|e| {
+        log::warn!("Could not read playerdata directory: {e}");
+        Vec::new()
+    });
+    log::debug!("Found {} player files", uuids.len());
+    uuids
+        .into_iter()
+        .filter_map(|uuid| match world.player(&uuid) {
+            Ok(player) => Some((uuid, player)),
+            Err(e) => {
+                log::warn!("Could not read player file {uuid}.dat: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Items of the inventory and of the ender chest of a player with their slot.
+pub fn player_items(player: &Player) -> [(&'static str, SlotItems<'_>); 2] {
+    let mut inventory = slot_items(Some(&player.inventory));
+    inventory.extend(player.selected_item.iter().map(|item| (None, item)));
+    [
+        ("inventory", inventory),
+        ("ender chest", slot_items(Some(&player.ender_items))),
+    ]
+}
+
+fn location(id: &str, position: BlockPos) -> String {
+    format!(
+        "{} at x:{} y:{} z:{}",
+        id, position.x, position.y, position.z
+    )
+}
+
+fn in_area(area: Option<&Area>, position: BlockPos) -> bool {
+    match area {
+        Some(area) => area.contains(position),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(value: &str) -> Tag {
+        Tag::String(value.to_string())
+    }
+
+    fn position(x: f64, y: f64, z: f64) -> Tag {
+        Tag::List(List::from(vec![
+            Tag::Double(x),
+            Tag::Double(y),
+            Tag::Double(z),
+        ]))
+    }
+
+    fn item_tag(id: &str, count: i8) -> Tag {
+        Tag::Compound(HashMap::from_iter([
+            ("id".to_string(), string(id)),
+            ("Count".to_string(), Tag::Byte(count)),
+        ]))
+    }
+
+    fn visited(entity: &HashMap<String, Tag>, area: Option<&Area>) -> Vec<(String, String)> {
+        let mut items = Vec::new();
+        visit_entity(entity, area, TextFormat::Json, &mut |location, item, _| {
+            items.push((location.to_string(), item.id.clone()))
+        });
+        items
+    }
+
+    fn zombie() -> HashMap<String, Tag> {
+        HashMap::from_iter([
+            ("id".to_string(), string("minecraft:zombie")),
+            ("Pos".to_string(), position(1.5, 64.0, -2.5)),
+            (
+                "HandItems".to_string(),
+                Tag::List(List::from(vec![
+                    item_tag("minecraft:iron_sword", 1),
+                    Tag::Compound(HashMap::new()),
+                ])),
+            ),
+            (
+                "Passengers".to_string(),
+                Tag::List(List::from(vec![Tag::Compound(HashMap::from_iter([
+                    ("id".to_string(), string("minecraft:item")),
+                    ("Pos".to_string(), position(1.5, 65.0, -2.5)),
+                    ("Item".to_string(), item_tag("minecraft:stone", 12)),
+                ]))])),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_visit_entity() {
+        assert_eq!(
+            visited(&zombie(), None),
+            vec![
+                (
+                    "minecraft:item at x:1 y:65 z:-3".to_string(),
+                    "minecraft:stone".to_string()
+                ),
+                (
+                    "minecraft:zombie at x:1 y:64 z:-3".to_string(),
+                    "minecraft:iron_sword".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visit_entity_outside_of_area() {
+        let area = Area {
+            x1: 100,
+            z1: 100,
+            x2: 200,
+            z2: 200,
+        };
+        assert_eq!(visited(&zombie(), Some(&area)), vec![]);
+    }
+
+    #[test]
+    fn test_entity_items_equipment() {
+        let entity = HashMap::from_iter([(
+            "equipment".to_string(),
+            Tag::Compound(HashMap::from_iter([(
+                "head".to_string(),
+                Tag::Compound(HashMap::from_iter([
+                    ("id".to_string(), string("minecraft:diamond_helmet")),
+                    ("count".to_string(), Tag::Int(1)),
+                ])),
+            )])),
+        )]);
+        let items = entity_items(&entity);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "minecraft:diamond_helmet");
+    }
+}